@@ -10,11 +10,33 @@ use std::ops::Range;
 const DEFAULT_MAX_ITER: usize = 1000;
 const DEFAULT_MIN_MOV: f32 = 1e-4;
 use locus::prelude::*;
+/// A seeded RNG when `seed` is given, otherwise one seeded from the thread RNG.
+fn make_rng(seed: Option<u64>) -> StdRng {
+    match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_rng(&mut rand::rng()),
+    }
+}
+
 #[derive(Debug)]
 struct Centroid {
     center: Datapoint,
     friends: Vec<usize>,
 }
+/// How [`KMeans`] places its initial centroids.
+#[derive(Debug, Clone, Default)]
+pub enum InitStrategy {
+    /// Uniformly at random inside the data's bounding box.
+    #[default]
+    Random,
+    /// k-means++: data points chosen with probability proportional to their
+    /// squared distance from the closest centroid picked so far.
+    PlusPlus,
+    /// Exactly these centers; `k` becomes their count. An empty list leaves
+    /// the model without clusters, like `k == 0`.
+    Provided(Vec<Datapoint>),
+}
+
 #[derive(Debug)]
 pub struct KMeans<'a> {
     k: usize,
@@ -24,11 +46,28 @@ pub struct KMeans<'a> {
     curr_iter: usize,
     min_mov: f32,
     has_converged: bool,
+    rng: StdRng,
+    init: InitStrategy,
 }
 
 impl<'a> KMeans<'a> {
+    /// A model of `k` clusters over `data`, initialised with
+    /// [`InitStrategy::Random`].
+    ///
+    /// With `k == 0` there is nothing to fit: the model starts out
+    /// [finished](KMeans::is_finished) and every point is left unassigned.
     #[must_use]
     pub fn new(k: usize, data: &'a Dataset) -> Self {
+        Self::with_rng(k, data, make_rng(None))
+    }
+
+    /// Like [`KMeans::new`], but with a reproducible centroid initialisation.
+    #[must_use]
+    pub fn with_seed(k: usize, data: &'a Dataset, seed: u64) -> Self {
+        Self::with_rng(k, data, make_rng(Some(seed)))
+    }
+
+    fn with_rng(k: usize, data: &'a Dataset, rng: StdRng) -> Self {
         let mut me = Self {
             k,
             centroids: HashMap::with_capacity(k),
@@ -37,16 +76,94 @@ impl<'a> KMeans<'a> {
             curr_iter: 0,
             min_mov: DEFAULT_MIN_MOV,
             has_converged: false,
+            rng,
+            init: InitStrategy::default(),
         };
         me.initialize();
         me
     }
+
+    /// Switch the initialisation strategy and re-seed the centroids with it.
+    #[must_use]
+    pub fn with_init(mut self, init: InitStrategy) -> Self {
+        self.init = init;
+        self.initialize();
+        self
+    }
+
     pub fn initialize(&mut self) {
-        let mut rng = rand::rng();
+        self.centroids.clear();
+        match self.init.clone() {
+            InitStrategy::Random => self.initialize_random(),
+            InitStrategy::PlusPlus => self.initialize_plus_plus(),
+            InitStrategy::Provided(centers) => {
+                self.k = centers.len();
+                for (k, center) in centers.into_iter().enumerate() {
+                    self.centroids.insert(
+                        k,
+                        Centroid {
+                            center,
+                            friends: Vec::new(),
+                        },
+                    );
+                }
+            }
+        }
+    }
+
+    /// Seed the centroids with k-means++, picking them from the data itself.
+    pub fn initialize_plus_plus(&mut self) {
+        self.centroids.clear();
+        let points = &self.data.data;
+        if points.is_empty() || self.k == 0 {
+            return;
+        }
+        let mut centers: Vec<Datapoint> = Vec::with_capacity(self.k);
+        centers.push(points[self.rng.random_range(0..points.len())]);
+        // Squared distance from each point to its closest chosen center.
+        let mut nearest: Vec<f32> = points
+            .iter()
+            .map(|p| (**p - *centers[0]).length_sqr())
+            .collect();
+        while centers.len() < self.k {
+            let total: f32 = nearest.iter().sum();
+            let chosen = if total > 0.0 {
+                let mut target = self.rng.random_range(0.0..total);
+                nearest
+                    .iter()
+                    .position(|d| {
+                        target -= d;
+                        target < 0.0
+                    })
+                    .unwrap_or(points.len() - 1)
+            } else {
+                // Every point coincides with a center already.
+                self.rng.random_range(0..points.len())
+            };
+            let center = points[chosen];
+            for (d, p) in nearest.iter_mut().zip(points) {
+                *d = d.min((**p - *center).length_sqr());
+            }
+            centers.push(center);
+        }
+        for (k, center) in centers.into_iter().enumerate() {
+            self.centroids.insert(
+                k,
+                Centroid {
+                    center,
+                    friends: Vec::new(),
+                },
+            );
+        }
+    }
+
+    fn initialize_random(&mut self) {
         for k in 0..self.k {
             let center = Datapoint::new(
-                rng.random_range(self.data.range_min.x..self.data.range_max.x),
-                rng.random_range(self.data.range_min.y..self.data.range_max.y),
+                self.rng
+                    .random_range(self.data.range_min.x..self.data.range_max.x),
+                self.rng
+                    .random_range(self.data.range_min.y..self.data.range_max.y),
             );
             self.centroids.insert(
                 k,
@@ -58,30 +175,117 @@ impl<'a> KMeans<'a> {
         }
     }
 
+    /// Index of the centroid nearest to `p`, or `None` without centroids or
+    /// when `p` is not finite.
+    ///
+    /// Ties go to the lowest index, so the answer does not depend on the
+    /// map's iteration order and every caller agrees on it.
+    fn nearest_centroid(&self, p: &Datapoint) -> Option<usize> {
+        if !(p.x.is_finite() && p.y.is_finite()) {
+            return None;
+        }
+        self.centroids
+            .iter()
+            .map(|(c, centroid)| (*c, (*centroid.center - **p).length_sqr()))
+            .min_by(|(c_a, a), (c_b, b)| a.total_cmp(b).then(c_a.cmp(c_b)))
+            .map(|(c, _)| c)
+    }
+
+    /// Cluster index of every data point, in data order.
+    ///
+    /// Points not yet assigned (before the first [`KMeans::assign`]) are
+    /// labelled with their nearest centroid.
+    #[must_use]
+    pub fn labels(&self) -> Vec<usize> {
+        let mut labels: Vec<Option<usize>> = vec![None; self.data.data.len()];
+        for (c_index, centroid) in &self.centroids {
+            for p_index in &centroid.friends {
+                labels[*p_index] = Some(*c_index);
+            }
+        }
+        labels
+            .into_iter()
+            .zip(&self.data.data)
+            .map(|(label, p)| {
+                label
+                    .or_else(|| self.nearest_centroid(p))
+                    .unwrap_or_default()
+            })
+            .collect()
+    }
+
+    /// Current centroid positions, ordered by cluster index.
+    #[must_use]
+    pub fn centers(&self) -> Vec<Datapoint> {
+        let mut centers: Vec<(usize, Datapoint)> = self
+            .centroids
+            .iter()
+            .map(|(c, centroid)| (*c, centroid.center))
+            .collect();
+        centers.sort_unstable_by_key(|(c, _)| *c);
+        centers.into_iter().map(|(_, center)| center).collect()
+    }
+
+    /// Sum of squared distances from each point to its assigned centroid.
+    #[must_use]
+    pub fn inertia(&self) -> f32 {
+        self.labels()
+            .into_iter()
+            .zip(&self.data.data)
+            .filter_map(|(label, p)| {
+                self.centroids
+                    .get(&label)
+                    .map(|c| (*c.center - **p).length_sqr())
+            })
+            .sum()
+    }
+
     pub fn fit(&mut self) {
-        while !self.has_converged && self.curr_iter <= self.max_iter {
+        while !self.is_finished() {
             self.step();
         }
     }
-    #[allow(clippy::missing_panics_doc)]
+    /// Closest centroid of every data point, in data order, on one thread.
+    fn assignments_serial(&self) -> Vec<Option<usize>> {
+        self.data
+            .data
+            .iter()
+            .map(|p| self.nearest_centroid(p))
+            .collect()
+    }
+
+    /// Closest centroid of every data point, in data order.
+    #[cfg(not(feature = "rayon"))]
+    fn assignments(&self) -> Vec<Option<usize>> {
+        self.assignments_serial()
+    }
+
+    /// Closest centroid of every data point, in data order, searched in
+    /// parallel.
+    #[cfg(feature = "rayon")]
+    fn assignments(&self) -> Vec<Option<usize>> {
+        use rayon::prelude::*;
+        self.data
+            .data
+            .par_iter()
+            .map(|p| self.nearest_centroid(p))
+            .collect()
+    }
+
+    /// Assign every point to its closest centroid. Points with a non-finite
+    /// coordinate are left out of every cluster.
+    ///
+    /// With the `rayon` feature the nearest-centroid search runs in
+    /// parallel; the grouping pass that follows is always serial.
     pub fn assign(&mut self) {
+        if self.centroids.is_empty() {
+            return;
+        }
         let mut mapping: HashMap<usize, Vec<usize>> = HashMap::with_capacity(self.k);
         for centroid_index in 0..self.k {
             mapping.entry(centroid_index).or_default();
         }
-        for (i, p) in self.data.data.iter().enumerate() {
-            let mut min_dist = f32::INFINITY;
-            let mut c_index: Option<usize> = None;
-            for (c, cluster) in &self.centroids {
-                let distance = f32::sqrt(
-                    f32::powi(cluster.center.x - p.x, 2) + f32::powi(cluster.center.y - p.y, 2),
-                );
-                if distance <= min_dist {
-                    min_dist = distance;
-                    c_index = Some(*c);
-                }
-            }
-            assert!(c_index.is_some());
+        for (i, c_index) in self.assignments().into_iter().enumerate() {
             if let Some(c_index) = c_index
                 && let Some(cluster) = mapping.get_mut(&c_index)
             {
@@ -126,8 +330,11 @@ impl<'a> KMeans<'a> {
             self.has_converged = true;
         }
     }
+    /// Run a single assign/update iteration. Does nothing once the
+    /// centroids have converged or `max_iter` iterations have run, so it is
+    /// safe to call once per frame (or keypress) for an animated fit.
     pub fn step(&mut self) {
-        if self.has_converged || self.curr_iter >= self.max_iter {
+        if self.is_finished() {
             return;
         }
 
@@ -135,6 +342,50 @@ impl<'a> KMeans<'a> {
         self.update();
         self.curr_iter += 1;
     }
+
+    /// Whether the last [`KMeans::step`] moved every centroid less than the
+    /// convergence threshold.
+    #[must_use]
+    pub fn has_converged(&self) -> bool {
+        self.has_converged
+    }
+
+    /// Number of iterations run since initialisation (or the last
+    /// [`KMeans::reset`]).
+    #[must_use]
+    pub fn current_iteration(&self) -> usize {
+        self.curr_iter
+    }
+
+    /// Whether further calls to [`KMeans::step`] are no-ops, either because
+    /// the fit converged, the iteration limit was reached, or there are no
+    /// centroids to move. At most `max_iter` iterations run.
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        self.centroids.is_empty() || self.has_converged || self.curr_iter >= self.max_iter
+    }
+
+    /// Re-initialise the centroids with the current [`InitStrategy`] and
+    /// start counting iterations from zero.
+    pub fn reset(&mut self) {
+        self.curr_iter = 0;
+        self.has_converged = false;
+        self.initialize();
+    }
+    /// One legend entry per centroid, colored like [`KMeansPlot`] draws it.
+    #[must_use]
+    pub fn legend_entries(&self, colorscheme: &Colorscheme) -> Vec<LegendEntry> {
+        let mut indices: Vec<usize> = self.centroids.keys().copied().collect();
+        indices.sort_unstable();
+        indices
+            .into_iter()
+            .map(|c_index| {
+                let color = colorscheme.cycle_color(c_index);
+                LegendEntry::new(format!("Cluster {}", c_index + 1), color)
+            })
+            .collect()
+    }
+
     #[must_use]
     pub fn plot(&'a self) -> KMeansPlot<'a> {
         KMeansPlot::new(self)
@@ -145,6 +396,39 @@ impl<'a> KMeans<'a> {
     }
 }
 
+/// Fit k-means for every `k` in `k_range` and return `(k, inertia)` pairs,
+/// ready to plot as an elbow curve. The curve starts at `k = 1`; a range
+/// reaching down to 0 skips it.
+#[must_use]
+pub fn elbow(data: &Dataset, k_range: Range<usize>, seed: Option<u64>) -> Vec<(usize, f32)> {
+    elbow_with_restarts(data, k_range, seed, 1)
+}
+
+/// Like [`elbow`], fitting `restarts` times per `k` and keeping the lowest
+/// inertia to smooth out unlucky initialisations.
+#[must_use]
+pub fn elbow_with_restarts(
+    data: &Dataset,
+    k_range: Range<usize>,
+    seed: Option<u64>,
+    restarts: usize,
+) -> Vec<(usize, f32)> {
+    let mut rng = make_rng(seed);
+    (k_range.start.max(1)..k_range.end)
+        .map(|k| {
+            let best = (0..restarts.max(1))
+                .map(|_| {
+                    let mut kmeans =
+                        KMeans::with_seed(k, data, rng.random()).with_init(InitStrategy::PlusPlus);
+                    kmeans.fit();
+                    kmeans.inertia()
+                })
+                .fold(f32::INFINITY, f32::min);
+            (k, best)
+        })
+        .collect()
+}
+
 #[derive(Builder)]
 #[builder(pattern = "owned", name = "KMeansPlotBuilder")]
 pub struct KMeansConfig {
@@ -205,7 +489,7 @@ impl ChartElement for KMeansPlot<'_> {
             None => &Colorscheme::default(),
         };
         for (c_index, centroid) in &self.kmeans.centroids {
-            let color = colorscheme.cycle[c_index % colorscheme.cycle.len()];
+            let color = colorscheme.cycle_color(*c_index);
             for p_index in &centroid.friends {
                 let p = &self.kmeans.data.data[*p_index];
                 view.to_screen(p).plot(
@@ -282,6 +566,8 @@ pub struct MakeCirclesConfig {
     radius: Range<f32>,
     x_range: Range<f32>,
     y_range: Range<f32>,
+    #[builder(setter(into, strip_option))]
+    seed: Option<u64>,
 }
 
 impl MakeCirclesBuilder {
@@ -303,13 +589,14 @@ impl Default for MakeCirclesConfig {
             radius: 1.0..10.0,
             x_range: -10.0..10.0,
             y_range: -10.0..10.0,
+            seed: None,
         }
     }
 }
 
 #[must_use]
 pub fn make_circles(config: &MakeCirclesConfig) -> Dataset {
-    let mut rng = rand::rng();
+    let mut rng = make_rng(config.seed);
     let mut radius: Vec<f32> = Vec::with_capacity(config.n_circles);
     let mut centers: Vec<Vector2> = Vec::with_capacity(config.n_circles);
     for _ in 0..config.n_circles {
@@ -319,8 +606,6 @@ pub fn make_circles(config: &MakeCirclesConfig) -> Dataset {
             rng.random_range(config.y_range.clone()),
         ));
     }
-    // let (mut min_x, mut max_x) = (f32::INFINITY, f32::NEG_INFINITY);
-    // let (mut min_y, mut max_y) = (f32::INFINITY, f32::NEG_INFINITY);
     let mut data: Vec<Datapoint> = Vec::with_capacity(config.n_samples);
     for i in 0..config.n_samples {
         let r = radius[i % config.n_circles] * f32::sqrt(rng.random::<f32>());
@@ -343,6 +628,8 @@ pub struct MakeMoonsConfig {
     radius: Range<f32>,
     n_moons: usize,
     scale: f32,
+    #[builder(setter(into, strip_option))]
+    seed: Option<u64>,
 }
 
 impl MakeMoonsBuilder {
@@ -366,17 +653,16 @@ impl Default for MakeMoonsConfig {
             radius: 1.0..5.0,
             n_moons: 2,
             scale: 0.3,
+            seed: None,
         }
     }
 }
 #[must_use]
 pub fn make_moons(config: &MakeMoonsConfig) -> Dataset {
-    let mut rng = rand::rng();
+    let mut rng = make_rng(config.seed);
     let mut data: Vec<Datapoint> = Vec::with_capacity(config.n_samples);
     let mut centers: Vec<Vector2> = Vec::with_capacity(config.n_moons);
     let mut radius: Vec<f32> = Vec::with_capacity(config.n_moons);
-    // let (mut min_x, mut max_x) = (f32::INFINITY, f32::NEG_INFINITY);
-    // let (mut min_y, mut max_y) = (f32::INFINITY, f32::NEG_INFINITY);
     for _ in 0..config.n_moons {
         centers.push(Vector2::new(
             rng.random_range(config.x_range.clone()),
@@ -402,3 +688,224 @@ pub fn make_moons(config: &MakeMoonsConfig) -> Dataset {
     }
     Dataset::new(data)
 }
+
+#[derive(Clone, Debug, Builder)]
+#[builder(pattern = "owned", name = "MakeBlobsBuilder", default)]
+pub struct MakeBlobsConfig {
+    n_samples: usize,
+    n_centers: usize,
+    cluster_std: f32,
+    x_range: Range<f32>,
+    y_range: Range<f32>,
+    #[builder(setter(into, strip_option))]
+    seed: Option<u64>,
+}
+
+impl MakeBlobsBuilder {
+    #[must_use]
+    pub fn with_equal_ranges(self, range: Range<f32>) -> Self {
+        Self {
+            x_range: Some(range.clone()),
+            y_range: Some(range),
+            ..self
+        }
+    }
+}
+
+impl Default for MakeBlobsConfig {
+    fn default() -> Self {
+        Self {
+            n_samples: 100,
+            n_centers: 3,
+            cluster_std: 1.0,
+            x_range: -10.0..10.0,
+            y_range: -10.0..10.0,
+            seed: None,
+        }
+    }
+}
+
+/// Draw a pair of independent standard normal samples via the Box–Muller
+/// transform.
+fn standard_normal_pair(rng: &mut impl Rng) -> (f32, f32) {
+    // `1.0 - u` keeps the logarithm's argument in (0, 1].
+    let u1: f32 = 1.0 - rng.random::<f32>();
+    let u2: f32 = rng.random::<f32>();
+    let r = f32::sqrt(-2.0 * u1.ln());
+    let theta = 2.0 * std::f32::consts::PI * u2;
+    (r * theta.cos(), r * theta.sin())
+}
+
+/// Isotropic Gaussian blobs around randomly placed centers.
+#[must_use]
+pub fn make_blobs(config: &MakeBlobsConfig) -> Dataset {
+    make_blobs_with_centers(config).0
+}
+
+/// Like [`make_blobs`], also returning the true center of each blob.
+#[must_use]
+pub fn make_blobs_with_centers(config: &MakeBlobsConfig) -> (Dataset, Vec<Datapoint>) {
+    let mut rng = make_rng(config.seed);
+    let centers: Vec<Datapoint> = (0..config.n_centers.max(1))
+        .map(|_| {
+            Datapoint::new(
+                rng.random_range(config.x_range.clone()),
+                rng.random_range(config.y_range.clone()),
+            )
+        })
+        .collect();
+    let mut data: Vec<Datapoint> = Vec::with_capacity(config.n_samples);
+    for i in 0..config.n_samples {
+        let center = centers[i % centers.len()];
+        let (dx, dy) = standard_normal_pair(&mut rng);
+        data.push(Datapoint::new(
+            center.x + config.cluster_std * dx,
+            center.y + config.cluster_std * dy,
+        ));
+    }
+    (Dataset::new(data), centers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use raylib::color::Color;
+
+    /// Run with `cargo test --features rayon` to compare the parallel
+    /// search against the serial one.
+    #[test]
+    fn assignments_match_serial() {
+        let data = make_blobs(
+            &MakeBlobsBuilder::default()
+                .n_samples(5000)
+                .n_centers(20)
+                .seed(7_u64)
+                .build()
+                .unwrap(),
+        );
+        let mut kmeans = KMeans::with_seed(20, &data, 11).with_init(InitStrategy::PlusPlus);
+        for _ in 0..5 {
+            assert_eq!(kmeans.assignments(), kmeans.assignments_serial());
+            kmeans.step();
+        }
+    }
+
+    /// `ds`'s range is exactly the span of its points.
+    fn assert_tight_bounds(ds: &Dataset) {
+        let min_x = ds.data.iter().map(|p| p.x).fold(f32::INFINITY, f32::min);
+        let max_x = ds
+            .data
+            .iter()
+            .map(|p| p.x)
+            .fold(f32::NEG_INFINITY, f32::max);
+        let min_y = ds.data.iter().map(|p| p.y).fold(f32::INFINITY, f32::min);
+        let max_y = ds
+            .data
+            .iter()
+            .map(|p| p.y)
+            .fold(f32::NEG_INFINITY, f32::max);
+        assert_eq!((ds.range_min.x, ds.range_max.x), (min_x, max_x));
+        assert_eq!((ds.range_min.y, ds.range_max.y), (min_y, max_y));
+    }
+
+    #[test]
+    fn generators_report_the_bounds_of_their_points() {
+        let circles = make_circles(
+            &MakeCirclesBuilder::default()
+                .n_samples(300)
+                .with_equal_ranges(-5.0..5.0)
+                .radius(1.0..2.0)
+                .seed(3_u64)
+                .build()
+                .unwrap(),
+        );
+        assert_eq!(circles.data.len(), 300);
+        assert_tight_bounds(&circles);
+        // Centres lie in -5..5 and points within 2 of their centre.
+        assert!(circles.range_min.x >= -7.0 && circles.range_max.x <= 7.0);
+        assert!(circles.range_min.y >= -7.0 && circles.range_max.y <= 7.0);
+
+        let moons = make_moons(
+            &MakeMoonsBuilder::default()
+                .n_samples(300)
+                .seed(5_u64)
+                .build()
+                .unwrap(),
+        );
+        assert_eq!(moons.data.len(), 300);
+        assert_tight_bounds(&moons);
+    }
+
+    #[test]
+    fn ties_go_to_the_lowest_centroid_and_nan_points_are_skipped() {
+        let data = Dataset::new(vec![(0.0, 0.0), (f32::NAN, 1.0), (2.0, 0.0)]);
+        let mut kmeans = KMeans::with_seed(0, &data, 1).with_init(InitStrategy::Provided(vec![
+            Datapoint::new(-1.0, 0.0),
+            Datapoint::new(1.0, 0.0),
+        ]));
+        assert_eq!(kmeans.nearest_centroid(&data.data[0]), Some(0));
+        assert_eq!(kmeans.nearest_centroid(&data.data[1]), None);
+        kmeans.assign();
+        assert_eq!(kmeans.labels(), vec![0, 0, 1]);
+        let assigned: usize = kmeans.centroids.values().map(|c| c.friends.len()).sum();
+        assert_eq!(assigned, 2);
+    }
+
+    #[test]
+    fn empty_initialisation_leaves_nothing_to_fit() {
+        let data = Dataset::new(vec![(0.0, 0.0), (1.0, 1.0)]);
+        let mut provided = KMeans::with_seed(2, &data, 1).with_init(InitStrategy::Provided(vec![]));
+        provided.fit();
+        assert!(provided.is_finished());
+        assert_eq!(provided.current_iteration(), 0);
+        assert!(provided.centers().is_empty());
+
+        let mut random = KMeans::with_seed(0, &data, 1);
+        random.assign();
+        random.step();
+        assert!(random.centers().is_empty());
+        assert_eq!(random.inertia(), 0.0);
+    }
+
+    #[test]
+    fn fit_runs_exactly_max_iter_iterations() {
+        let data = make_blobs(&MakeBlobsBuilder::default().seed(2_u64).build().unwrap());
+        let mut kmeans = KMeans::with_seed(3, &data, 4);
+        kmeans.max_iter = 3;
+        // Never counts as converged, so only the cap stops the fit.
+        kmeans.min_mov = -1.0;
+        kmeans.fit();
+        assert_eq!(kmeans.current_iteration(), 3);
+        kmeans.step();
+        assert_eq!(kmeans.current_iteration(), 3);
+    }
+
+    #[test]
+    fn plus_plus_and_elbow_skip_zero_clusters() {
+        let data = Dataset::new(vec![(0.0, 0.0), (1.0, 1.0), (4.0, 4.0)]);
+        let kmeans = KMeans::with_seed(0, &data, 1).with_init(InitStrategy::PlusPlus);
+        assert!(kmeans.centers().is_empty());
+        let ks: Vec<usize> = elbow(&data, 0..3, Some(5))
+            .iter()
+            .map(|(k, _)| *k)
+            .collect();
+        assert_eq!(ks, vec![1, 2]);
+    }
+
+    #[test]
+    fn empty_cycle_falls_back_to_black() {
+        let data = Dataset::new(vec![(0.0, 0.0), (1.0, 1.0), (5.0, 5.0)]);
+        let mut kmeans = KMeans::with_seed(2, &data, 3);
+        kmeans.step();
+        let empty = Colorscheme::new(
+            Color::WHITE,
+            Color::GRAY,
+            Color::BLACK,
+            Color::BLACK,
+            vec![],
+        );
+        let entries = kmeans.legend_entries(&empty);
+        assert_eq!(entries.len(), 2);
+        assert!(entries.iter().all(|e| e.color == Color::BLACK));
+    }
+}
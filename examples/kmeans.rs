@@ -3,7 +3,7 @@
 #![deny(clippy::style, clippy::perf, clippy::correctness, clippy::complexity)]
 
 mod common;
-use common::{KMeans, KMeansPlotBuilder, MakeCirclesBuilder, make_circles};
+use common::{InitStrategy, KMeans, KMeansPlotBuilder, MakeCirclesBuilder, make_circles};
 use locus::prelude::*;
 use raylib::prelude::*;
 use std::f32;
@@ -42,10 +42,11 @@ fn main() {
             separation_y: Separation::Auto,
         },
     );
-    let mut kmeans = KMeans::new(4, &dataset);
+    let mut kmeans = KMeans::new(4, &dataset).with_init(InitStrategy::PlusPlus);
     kmeans.fit();
-    let kmeans_plot = kmeans.plot();
     let colorscheme = &GITHUB_DARK;
+    let legend = kmeans.legend_entries(colorscheme);
+    let kmeans_plot = kmeans.plot();
     let graph = Graph::new(kmeans_plot);
     let graph_config: locus::graph::GraphConfig<common::KMeansPlot<'_>> = GraphBuilder::default()
         .viewport(Viewport::new(0.0, 0.0, WIDTH as f32, HEIGHT as f32))
@@ -53,6 +54,7 @@ fn main() {
         .axis(ConfiguredElement::with_defaults(axis))
         .subject_configs(KMeansPlotBuilder::default().build().unwrap())
         .colorscheme(colorscheme)
+        .legend_from(legend)
         .build()
         .unwrap();
     while !rl.window_should_close() {
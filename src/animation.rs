@@ -0,0 +1,233 @@
+//! Smooth transitions between two states of the data.
+//!
+//! An [`Animator`] holds an old and a new state and produces the state in
+//! between for any elapsed time, eased by an [`Easing`] curve. Anything that
+//! implements [`Interpolate`] can be animated: single values, points,
+//! vectors of points (such as k-means centroids), and whole [`Dataset`]s.
+//! Collections are matched by index, so point `i` of the old state moves to
+//! point `i` of the new one.
+//!
+//! The animator does not own a clock; pass it the time elapsed since the
+//! transition began, e.g. accumulated from `RaylibHandle::get_frame_time`.
+//!
+//! # Example
+//!
+//! ```rust
+//! use locus::prelude::*;
+//!
+//! let before = Dataset::new(vec![(0.0, 0.0), (10.0, 0.0)]);
+//! let after = Dataset::new(vec![(0.0, 10.0), (10.0, 10.0)]);
+//! let animator = Animator::new(before, after, 2.0).with_easing(Easing::Linear);
+//!
+//! let halfway = animator.frame(1.0);
+//! assert_eq!(halfway.data[1].0.y, 5.0);
+//! assert!(animator.is_finished(2.0));
+//! ```
+
+use raylib::prelude::Vector2;
+
+use crate::{dataset::Dataset, plottable::point::Datapoint};
+
+/// Curve mapping linear progress in `0.0..=1.0` to eased progress.
+#[derive(Debug, Clone, Copy, Default)]
+pub enum Easing {
+    /// Constant speed.
+    Linear,
+    /// Starts slow and accelerates (cubic).
+    EaseIn,
+    /// Starts fast and decelerates (cubic).
+    EaseOut,
+    /// Slow at both ends (cubic).
+    #[default]
+    EaseInOut,
+    /// A custom curve. It should map `0.0` to `0.0` and `1.0` to `1.0`.
+    Custom(fn(f32) -> f32),
+}
+
+impl Easing {
+    /// Eased progress for linear progress `t`, clamped to `0.0..=1.0` first.
+    ///
+    /// ```rust
+    /// use locus::prelude::*;
+    ///
+    /// assert_eq!(Easing::EaseInOut.apply(0.5), 0.5);
+    /// assert!(Easing::EaseIn.apply(0.25) < 0.25);
+    /// ```
+    #[must_use]
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t * t * t,
+            Easing::EaseOut => 1.0 - (1.0 - t).powi(3),
+            Easing::EaseInOut => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (2.0 - 2.0 * t).powi(3) * 0.5
+                }
+            }
+            Easing::Custom(f) => f(t),
+        }
+    }
+}
+
+/// Values that can be blended between two states.
+pub trait Interpolate: Clone {
+    /// The state `t` of the way from `self` to `to`, where `0.0` is `self`
+    /// and `1.0` is `to`.
+    #[must_use]
+    fn interpolate(&self, to: &Self, t: f32) -> Self;
+}
+
+impl Interpolate for f32 {
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        self + (to - self) * t
+    }
+}
+
+impl Interpolate for Vector2 {
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        Vector2::new(self.x.interpolate(&to.x, t), self.y.interpolate(&to.y, t))
+    }
+}
+
+impl Interpolate for Datapoint {
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        Datapoint(self.0.interpolate(&to.0, t))
+    }
+}
+
+/// Elements are matched by index. When the lengths differ, elements only in
+/// `to` appear at their final value and elements only in `self` are dropped.
+impl<T: Interpolate> Interpolate for Vec<T> {
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        self.iter()
+            .zip(to)
+            .map(|(a, b)| a.interpolate(b, t))
+            .chain(to.iter().skip(self.len()).cloned())
+            .collect()
+    }
+}
+
+/// Points are matched by index, as for `Vec`, and the bounds are recomputed
+/// for every frame.
+impl Interpolate for Dataset {
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        Dataset::new(self.data.interpolate(&to.data, t))
+    }
+}
+
+/// Eased transition from one state to another over a fixed duration.
+///
+/// See the [module documentation](self) for an example.
+#[derive(Debug, Clone)]
+pub struct Animator<T> {
+    /// State at the start of the transition.
+    pub from: T,
+    /// State at the end of the transition.
+    pub to: T,
+    /// Length of the transition, in seconds.
+    pub duration: f32,
+    /// Curve applied to the progress.
+    pub easing: Easing,
+}
+
+impl<T: Interpolate> Animator<T> {
+    /// Animate from `from` to `to` over `duration` seconds with the default
+    /// [`Easing::EaseInOut`] curve.
+    #[must_use]
+    pub fn new(from: T, to: T, duration: f32) -> Self {
+        Self {
+            from,
+            to,
+            duration,
+            easing: Easing::default(),
+        }
+    }
+
+    /// Use `easing` for the transition.
+    #[must_use]
+    pub fn with_easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Eased progress after `elapsed` seconds. A non-positive duration
+    /// finishes immediately.
+    #[must_use]
+    pub fn progress(&self, elapsed: f32) -> f32 {
+        if self.duration > 0.0 {
+            self.easing.apply(elapsed / self.duration)
+        } else {
+            1.0
+        }
+    }
+
+    /// Whether the transition has completed after `elapsed` seconds.
+    #[must_use]
+    pub fn is_finished(&self, elapsed: f32) -> bool {
+        elapsed >= self.duration
+    }
+
+    /// The intermediate state after `elapsed` seconds. Once finished this is
+    /// exactly `to`.
+    #[must_use]
+    pub fn frame(&self, elapsed: f32) -> T {
+        if self.is_finished(elapsed) {
+            self.to.clone()
+        } else {
+            self.from.interpolate(&self.to, self.progress(elapsed))
+        }
+    }
+
+    /// A new transition from the state after `elapsed` seconds towards
+    /// `next`, with the same duration and easing. Restart the clock for it.
+    ///
+    /// Chaining this way keeps the motion continuous when new data arrives
+    /// before the current transition has finished.
+    #[must_use]
+    pub fn retarget(&self, next: T, elapsed: f32) -> Self {
+        Self {
+            from: self.frame(elapsed),
+            to: next,
+            duration: self.duration,
+            easing: self.easing,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frames_follow_the_eased_progress_and_end_on_target() {
+        let from = vec![Datapoint::new(0.0, 0.0), Datapoint::new(4.0, 4.0)];
+        let to = vec![
+            Datapoint::new(2.0, 0.0),
+            Datapoint::new(4.0, 8.0),
+            Datapoint::new(9.0, 9.0),
+        ];
+        let animator = Animator::new(from, to, 1.0).with_easing(Easing::EaseIn);
+
+        let frame = animator.frame(0.5);
+        assert_eq!(frame.len(), 3);
+        assert!((frame[0].0.x - 0.25).abs() < 1e-6);
+        assert!((frame[1].0.y - 4.5).abs() < 1e-6);
+        assert_eq!((frame[2].0.x, frame[2].0.y), (9.0, 9.0));
+
+        let end = animator.frame(1.5);
+        assert_eq!((end[1].0.x, end[1].0.y), (4.0, 8.0));
+        assert!((animator.frame(0.0)[0].0.x).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn retarget_starts_from_the_current_frame() {
+        let animator = Animator::new(0.0_f32, 10.0, 2.0).with_easing(Easing::Linear);
+        let next = animator.retarget(20.0, 1.0);
+        assert!((next.from - 5.0).abs() < 1e-6);
+        assert!((next.frame(2.0) - 20.0).abs() < f32::EPSILON);
+        assert!((Animator::new(1.0_f32, 3.0, 0.0).frame(0.0) - 3.0).abs() < f32::EPSILON);
+    }
+}
@@ -0,0 +1,243 @@
+//! Continuous colormaps for mapping numbers to colors.
+//!
+//! A [`Colormap`] is a list of evenly spaced color stops. [`sample`](Colormap::sample)
+//! interpolates between them for a position in `0.0..=1.0`, and
+//! [`sample_range`](Colormap::sample_range) first normalizes a value from a
+//! data range, which makes a colormap a natural fit for
+//! [`ScatterPlotBuilder::mapped_color`](crate::plottable::scatter::ScatterPlotBuilder::mapped_color).
+//!
+//! For signed data (correlations, residuals) use a diverging map with
+//! [`sample_centered`](Colormap::sample_centered): it pins a chosen center,
+//! usually zero, to the middle color even when the data range is lopsided.
+//!
+//! | Static | Style |
+//! |---|---|
+//! | [`RDBU`] | Diverging red (low) to blue (high), after ColorBrewer `RdBu` |
+//! | [`COOLWARM`] | Diverging blue (low) to red (high), after Moreland's cool-warm |
+//!
+//! # Example
+//!
+//! ```rust
+//! use locus::prelude::*;
+//! // Residuals span -0.5..2.0; zero stays neutral gray.
+//! let config = ScatterPlotBuilder::default()
+//!     .mapped_color(Box::new(|pt, _i| {
+//!         COOLWARM.sample_centered(pt.y, -0.5, 2.0, 0.0)
+//!     }))
+//!     .build()
+//!     .unwrap();
+//! ```
+
+use raylib::color::Color;
+use std::borrow::Cow;
+
+/// An ordered list of color stops spread evenly over `0.0..=1.0`.
+#[derive(Clone, Debug)]
+pub struct Colormap {
+    /// The color stops, from low to high.
+    pub stops: Cow<'static, [Color]>,
+}
+
+impl Colormap {
+    /// Create a colormap from its stops, low to high.
+    ///
+    /// An empty colormap samples as `Color::BLACK`.
+    #[must_use]
+    pub fn new(stops: Vec<Color>) -> Self {
+        Self {
+            stops: stops.into(),
+        }
+    }
+
+    /// A diverging colormap running from `low` through `mid` to `high`.
+    #[must_use]
+    pub fn diverging(low: Color, mid: Color, high: Color) -> Self {
+        Self::new(vec![low, mid, high])
+    }
+
+    /// The color at position `t`, interpolating between neighbouring stops.
+    ///
+    /// `t` is clamped to `0.0..=1.0`; NaN samples the low end.
+    #[must_use]
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )]
+    pub fn sample(&self, t: f32) -> Color {
+        match self.stops.as_ref() {
+            [] => Color::BLACK,
+            [only] => *only,
+            stops => {
+                let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+                let pos = t * (stops.len() - 1) as f32;
+                let i = (pos.floor() as usize).min(stops.len() - 2);
+                mix(stops[i], stops[i + 1], pos - i as f32)
+            }
+        }
+    }
+
+    /// The color of `value` within `vmin..=vmax`, mapped linearly.
+    ///
+    /// A degenerate range samples the middle of the map.
+    #[must_use]
+    pub fn sample_range(&self, value: f32, vmin: f32, vmax: f32) -> Color {
+        self.sample(normalize(value, vmin, vmax))
+    }
+
+    /// The color of `value` within `vmin..=vmax`, with `center` pinned to
+    /// the middle of the map.
+    ///
+    /// Each side of `center` is stretched over its half of the map
+    /// separately, so `center` renders neutral on a diverging map however
+    /// lopsided the bounds are.
+    ///
+    /// ```rust
+    /// use locus::prelude::*;
+    /// use raylib::color::Color;
+    /// let map = Colormap::diverging(Color::BLUE, Color::WHITE, Color::RED);
+    /// let zero = map.sample_centered(0.0, -1.0, 10.0, 0.0);
+    /// assert_eq!((zero.r, zero.g, zero.b), (255, 255, 255));
+    /// ```
+    #[must_use]
+    pub fn sample_centered(&self, value: f32, vmin: f32, vmax: f32, center: f32) -> Color {
+        let t = if value < center {
+            0.5 * normalize(value, vmin, center)
+        } else {
+            0.5 + 0.5 * normalize(value, center, vmax)
+        };
+        self.sample(t)
+    }
+}
+
+/// Position of `value` within `min..=max` as a fraction, `0.5` when the
+/// range is degenerate.
+fn normalize(value: f32, min: f32, max: f32) -> f32 {
+    if (max - min).abs() < f32::EPSILON {
+        0.5
+    } else {
+        (value - min) / (max - min)
+    }
+}
+
+/// Component-wise linear interpolation from `a` to `b`.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn mix(a: Color, b: Color, t: f32) -> Color {
+    let channel = |x: u8, y: u8| (f32::from(x) + (f32::from(y) - f32::from(x)) * t).round() as u8;
+    Color {
+        r: channel(a.r, b.r),
+        g: channel(a.g, b.g),
+        b: channel(a.b, b.b),
+        a: channel(a.a, b.a),
+    }
+}
+
+/// Diverging red-to-blue map after the five-class ColorBrewer `RdBu`
+/// scheme, with a light gray center.
+pub static RDBU: Colormap = Colormap {
+    stops: Cow::Borrowed(&[
+        Color {
+            r: 202,
+            g: 0,
+            b: 32,
+            a: 255,
+        }, // Red
+        Color {
+            r: 244,
+            g: 165,
+            b: 130,
+            a: 255,
+        }, // Salmon
+        Color {
+            r: 247,
+            g: 247,
+            b: 247,
+            a: 255,
+        }, // Neutral
+        Color {
+            r: 146,
+            g: 197,
+            b: 222,
+            a: 255,
+        }, // Light blue
+        Color {
+            r: 5,
+            g: 113,
+            b: 176,
+            a: 255,
+        }, // Blue
+    ]),
+};
+
+/// Diverging blue-to-red map after Kenneth Moreland's cool-warm scheme,
+/// with a gray center.
+pub static COOLWARM: Colormap = Colormap {
+    stops: Cow::Borrowed(&[
+        Color {
+            r: 59,
+            g: 76,
+            b: 192,
+            a: 255,
+        }, // Blue
+        Color {
+            r: 141,
+            g: 176,
+            b: 254,
+            a: 255,
+        }, // Light blue
+        Color {
+            r: 221,
+            g: 221,
+            b: 221,
+            a: 255,
+        }, // Neutral
+        Color {
+            r: 244,
+            g: 154,
+            b: 123,
+            a: 255,
+        }, // Salmon
+        Color {
+            r: 180,
+            g: 4,
+            b: 38,
+            a: 255,
+        }, // Red
+    ]),
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rgb(c: Color) -> (u8, u8, u8) {
+        (c.r, c.g, c.b)
+    }
+
+    #[test]
+    fn sample_interpolates_between_stops() {
+        let map = Colormap::new(vec![Color::new(0, 0, 0, 255), Color::new(200, 100, 0, 255)]);
+        assert_eq!(rgb(map.sample(0.5)), (100, 50, 0));
+        assert_eq!(rgb(map.sample(-1.0)), (0, 0, 0));
+        assert_eq!(rgb(map.sample(2.0)), (200, 100, 0));
+        assert_eq!(rgb(Colormap::new(Vec::new()).sample(0.3)), (0, 0, 0));
+    }
+
+    #[test]
+    fn center_is_neutral_with_lopsided_bounds() {
+        let mid = COOLWARM.stops[2];
+        assert_eq!(rgb(COOLWARM.sample_centered(0.0, -1.0, 9.0, 0.0)), rgb(mid));
+        assert_eq!(rgb(COOLWARM.sample_centered(0.0, -9.0, 1.0, 0.0)), rgb(mid));
+        // The ends still reach the extreme colors.
+        assert_eq!(
+            rgb(RDBU.sample_centered(-1.0, -1.0, 9.0, 0.0)),
+            rgb(RDBU.stops[0])
+        );
+        assert_eq!(
+            rgb(RDBU.sample_centered(9.0, -1.0, 9.0, 0.0)),
+            rgb(RDBU.stops[4])
+        );
+        // Linear mapping of the same range would put zero well off-center.
+        assert_ne!(rgb(COOLWARM.sample_range(0.0, -1.0, 9.0)), rgb(mid));
+    }
+}
@@ -17,6 +17,7 @@
 //! | [`GITHUB_DARK`] | GitHub-inspired dark theme |
 //! | [`GITHUB_LIGHT`] | GitHub-inspired light theme |
 //! | [`MATPLOTLIB_LIGHT`] | Classic Matplotlib / tab10 on white |
+//! | [`OKABE_ITO`] | Colorblind-safe Okabe-Ito palette on white |
 //!
 //! # Custom themes
 //!
@@ -33,6 +34,14 @@
 //! );
 //! ```
 //!
+//! Schemes can also be written with hex strings via [`Colorscheme::from_hex`],
+//! and single colors parsed with [`from_hex`].
+//!
+//! With the `serde` feature enabled, `Colorscheme` implements
+//! `Serialize`/`Deserialize` (colors as hex strings), and
+//! `Colorscheme::from_json` / `Colorscheme::to_json` load and save themes
+//! at runtime.
+//!
 //! You can also extend an existing scheme with additional accent colors using
 //! [`Colorscheme::extend`] (consuming) or [`Colorscheme::extend_in_place`]
 //! (mutating).
@@ -117,6 +126,527 @@ impl Colorscheme {
             ..self
         }
     }
+
+    /// The cycle color for series `index`, wrapping around the cycle.
+    ///
+    /// Falls back to `Color::BLACK` when the cycle is empty instead of
+    /// panicking on the modulo.
+    ///
+    /// ```rust
+    /// use locus::prelude::*;
+    /// use raylib::color::Color;
+    /// let empty = Colorscheme::new(Color::WHITE, Color::GRAY, Color::BLACK, Color::BLACK, vec![]);
+    /// assert_eq!(empty.cycle_color(3), Color::BLACK);
+    /// ```
+    #[must_use]
+    pub fn cycle_color(&self, index: usize) -> Color {
+        if self.cycle.is_empty() {
+            Color::BLACK
+        } else {
+            self.cycle[index % self.cycle.len()]
+        }
+    }
+
+    /// Create a new color scheme from hex strings.
+    ///
+    /// Every argument is parsed with [`from_hex`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`ParseError`] of the first malformed color.
+    ///
+    /// ```rust
+    /// use locus::prelude::*;
+    /// let scheme = Colorscheme::from_hex(
+    ///     "#282a36",
+    ///     "#44475a",
+    ///     "#f8f8f2",
+    ///     "#44475a",
+    ///     &["#ff5555", "#50fa7b"],
+    /// )
+    /// .unwrap();
+    /// assert_eq!(scheme.cycle.len(), 2);
+    /// ```
+    pub fn from_hex(
+        background: &str,
+        grid: &str,
+        text: &str,
+        axis: &str,
+        cycle: &[&str],
+    ) -> Result<Self, ParseError> {
+        let cycle = cycle
+            .iter()
+            .map(|c| from_hex(c))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self::new(
+            from_hex(background)?,
+            from_hex(grid)?,
+            from_hex(text)?,
+            from_hex(axis)?,
+            cycle,
+        ))
+    }
+
+    /// Blend from `self` (at `t = 0`) to `other` (at `t = 1`), e.g. to
+    /// animate a switch between a dark and a light theme.
+    ///
+    /// Every color is interpolated with [`lerp_color`], in linear RGB.
+    /// The shorter cycle is padded by repeating it, so the result has as
+    /// many cycle colors as the longer one; an empty cycle leaves the other
+    /// side's colors unchanged. `t` is clamped to `0.0..=1.0`.
+    ///
+    /// ```rust
+    /// use locus::prelude::*;
+    /// let halfway = GITHUB_DARK.lerp(&GITHUB_LIGHT, 0.5);
+    /// assert_eq!(halfway.cycle.len(), GITHUB_DARK.cycle.len().max(GITHUB_LIGHT.cycle.len()));
+    /// ```
+    #[must_use]
+    pub fn lerp(&self, other: &Colorscheme, t: f32) -> Colorscheme {
+        let len = self.cycle.len().max(other.cycle.len());
+        let cycle = (0..len)
+            .map(|i| match (self.cycle.as_ref(), other.cycle.as_ref()) {
+                ([], to) => to[i % to.len()],
+                (from, []) => from[i % from.len()],
+                (from, to) => lerp_color(from[i % from.len()], to[i % to.len()], t),
+            })
+            .collect::<Vec<_>>();
+        Self {
+            background: lerp_color(self.background, other.background, t),
+            grid: lerp_color(self.grid, other.grid, t),
+            text: lerp_color(self.text, other.text, t),
+            axis: lerp_color(self.axis, other.axis, t),
+            cycle: cycle.into(),
+        }
+    }
+
+    /// A copy of this scheme with the alpha of every color (background,
+    /// grid, text, axis, and cycle) multiplied by `alpha`, e.g. to fade a
+    /// whole background plot.
+    ///
+    /// `alpha` is clamped to `0.0..=1.0`. The scheme itself is untouched,
+    /// so this is safe to call on the shared statics.
+    ///
+    /// ```rust
+    /// use locus::prelude::*;
+    /// let faded = NORD.with_alpha(0.5);
+    /// assert_eq!(faded.text.a, 128);
+    /// assert_eq!(NORD.text.a, 255);
+    /// ```
+    #[must_use]
+    pub fn with_alpha(&self, alpha: f32) -> Colorscheme {
+        let fade = |c: Color| scale_alpha(c, alpha);
+        Self {
+            background: fade(self.background),
+            grid: fade(self.grid),
+            text: fade(self.text),
+            axis: fade(self.axis),
+            cycle: self
+                .cycle
+                .iter()
+                .copied()
+                .map(fade)
+                .collect::<Vec<_>>()
+                .into(),
+        }
+    }
+
+    /// Set `text` and `axis` to black or white, whichever contrasts more
+    /// with `background`, e.g. after picking a custom background.
+    ///
+    /// See [`contrasting_color`].
+    ///
+    /// ```rust
+    /// use locus::prelude::*;
+    /// use raylib::color::Color;
+    /// let scheme = Colorscheme::new(
+    ///     Color::new(20, 30, 90, 255),
+    ///     Color::GRAY,
+    ///     Color::DARKGRAY,
+    ///     Color::DARKGRAY,
+    ///     vec![Color::ORANGE],
+    /// )
+    /// .with_auto_text();
+    /// assert_eq!(scheme.text.r, 255);
+    /// ```
+    #[must_use]
+    pub fn with_auto_text(self) -> Self {
+        let ink = contrasting_color(self.background);
+        Self {
+            text: ink,
+            axis: ink,
+            ..self
+        }
+    }
+
+    /// Derive a whole scheme from one brand color.
+    ///
+    /// The background, grid, and axis are near-neutral tints of the
+    /// accent's hue (dark or light depending on `dark`), the text is black
+    /// or white via [`contrasting_color`], and the cycle holds
+    /// [`ACCENT_CYCLE_LEN`] colors: the accent itself followed by copies
+    /// with its hue rotated in even steps around the color wheel.
+    ///
+    /// ```rust
+    /// use locus::prelude::*;
+    /// use raylib::color::Color;
+    /// let scheme = Colorscheme::from_accent(Color::new(230, 80, 40, 255), true);
+    /// assert_eq!(scheme.cycle.len(), ACCENT_CYCLE_LEN);
+    /// assert_eq!(scheme.text.r, 255);
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn from_accent(accent: Color, dark: bool) -> Self {
+        let (hue, saturation, value) = rgb_to_hsv(accent);
+        let (background, grid, axis) = if dark {
+            (
+                hsv_to_rgb(hue, 0.25, 0.12),
+                hsv_to_rgb(hue, 0.15, 0.28),
+                hsv_to_rgb(hue, 0.1, 0.6),
+            )
+        } else {
+            (
+                hsv_to_rgb(hue, 0.03, 0.99),
+                hsv_to_rgb(hue, 0.08, 0.85),
+                hsv_to_rgb(hue, 0.1, 0.35),
+            )
+        };
+        let step = 360.0 / ACCENT_CYCLE_LEN as f32;
+        let cycle = std::iter::once(accent)
+            .chain(
+                (1..ACCENT_CYCLE_LEN).map(|i| hsv_to_rgb(hue + step * i as f32, saturation, value)),
+            )
+            .collect();
+        Self::new(background, grid, contrasting_color(background), axis, cycle)
+    }
+}
+
+/// Number of cycle colors produced by [`Colorscheme::from_accent`].
+pub const ACCENT_CYCLE_LEN: usize = 8;
+
+/// Hue (degrees in `0.0..360.0`), saturation, and value (both `0.0..=1.0`)
+/// of `color`. Alpha is ignored; grays have a hue of `0.0`.
+///
+/// ```rust
+/// use locus::colorscheme::rgb_to_hsv;
+/// use raylib::color::Color;
+/// let (h, s, v) = rgb_to_hsv(Color::new(0, 255, 0, 255));
+/// assert_eq!((h, s, v), (120.0, 1.0, 1.0));
+/// ```
+#[must_use]
+pub fn rgb_to_hsv(color: Color) -> (f32, f32, f32) {
+    let [r, g, b] = [color.r, color.g, color.b].map(|c| f32::from(c) / 255.0);
+    let max = r.max(g).max(b);
+    let delta = max - r.min(g).min(b);
+    let hue = if delta <= 0.0 {
+        0.0
+    } else if color.r >= color.g && color.r >= color.b {
+        60.0 * ((g - b) / delta).rem_euclid(6.0)
+    } else if color.g >= color.b {
+        60.0 * ((b - r) / delta + 2.0)
+    } else {
+        60.0 * ((r - g) / delta + 4.0)
+    };
+    let saturation = if max > 0.0 { delta / max } else { 0.0 };
+    (hue, saturation, max)
+}
+
+/// The opaque color with hue `hue` (degrees, wrapped into `0.0..360.0`),
+/// saturation, and value (both clamped to `0.0..=1.0`); the inverse of
+/// [`rgb_to_hsv`].
+#[must_use]
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub fn hsv_to_rgb(hue: f32, saturation: f32, value: f32) -> Color {
+    let hue = hue.rem_euclid(360.0);
+    let (s, v) = (saturation.clamp(0.0, 1.0), value.clamp(0.0, 1.0));
+    let chroma = v * s;
+    let x = chroma * (1.0 - ((hue / 60.0).rem_euclid(2.0) - 1.0).abs());
+    let (r, g, b) = match hue {
+        h if h < 60.0 => (chroma, x, 0.0),
+        h if h < 120.0 => (x, chroma, 0.0),
+        h if h < 180.0 => (0.0, chroma, x),
+        h if h < 240.0 => (0.0, x, chroma),
+        h if h < 300.0 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    let m = v - chroma;
+    let channel = |c: f32| ((c + m) * 255.0).round() as u8;
+    Color {
+        r: channel(r),
+        g: channel(g),
+        b: channel(b),
+        a: 255,
+    }
+}
+
+/// Relative luminance of `color` as defined by WCAG 2: `0.0` for black,
+/// `1.0` for white, weighted by how bright each channel looks. Alpha is
+/// ignored.
+///
+/// ```rust
+/// use locus::colorscheme::relative_luminance;
+/// use raylib::color::Color;
+/// assert!(relative_luminance(Color::YELLOW) > relative_luminance(Color::BLUE));
+/// ```
+#[must_use]
+pub fn relative_luminance(color: Color) -> f32 {
+    0.2126 * srgb_to_linear(color.r)
+        + 0.7152 * srgb_to_linear(color.g)
+        + 0.0722 * srgb_to_linear(color.b)
+}
+
+/// WCAG 2 contrast ratio between two colors, from `1.0` (identical
+/// luminance) to `21.0` (black on white). Text is considered readable from
+/// about `4.5`.
+#[must_use]
+pub fn contrast_ratio(a: Color, b: Color) -> f32 {
+    let (la, lb) = (relative_luminance(a), relative_luminance(b));
+    (la.max(lb) + 0.05) / (la.min(lb) + 0.05)
+}
+
+/// Black or white, whichever has the higher contrast ratio against
+/// `background`.
+#[must_use]
+pub fn contrasting_color(background: Color) -> Color {
+    if contrast_ratio(background, Color::BLACK) >= contrast_ratio(background, Color::WHITE) {
+        Color::BLACK
+    } else {
+        Color::WHITE
+    }
+}
+
+/// Color `index` of `colors`, or of `scheme`'s cycle when `colors` is
+/// `None`, wrapping around. Black when there is nothing to pick from.
+pub(crate) fn indexed_color(
+    colors: Option<&[Color]>,
+    scheme: Option<&Colorscheme>,
+    index: usize,
+) -> Color {
+    match (colors, scheme) {
+        (Some(colors), _) if !colors.is_empty() => colors[index % colors.len()],
+        (None, Some(scheme)) => scheme.cycle_color(index),
+        _ => Color::BLACK,
+    }
+}
+
+/// `color` with its alpha multiplied by `alpha` (clamped to `0.0..=1.0`;
+/// `NaN` counts as `0.0`).
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub(crate) fn scale_alpha(color: Color, alpha: f32) -> Color {
+    let alpha = if alpha.is_nan() {
+        0.0
+    } else {
+        alpha.clamp(0.0, 1.0)
+    };
+    Color {
+        a: (f32::from(color.a) * alpha).round() as u8,
+        ..color
+    }
+}
+
+/// Interpolate from `a` (at `t = 0`) to `b` (at `t = 1`) in linear RGB.
+///
+/// Blending the gamma-encoded sRGB values directly gives dark, muddy
+/// midpoints; decoding to linear light first keeps the perceived brightness
+/// of the blend between its ends. Alpha is interpolated directly. `t` is
+/// clamped to `0.0..=1.0`.
+///
+/// ```rust
+/// use locus::colorscheme::lerp_color;
+/// use raylib::color::Color;
+/// let gray = lerp_color(Color::BLACK, Color::WHITE, 0.5);
+/// assert_eq!(gray.r, 188); // not 128
+/// ```
+#[must_use]
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub fn lerp_color(a: Color, b: Color, t: f32) -> Color {
+    let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+    let channel = |x: u8, y: u8| {
+        let (x, y) = (srgb_to_linear(x), srgb_to_linear(y));
+        linear_to_srgb(x + (y - x) * t)
+    };
+    Color {
+        r: channel(a.r, b.r),
+        g: channel(a.g, b.g),
+        b: channel(a.b, b.b),
+        a: (f32::from(a.a) + (f32::from(b.a) - f32::from(a.a)) * t).round() as u8,
+    }
+}
+
+/// Decode an sRGB channel to linear light in `0.0..=1.0`.
+fn srgb_to_linear(channel: u8) -> f32 {
+    let c = f32::from(channel) / 255.0;
+    if c <= 0.040_45 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Encode linear light in `0.0..=1.0` as an sRGB channel.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn linear_to_srgb(linear: f32) -> u8 {
+    let c = linear.clamp(0.0, 1.0);
+    let c = if c <= 0.003_130_8 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    };
+    (c * 255.0).round() as u8
+}
+
+/// Error returned when a hex color string cannot be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError(String);
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ParseError: {}", self.0)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parse a color from a `#RRGGBB` or `#RRGGBBAA` hex string.
+///
+/// The leading `#` is optional and digits are case-insensitive. When the
+/// alpha component is omitted the color is fully opaque.
+///
+/// # Errors
+///
+/// Returns a [`ParseError`] when `hex` holds anything but hexadecimal digits
+/// after the `#`, or neither 6 nor 8 of them.
+///
+/// ```rust
+/// use locus::prelude::*;
+/// let orange = from_hex("#FF8000").unwrap();
+/// assert_eq!((orange.r, orange.g, orange.b, orange.a), (255, 128, 0, 255));
+/// assert!(from_hex("#fff").is_err());
+/// ```
+pub fn from_hex(hex: &str) -> Result<Color, ParseError> {
+    let digits = hex.strip_prefix('#').unwrap_or(hex);
+    if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(ParseError(format!(
+            "`{hex}` contains non-hexadecimal digits"
+        )));
+    }
+    if digits.len() != 6 && digits.len() != 8 {
+        return Err(ParseError(format!(
+            "`{hex}` has {} digits, expected 6 (#RRGGBB) or 8 (#RRGGBBAA)",
+            digits.len()
+        )));
+    }
+    // All digits are ASCII, so byte slicing is safe and parsing cannot fail.
+    let channel = |i: usize| u8::from_str_radix(&digits[i..i + 2], 16).unwrap_or_default();
+    let a = if digits.len() == 8 { channel(6) } else { 255 };
+    Ok(Color::new(channel(0), channel(2), channel(4), a))
+}
+
+/// Format a color as a lowercase hex string.
+///
+/// Opaque colors are written as `#rrggbb`, translucent ones as `#rrggbbaa`,
+/// so that the output always parses back to the same color with
+/// [`from_hex`].
+#[must_use]
+pub fn to_hex(color: Color) -> String {
+    if color.a == 255 {
+        format!("#{:02x}{:02x}{:02x}", color.r, color.g, color.b)
+    } else {
+        format!(
+            "#{:02x}{:02x}{:02x}{:02x}",
+            color.r, color.g, color.b, color.a
+        )
+    }
+}
+
+/// Serialized form of a [`Colorscheme`], with every color stored as a hex
+/// string for readability in TOML/JSON theme files.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct ColorschemeRepr {
+    background: String,
+    grid: String,
+    text: String,
+    axis: String,
+    cycle: Vec<String>,
+}
+
+#[cfg(feature = "serde")]
+impl From<Colorscheme> for ColorschemeRepr {
+    fn from(value: Colorscheme) -> Self {
+        Self {
+            background: to_hex(value.background),
+            grid: to_hex(value.grid),
+            text: to_hex(value.text),
+            axis: to_hex(value.axis),
+            cycle: value.cycle.iter().copied().map(to_hex).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<ColorschemeRepr> for Colorscheme {
+    type Error = ParseError;
+
+    fn try_from(value: ColorschemeRepr) -> Result<Self, Self::Error> {
+        let cycle: Vec<&str> = value.cycle.iter().map(String::as_str).collect();
+        Colorscheme::from_hex(
+            &value.background,
+            &value.grid,
+            &value.text,
+            &value.axis,
+            &cycle,
+        )
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Colorscheme {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&ColorschemeRepr::from(self.clone()), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Colorscheme {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = <ColorschemeRepr as serde::Deserialize>::deserialize(deserializer)?;
+        Colorscheme::try_from(repr).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Colorscheme {
+    /// Load a color scheme from a JSON document.
+    ///
+    /// Colors are expected as hex strings (see [`from_hex`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when `json` is not valid JSON, lacks a field, or
+    /// holds a malformed color.
+    ///
+    /// ```rust
+    /// use locus::prelude::*;
+    /// let scheme = Colorscheme::from_json(
+    ///     r##"{"background": "#000000", "grid": "#333333", "text": "#ffffff",
+    ///         "axis": "#888888", "cycle": ["#ff0000", "#0000ff80"]}"##,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(scheme.cycle[1].a, 0x80);
+    /// ```
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Serialize this color scheme to a pretty-printed JSON document.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization fails, which the scheme's plain
+    /// fields make unexpected in practice.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
 }
 
 impl Default for Colorscheme {
@@ -689,3 +1219,291 @@ pub static GITHUB_LIGHT: Colorscheme = Colorscheme {
         },
     ]),
 };
+
+/// Colorblind-safe light theme using the eight-color qualitative palette of
+/// [Okabe and Ito](https://jfly.uni-koeln.de/color/), which stays
+/// distinguishable under deuteranopia, protanopia, and tritanopia.
+///
+/// Black comes last in the cycle so the first series gets a hue; dark text
+/// sits on a white background.
+pub static OKABE_ITO: Colorscheme = Colorscheme {
+    background: Color {
+        r: 255,
+        g: 255,
+        b: 255,
+        a: 255,
+    },
+    text: Color {
+        r: 30,
+        g: 30,
+        b: 30,
+        a: 255,
+    },
+    grid: Color {
+        r: 200,
+        g: 200,
+        b: 200,
+        a: 255,
+    }, // Light neutral gray
+    axis: Color {
+        r: 60,
+        g: 60,
+        b: 60,
+        a: 255,
+    },
+    cycle: Cow::Borrowed(&[
+        Color {
+            r: 230,
+            g: 159,
+            b: 0,
+            a: 255,
+        }, // Orange
+        Color {
+            r: 86,
+            g: 180,
+            b: 233,
+            a: 255,
+        }, // Sky blue
+        Color {
+            r: 0,
+            g: 158,
+            b: 115,
+            a: 255,
+        }, // Bluish green
+        Color {
+            r: 240,
+            g: 228,
+            b: 66,
+            a: 255,
+        }, // Yellow
+        Color {
+            r: 0,
+            g: 114,
+            b: 178,
+            a: 255,
+        }, // Blue
+        Color {
+            r: 213,
+            g: 94,
+            b: 0,
+            a: 255,
+        }, // Vermillion
+        Color {
+            r: 204,
+            g: 121,
+            b: 167,
+            a: 255,
+        }, // Reddish purple
+        Color {
+            r: 0,
+            g: 0,
+            b: 0,
+            a: 255,
+        }, // Black
+    ]),
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rgba(c: Color) -> (u8, u8, u8, u8) {
+        (c.r, c.g, c.b, c.a)
+    }
+
+    #[test]
+    fn cycle_color_wraps_and_survives_empty_cycle() {
+        let scheme = Colorscheme::new(
+            Color::WHITE,
+            Color::GRAY,
+            Color::BLACK,
+            Color::BLACK,
+            vec![Color::RED, Color::BLUE],
+        );
+        assert_eq!(rgba(scheme.cycle_color(3)), rgba(Color::BLUE));
+        let empty = Colorscheme {
+            cycle: Vec::new().into(),
+            ..scheme
+        };
+        assert_eq!(rgba(empty.cycle_color(0)), rgba(Color::BLACK));
+        assert_eq!(rgba(empty.cycle_color(7)), rgba(Color::BLACK));
+    }
+
+    #[test]
+    fn indexed_color_prefers_explicit_colors() {
+        let scheme = Colorscheme::new(
+            Color::WHITE,
+            Color::GRAY,
+            Color::BLACK,
+            Color::BLACK,
+            vec![Color::RED, Color::BLUE],
+        );
+        let explicit = [Color::GREEN, Color::GOLD];
+        assert_eq!(
+            rgba(indexed_color(Some(&explicit), Some(&scheme), 2)),
+            rgba(Color::GREEN)
+        );
+        assert_eq!(
+            rgba(indexed_color(None, Some(&scheme), 1)),
+            rgba(Color::BLUE)
+        );
+        assert_eq!(
+            rgba(indexed_color(Some(&[]), Some(&scheme), 1)),
+            rgba(Color::BLACK)
+        );
+        assert_eq!(rgba(indexed_color(None, None, 0)), rgba(Color::BLACK));
+    }
+
+    #[test]
+    fn from_hex_rejects_short_form() {
+        assert!(from_hex("#fff").is_err());
+    }
+
+    #[test]
+    fn from_hex_rejects_non_hex_digits() {
+        assert!(from_hex("#12345g").is_err());
+        assert!(from_hex("#+12345").is_err());
+    }
+
+    #[test]
+    fn from_hex_parses_six_and_eight_digits() {
+        assert_eq!(rgba(from_hex("#102030").unwrap()), (16, 32, 48, 255));
+        assert_eq!(rgba(from_hex("#10203040").unwrap()), (16, 32, 48, 64));
+    }
+
+    #[test]
+    fn from_hex_is_case_insensitive() {
+        assert_eq!(
+            rgba(from_hex("#ABCDEF").unwrap()),
+            rgba(from_hex("#abcdef").unwrap())
+        );
+    }
+
+    #[test]
+    fn to_hex_round_trips() {
+        for hex in ["#0a0b0c", "#0a0b0c0d"] {
+            assert_eq!(to_hex(from_hex(hex).unwrap()), hex);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn json_round_trips_cycle_exactly() {
+        let json = DRACULA.to_json().unwrap();
+        let scheme = Colorscheme::from_json(&json).unwrap();
+        assert_eq!(scheme.cycle.len(), DRACULA.cycle.len());
+        for (a, b) in scheme.cycle.iter().zip(DRACULA.cycle.iter()) {
+            assert_eq!(rgba(*a), rgba(*b));
+        }
+        assert_eq!(rgba(scheme.grid), rgba(DRACULA.grid));
+    }
+
+    #[test]
+    fn okabe_ito_has_the_full_palette() {
+        assert_eq!(OKABE_ITO.cycle.len(), 8);
+        assert_eq!(rgba(OKABE_ITO.cycle[0]), (230, 159, 0, 255));
+        assert_eq!(rgba(OKABE_ITO.cycle[7]), (0, 0, 0, 255));
+    }
+
+    #[test]
+    fn lerp_hits_both_ends_and_pads_cycle() {
+        let dark = Colorscheme::new(
+            Color::BLACK,
+            Color::BLACK,
+            Color::WHITE,
+            Color::BLACK,
+            vec![Color::RED],
+        );
+        let light = Colorscheme::new(
+            Color::WHITE,
+            Color::WHITE,
+            Color::BLACK,
+            Color::WHITE,
+            vec![Color::BLUE, Color::GREEN],
+        );
+        let start = dark.lerp(&light, -3.0);
+        assert_eq!(rgba(start.background), rgba(Color::BLACK));
+        assert_eq!(rgba(start.cycle[1]), rgba(Color::RED));
+        let end = dark.lerp(&light, 7.0);
+        assert_eq!(rgba(end.text), rgba(Color::BLACK));
+        assert_eq!(end.cycle.len(), 2);
+        assert_eq!(rgba(end.cycle[1]), rgba(Color::GREEN));
+
+        let mid = dark.lerp(&light, 0.5);
+        assert_eq!(rgba(mid.background), (188, 188, 188, 255));
+    }
+
+    #[test]
+    fn with_alpha_fades_every_color() {
+        let faded = DRACULA.with_alpha(0.5);
+        assert_eq!(faded.background.a, 128);
+        assert_eq!(faded.grid.a, 100);
+        assert!(faded.cycle.iter().all(|c| c.a == 128));
+        assert_eq!(faded.cycle.len(), DRACULA.cycle.len());
+        assert_eq!(rgba(DRACULA.background).3, 255);
+        assert_eq!(DRACULA.with_alpha(3.0).grid.a, DRACULA.grid.a);
+    }
+
+    #[test]
+    fn auto_text_contrasts_with_background() {
+        let light = Colorscheme {
+            background: Color::new(250, 240, 200, 255),
+            ..DRACULA.clone()
+        }
+        .with_auto_text();
+        assert_eq!(rgba(light.text), rgba(Color::BLACK));
+        assert_eq!(rgba(light.axis), rgba(Color::BLACK));
+        let dark = NORD.clone().with_auto_text();
+        assert_eq!(rgba(dark.text), rgba(Color::WHITE));
+        assert!((contrast_ratio(Color::BLACK, Color::WHITE) - 21.0).abs() < 1e-3);
+        // Mid gray is still light enough for black text.
+        assert_eq!(
+            rgba(contrasting_color(Color::new(128, 128, 128, 255))),
+            rgba(Color::BLACK)
+        );
+    }
+
+    #[test]
+    fn hsv_round_trips() {
+        for color in [
+            Color::new(230, 80, 40, 255),
+            Color::new(12, 200, 180, 255),
+            Color::new(90, 90, 90, 255),
+            Color::new(255, 0, 255, 255),
+        ] {
+            let (h, s, v) = rgb_to_hsv(color);
+            assert_eq!(rgba(hsv_to_rgb(h, s, v)), rgba(color));
+        }
+        assert_eq!(rgba(hsv_to_rgb(-120.0, 1.0, 1.0)), (0, 0, 255, 255));
+    }
+
+    #[test]
+    fn from_accent_derives_a_readable_scheme() {
+        let accent = Color::new(40, 120, 220, 255);
+        for dark in [true, false] {
+            let scheme = Colorscheme::from_accent(accent, dark);
+            assert_eq!(scheme.cycle.len(), ACCENT_CYCLE_LEN);
+            assert_eq!(rgba(scheme.cycle[0]), rgba(accent));
+            assert!(contrast_ratio(scheme.text, scheme.background) > 7.0);
+            let (hue, _, _) = rgb_to_hsv(scheme.cycle[2]);
+            let (accent_hue, _, _) = rgb_to_hsv(accent);
+            assert!(((hue - accent_hue).rem_euclid(360.0) - 90.0).abs() < 2.0);
+        }
+        assert!(relative_luminance(Colorscheme::from_accent(accent, true).background) < 0.05);
+        assert!(relative_luminance(Colorscheme::from_accent(accent, false).background) > 0.9);
+    }
+
+    #[test]
+    fn srgb_round_trips() {
+        for c in [0, 1, 10, 64, 128, 200, 255] {
+            assert_eq!(linear_to_srgb(srgb_to_linear(c)), c);
+        }
+    }
+
+    #[test]
+    fn colorscheme_from_hex_propagates_errors() {
+        assert!(
+            Colorscheme::from_hex("#000000", "#000000", "#000000", "#000000", &["#zz"]).is_err()
+        );
+    }
+}
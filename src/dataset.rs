@@ -16,9 +16,13 @@
 //! let ds = Dataset::new(vec![(0.0, 1.0), (2.0, 3.0), (4.0, 5.0)]);
 //! assert_eq!(ds.data.len(), 3);
 //! ```
+//!
+//! Datasets can also be collected from any iterator of such values, or
+//! sampled from a function with [`Dataset::from_fn`].
 
-use crate::plottable::point::Datapoint;
-use raylib::prelude::Vector2;
+use crate::plottable::{line::Line, point::Datapoint, view::DataBBox};
+use raylib::prelude::{Color, Vector2};
+use std::{cmp::Ordering, ops::Range};
 
 /// An owned collection of [`Datapoint`]s together with the pre-computed
 /// axis-aligned bounding box of the data.
@@ -40,31 +44,810 @@ impl Dataset {
     /// Create a new `Dataset` from anything convertible into [`Datapoint`]s.
     ///
     /// Accepts `Vec<(f32, f32)>`, `Vec<Vector2>`, or `Vec<Datapoint>` and
-    /// computes the bounding box in a single pass.
+    /// computes the bounding box in a single pass. An empty input yields an
+    /// empty dataset whose range is `0.0..0.0`; use [`Dataset::try_new`] to
+    /// reject empty input instead.
     #[must_use]
     pub fn new(data: Vec<impl Into<Datapoint>>) -> Self {
         let data: Vec<Datapoint> = data
             .into_iter()
             .map(std::convert::Into::into)
             .collect::<Vec<_>>();
-        if data.is_empty() {
-            return Self {
+        match Self::bounds(&data) {
+            Some((range_min, range_max)) => Self {
+                data,
+                range_max,
+                range_min,
+            },
+            None => Self {
                 data,
                 range_max: Vector2::zero(),
                 range_min: Vector2::zero(),
-            };
+            },
+        }
+    }
+
+    /// Fallible variant of [`Dataset::new`] that rejects empty input.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EmptyDataset`] when `data` has no points.
+    ///
+    /// ```rust
+    /// use locus::dataset::{Dataset, EmptyDataset};
+    ///
+    /// assert!(Dataset::try_new(vec![(1.0, 2.0)]).is_ok());
+    /// assert_eq!(Dataset::try_new(Vec::<(f32, f32)>::new()).unwrap_err(), EmptyDataset);
+    /// ```
+    pub fn try_new(data: Vec<impl Into<Datapoint>>) -> Result<Self, EmptyDataset> {
+        if data.is_empty() {
+            return Err(EmptyDataset);
+        }
+        Ok(Self::new(data))
+    }
+
+    /// Sample `f` at `n` evenly spaced x values spanning `domain`, both ends
+    /// included.
+    ///
+    /// Samples where `f` returns a non-finite value (NaN or ±∞) are skipped,
+    /// so the bounds only cover plottable points.
+    ///
+    /// ```rust
+    /// use locus::prelude::*;
+    ///
+    /// let ds = Dataset::from_fn(0.0..std::f32::consts::TAU, 100, f32::sin);
+    /// assert_eq!(ds.data.len(), 100);
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn from_fn(domain: Range<f32>, n: usize, f: impl Fn(f32) -> f32) -> Self {
+        let step = if n > 1 {
+            (domain.end - domain.start) / (n - 1) as f32
+        } else {
+            0.0
+        };
+        (0..n)
+            .map(|i| domain.start + step * i as f32)
+            .map(|x| (x, f(x)))
+            .filter(|(_, y)| y.is_finite())
+            .collect()
+    }
+
+    /// Append a point, growing the bounds to cover it in O(1).
+    ///
+    /// The first point pushed into an empty dataset becomes its bounds,
+    /// replacing the `0.0..0.0` placeholder range. Edits made directly to
+    /// `data` are not tracked; build a new dataset after those.
+    ///
+    /// ```rust
+    /// use locus::prelude::*;
+    /// let mut ds = Dataset::new(Vec::<(f32, f32)>::new());
+    /// ds.push((2.0, 5.0));
+    /// ds.push((4.0, 1.0));
+    /// assert_eq!((ds.range_min.x, ds.range_min.y), (2.0, 1.0));
+    /// assert_eq!((ds.range_max.x, ds.range_max.y), (4.0, 5.0));
+    /// ```
+    pub fn push(&mut self, point: impl Into<Datapoint>) {
+        let point: Datapoint = point.into();
+        if self.data.is_empty() {
+            self.range_min = *point;
+            self.range_max = *point;
+        } else {
+            self.range_min =
+                Vector2::new(self.range_min.x.min(point.x), self.range_min.y.min(point.y));
+            self.range_max =
+                Vector2::new(self.range_max.x.max(point.x), self.range_max.y.max(point.y));
+        }
+        self.data.push(point);
+    }
+
+    /// Append several points, growing the bounds in O(added). See
+    /// [`Dataset::push`].
+    pub fn extend(&mut self, points: impl IntoIterator<Item = impl Into<Datapoint>>) {
+        let points = points.into_iter();
+        self.data.reserve(points.size_hint().0);
+        for point in points {
+            self.push(point);
         }
+    }
+
+    /// The data points as a slice.
+    #[must_use]
+    pub fn points(&self) -> &[Datapoint] {
+        &self.data
+    }
+
+    /// Component-wise minimum of all points.
+    #[must_use]
+    pub fn range_min(&self) -> Vector2 {
+        self.range_min
+    }
+
+    /// Component-wise maximum of all points.
+    #[must_use]
+    pub fn range_max(&self) -> Vector2 {
+        self.range_max
+    }
+
+    /// Number of points in the dataset.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Whether the dataset has no points.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Component-wise minimum of all points (same as `range_min`).
+    #[must_use]
+    pub fn min(&self) -> Datapoint {
+        self.range_min.into()
+    }
+
+    /// Component-wise maximum of all points (same as `range_max`).
+    #[must_use]
+    pub fn max(&self) -> Datapoint {
+        self.range_max.into()
+    }
+
+    /// Smallest finite, strictly positive x and y among the points, `None`
+    /// on an axis without one. Log axes start here.
+    ///
+    /// ```rust
+    /// use locus::prelude::*;
+    /// let data = Dataset::new(vec![(-1.0, 0.0), (0.5, 3.0), (2.0, 0.25)]);
+    /// assert_eq!(data.min_positive(), (Some(0.5), Some(0.25)));
+    /// ```
+    #[must_use]
+    pub fn min_positive(&self) -> (Option<f32>, Option<f32>) {
+        let smallest = |axis: fn(&Datapoint) -> f32| {
+            self.data
+                .iter()
+                .map(axis)
+                .filter(|v| v.is_finite() && *v > 0.0)
+                .min_by(f32::total_cmp)
+        };
+        (smallest(|p| p.x), smallest(|p| p.y))
+    }
 
-        let (min_x, max_x) = data.iter().fold((data[0].x, data[0].x), |acc, p| {
-            (acc.0.min(p.x), acc.1.max(p.x))
+    /// Component-wise arithmetic mean. An empty dataset yields the origin.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn mean(&self) -> Datapoint {
+        if self.data.is_empty() {
+            return Datapoint::new(0.0, 0.0);
+        }
+        let sum = self.data.iter().fold(Vector2::zero(), |acc, p| {
+            Vector2::new(acc.x + p.x, acc.y + p.y)
         });
-        let (min_y, max_y) = data.iter().fold((data[0].y, data[0].y), |acc, p| {
-            (acc.0.min(p.y), acc.1.max(p.y))
+        let n = self.data.len() as f32;
+        Datapoint::new(sum.x / n, sum.y / n)
+    }
+
+    /// Component-wise population standard deviation. An empty dataset yields
+    /// zero.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn std_dev(&self) -> Datapoint {
+        if self.data.is_empty() {
+            return Datapoint::new(0.0, 0.0);
+        }
+        let mean = self.mean();
+        let sq = self.data.iter().fold(Vector2::zero(), |acc, p| {
+            Vector2::new(
+                acc.x + (p.x - mean.x).powi(2),
+                acc.y + (p.y - mean.y).powi(2),
+            )
         });
+        let n = self.data.len() as f32;
+        Datapoint::new((sq.x / n).sqrt(), (sq.y / n).sqrt())
+    }
+
+    /// Median of the x coordinates, or `None` for an empty dataset.
+    #[must_use]
+    pub fn median_x(&self) -> Option<f32> {
+        median(self.data.iter().map(|p| p.x).collect())
+    }
+
+    /// Median of the y coordinates, or `None` for an empty dataset.
+    #[must_use]
+    pub fn median_y(&self) -> Option<f32> {
+        median(self.data.iter().map(|p| p.y).collect())
+    }
+
+    /// A copy of the dataset with each axis min-max scaled to `[0, 1]`.
+    ///
+    /// An axis with zero range is left unchanged.
+    #[must_use]
+    pub fn normalized(&self) -> Self {
+        let span = self.range_max - self.range_min;
+        self.map_axes(
+            |x| axis_scale(x, self.range_min.x, span.x),
+            |y| axis_scale(y, self.range_min.y, span.y),
+        )
+    }
+
+    /// A copy of the dataset with each axis shifted to zero mean and scaled
+    /// to unit variance.
+    ///
+    /// An axis with zero variance is left unchanged.
+    #[must_use]
+    pub fn standardized(&self) -> Self {
+        let mean = self.mean();
+        let std = self.std_dev();
+        self.map_axes(
+            |x| axis_scale(x, mean.x, std.x),
+            |y| axis_scale(y, mean.y, std.y),
+        )
+    }
+
+    /// A copy of the dataset with each y replaced by the centered mean of the
+    /// `window` points around it, in storage order. x values are kept.
+    ///
+    /// Near the ends the window shrinks to the points that exist rather than
+    /// dropping them, so the result has the same length. A `window` of `0`
+    /// or `1` returns the data unchanged.
+    ///
+    /// ```rust
+    /// use locus::prelude::*;
+    /// let ds = Dataset::new(vec![(0.0, 0.0), (1.0, 3.0), (2.0, 0.0), (3.0, 3.0)]);
+    /// let smooth = ds.moving_average(3);
+    /// assert_eq!(smooth.data[1].y, 1.0);
+    /// assert_eq!(smooth.data[0].y, 1.5);
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn moving_average(&self, window: usize) -> Self {
+        let window = window.max(1);
+        let (before, after) = ((window - 1) / 2, window / 2);
+        let n = self.data.len();
+        (0..n)
+            .map(|i| {
+                let neighbours = &self.data[i.saturating_sub(before)..(i + after + 1).min(n)];
+                let mean = neighbours.iter().map(|p| p.y).sum::<f32>() / neighbours.len() as f32;
+                (self.data[i].x, mean)
+            })
+            .collect()
+    }
+
+    /// A copy of the dataset smoothed with an exponentially weighted moving
+    /// average, in storage order. x values are kept.
+    ///
+    /// Each smoothed y is `alpha * y + (1 - alpha) * previous`, starting from
+    /// the first point. `alpha` is clamped to `0.0..=1.0`; `1.0` returns the
+    /// data unchanged and smaller values smooth more.
+    #[must_use]
+    pub fn ewma(&self, alpha: f32) -> Self {
+        let alpha = alpha.clamp(0.0, 1.0);
+        let mut previous = None;
+        self.data
+            .iter()
+            .map(|p| {
+                let y = match previous {
+                    Some(prev) => alpha * p.y + (1.0 - alpha) * prev,
+                    None => p.y,
+                };
+                previous = Some(y);
+                (p.x, y)
+            })
+            .collect()
+    }
+
+    /// Sort the points by x in place, as line and area plots expect.
+    ///
+    /// The sort is stable, so points sharing an x keep their relative order.
+    /// Points with a `NaN` x, of either sign, end up last, and `-0.0` ties
+    /// with `0.0`. The ranges are unaffected.
+    ///
+    /// ```rust
+    /// use locus::prelude::*;
+    /// let mut ds = Dataset::new(vec![(2.0, 0.0), (0.0, 1.0), (1.0, 2.0), (0.0, 3.0)]);
+    /// ds.sort_by_x();
+    /// let order: Vec<(f32, f32)> = ds.data.iter().map(|p| (p.x, p.y)).collect();
+    /// assert_eq!(order, vec![(0.0, 1.0), (0.0, 3.0), (1.0, 2.0), (2.0, 0.0)]);
+    /// ```
+    pub fn sort_by_x(&mut self) {
+        // NaNs compare greater than every number and equal to each other;
+        // `-0.0` and `0.0` compare equal, so neither reorders ties.
+        self.data
+            .sort_by(|a, b| match (a.x.is_nan(), b.x.is_nan()) {
+                (false, false) => a.x.partial_cmp(&b.x).unwrap_or(Ordering::Equal),
+                (nan_a, nan_b) => nan_a.cmp(&nan_b),
+            });
+    }
+
+    /// A copy of the dataset sorted by x; see [`Dataset::sort_by_x`].
+    #[must_use]
+    pub fn sorted_by_x(&self) -> Self {
+        let mut sorted = self.clone();
+        sorted.sort_by_x();
+        sorted
+    }
+
+    /// A new dataset with `fx` / `fy` applied to every x / y coordinate.
+    fn map_axes(&self, fx: impl Fn(f32) -> f32, fy: impl Fn(f32) -> f32) -> Self {
+        self.data.iter().map(|p| (fx(p.x), fy(p.y))).collect()
+    }
+
+    /// Component-wise `(min, max)` of `data`, or `None` when it is empty.
+    fn bounds(data: &[Datapoint]) -> Option<(Vector2, Vector2)> {
+        let first = data.first()?;
+        let init = (
+            Vector2::new(first.x, first.y),
+            Vector2::new(first.x, first.y),
+        );
+        Some(data.iter().fold(init, |(min, max), p| {
+            (
+                Vector2::new(min.x.min(p.x), min.y.min(p.y)),
+                Vector2::new(max.x.max(p.x), max.y.max(p.y)),
+            )
+        }))
+    }
+}
+
+impl IntoIterator for Dataset {
+    type Item = Datapoint;
+    type IntoIter = std::vec::IntoIter<Datapoint>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Dataset {
+    type Item = &'a Datapoint;
+    type IntoIter = std::slice::Iter<'a, Datapoint>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter()
+    }
+}
+
+/// A named [`Dataset`], optionally with its own color, as one entry of a
+/// [`MultiSeries`].
+#[derive(Debug, Clone)]
+pub struct Series {
+    /// Series name, used for legend entries.
+    pub name: String,
+    /// The series' points.
+    pub data: Dataset,
+    /// Explicit color. `None` picks the next theme cycle color by index.
+    pub color: Option<Color>,
+}
+
+impl Series {
+    /// Create a series named `name` over `data`, colored from the theme.
+    #[must_use]
+    pub fn new(name: impl Into<String>, data: Dataset) -> Self {
         Self {
+            name: name.into(),
             data,
-            range_max: Vector2 { x: max_x, y: max_y },
-            range_min: Vector2 { x: min_x, y: min_y },
+            color: None,
         }
     }
+
+    /// Give the series a fixed color instead of a theme cycle color.
+    #[must_use]
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+}
+
+/// An ordered collection of named [`Series`] drawn in one chart, e.g. by
+/// [`MultiScatter`](crate::plottable::series::MultiScatter) or
+/// [`MultiLine`](crate::plottable::series::MultiLine).
+///
+/// ```rust
+/// use locus::prelude::*;
+/// let all = MultiSeries::new(vec![
+///     Series::new("train", Dataset::new(vec![(0.0, 1.0), (1.0, 0.5)])),
+///     Series::new("test", Dataset::new(vec![(0.0, 1.2), (2.0, 0.7)])),
+/// ]);
+/// let bounds = all.data_bounds();
+/// assert_eq!((bounds.maximum.x, bounds.maximum.y), (2.0, 1.2));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MultiSeries {
+    /// The series, in drawing and legend order.
+    pub series: Vec<Series>,
+}
+
+impl MultiSeries {
+    /// Create a collection from its series.
+    #[must_use]
+    pub fn new(series: Vec<Series>) -> Self {
+        Self { series }
+    }
+
+    /// Append a series, returning self for chaining.
+    #[must_use]
+    pub fn with_series(mut self, series: Series) -> Self {
+        self.series.push(series);
+        self
+    }
+
+    /// Bounding box of every non-empty series combined. Empty when no
+    /// series has points.
+    #[must_use]
+    pub fn data_bounds(&self) -> DataBBox {
+        self.series
+            .iter()
+            .filter(|s| !s.data.is_empty())
+            .map(|s| DataBBox::from_min_max(s.data.range_min, s.data.range_max))
+            .reduce(|a, b| a.union(&b))
+            .unwrap_or_else(|| DataBBox::from_min_max((0.0, 0.0), (0.0, 0.0)))
+    }
+}
+
+/// Ordinary least-squares fit of `y = slope * x + intercept`.
+///
+/// Returns `(slope, intercept, r2)`, where `r2` is the coefficient of
+/// determination. A dataset whose y values are all equal is fitted exactly,
+/// so its `r2` is `1.0`. Returns `None` for fewer than two points or when
+/// every x is the same, since a vertical line has no finite slope.
+///
+/// Values count as all the same when they spread by less than the `f32`
+/// rounding of their magnitude, so the check holds for tiny and huge
+/// coordinates alike.
+///
+/// ```rust
+/// use locus::prelude::*;
+/// let ds = Dataset::new(vec![(0.0, 1.0), (1.0, 3.0), (2.0, 5.0)]);
+/// let (slope, intercept, r2) = linear_fit(&ds).unwrap();
+/// assert!((slope - 2.0).abs() < 1e-6 && (intercept - 1.0).abs() < 1e-6);
+/// assert!((r2 - 1.0).abs() < 1e-6);
+/// ```
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn linear_fit(data: &Dataset) -> Option<(f32, f32, f32)> {
+    if data.len() < 2 {
+        return None;
+    }
+    let mean = data.mean();
+    let (sxx, sxy, syy) = data
+        .data
+        .iter()
+        .fold((0.0, 0.0, 0.0), |(sxx, sxy, syy), p| {
+            let (dx, dy) = (p.x - mean.x, p.y - mean.y);
+            (sxx + dx * dx, sxy + dx * dy, syy + dy * dy)
+        });
+    // Squared deviations indistinguishable from rounding the largest value.
+    let flat = |sum_sq: f32, min: f32, max: f32| {
+        let ulp = f32::EPSILON * min.abs().max(max.abs());
+        sum_sq <= data.len() as f32 * ulp * ulp
+    };
+    if flat(sxx, data.range_min.x, data.range_max.x) {
+        return None;
+    }
+    let slope = sxy / sxx;
+    let intercept = mean.y - slope * mean.x;
+    let r2 = if flat(syy, data.range_min.y, data.range_max.y) {
+        1.0
+    } else {
+        (sxy * sxy) / (sxx * syy)
+    };
+    Some((slope, intercept, r2))
+}
+
+/// The least-squares trend line of `data`, spanning its x range.
+///
+/// The returned [`Line`] is a chart element, so it can be layered over a
+/// scatter plot. Returns `None` in the same cases as [`linear_fit`].
+///
+/// ```rust
+/// use locus::prelude::*;
+/// # let ds = Dataset::new(vec![(0.0, 1.0), (1.0, 3.0), (2.0, 5.0)]);
+/// let trend = trend_line(&ds).unwrap();
+/// let layers = Layers::new()
+///     .with_layer(ScatterPlot::new(&ds), ScatterPlotConfig::default())
+///     .with_layer(
+///         trend,
+///         LineConfigBuilder::default()
+///             .arrow(Visibility::Invisible)
+///             .build()
+///             .unwrap(),
+///     );
+/// ```
+#[must_use]
+pub fn trend_line(data: &Dataset) -> Option<Line> {
+    let (slope, intercept, _) = linear_fit(data)?;
+    let (x0, x1) = (data.range_min.x, data.range_max.x);
+    Some(Line::new(
+        (x0, slope * x0 + intercept),
+        (x1, slope * x1 + intercept),
+    ))
+}
+
+/// `(value - offset) / scale`, or `value` unchanged for a degenerate scale.
+fn axis_scale(value: f32, offset: f32, scale: f32) -> f32 {
+    if scale.abs() > f32::EPSILON {
+        (value - offset) / scale
+    } else {
+        value
+    }
+}
+
+/// Median of `values`, averaging the two middle values for even lengths.
+fn median(mut values: Vec<f32>) -> Option<f32> {
+    if values.is_empty() {
+        return None;
+    }
+    let even = values.len() % 2 == 0;
+    let mid = values.len() / 2;
+    let (lower, upper, _) = values.select_nth_unstable_by(mid, f32::total_cmp);
+    if even {
+        // `lower` holds the `mid` smallest values; its maximum is the other
+        // middle element.
+        let below = lower.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+        Some((below + *upper) * 0.5)
+    } else {
+        Some(*upper)
+    }
+}
+
+/// Collect any iterator of point-like values into a `Dataset`.
+///
+/// ```rust
+/// use locus::prelude::*;
+///
+/// let ds: Dataset = (0..5).map(|i| (i as f32, (i * i) as f32)).collect();
+/// assert_eq!(ds.range_max.y, 16.0);
+/// ```
+impl<T: Into<Datapoint>> FromIterator<T> for Dataset {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::new(iter.into_iter().collect::<Vec<_>>())
+    }
+}
+
+/// Append point-like values through the standard [`Extend`] trait, keeping
+/// the bounds up to date like [`Dataset::extend`].
+///
+/// ```rust
+/// use locus::prelude::*;
+///
+/// let mut ds = Dataset::new(vec![(0.0, 0.0)]);
+/// Extend::extend(&mut ds, [(2.0, -1.0)]);
+/// assert_eq!(ds.range_min.y, -1.0);
+/// ```
+impl<T: Into<Datapoint>> Extend<T> for Dataset {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        Dataset::extend(self, iter);
+    }
+}
+
+/// Error returned by [`Dataset::try_new`] when given no data points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EmptyDataset;
+
+impl std::fmt::Display for EmptyDataset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "EmptyDataset: a dataset needs at least one point")
+    }
+}
+
+impl std::error::Error for EmptyDataset {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sorted_by_x_is_stable_and_keeps_the_original() {
+        let ds = Dataset::new(vec![(3.0, 0.0), (f32::NAN, 9.0), (1.0, 1.0), (1.0, 2.0)]);
+        let sorted = ds.sorted_by_x();
+        let ys: Vec<f32> = sorted.data.iter().map(|p| p.y).collect();
+        assert_eq!(ys, vec![1.0, 2.0, 0.0, 9.0]);
+        assert_eq!(ds.data[0].x, 3.0);
+        assert_eq!(sorted.range_max.x, ds.range_max.x);
+    }
+
+    #[test]
+    fn sort_by_x_puts_negative_nan_last_and_ties_signed_zeros() {
+        let mut ds = Dataset::new(vec![(-f32::NAN, 0.0), (0.0, 1.0), (-0.0, 2.0), (-1.0, 3.0)]);
+        ds.sort_by_x();
+        let ys: Vec<f32> = ds.data.iter().map(|p| p.y).collect();
+        assert_eq!(ys, vec![3.0, 1.0, 2.0, 0.0]);
+    }
+
+    #[test]
+    fn empty_input_has_zero_range() {
+        let ds = Dataset::new(Vec::<(f32, f32)>::new());
+        assert!(ds.data.is_empty());
+        assert_eq!((ds.range_min.x, ds.range_min.y), (0.0, 0.0));
+        assert_eq!((ds.range_max.x, ds.range_max.y), (0.0, 0.0));
+        assert_eq!(
+            Dataset::try_new(Vec::<(f32, f32)>::new()).unwrap_err(),
+            EmptyDataset
+        );
+    }
+
+    #[test]
+    fn push_and_extend_track_bounds() {
+        let mut ds = Dataset::new(Vec::<(f32, f32)>::new());
+        ds.push((-1.0, 3.0));
+        assert_eq!((ds.range_min.x, ds.range_min.y), (-1.0, 3.0));
+        assert_eq!((ds.range_max.x, ds.range_max.y), (-1.0, 3.0));
+        ds.extend(vec![(2.0, -4.0), (0.5, 8.0)]);
+        let full = Dataset::new(ds.data.clone());
+        assert_eq!(ds.len(), 3);
+        assert_eq!(
+            (ds.range_min.x, ds.range_min.y),
+            (full.range_min.x, full.range_min.y)
+        );
+        assert_eq!(
+            (ds.range_max.x, ds.range_max.y),
+            (full.range_max.x, full.range_max.y)
+        );
+    }
+
+    #[test]
+    fn single_element_range_is_the_point() {
+        let ds = Dataset::try_new(vec![(3.0, -2.0)]).unwrap();
+        assert_eq!((ds.range_min.x, ds.range_min.y), (3.0, -2.0));
+        assert_eq!((ds.range_max.x, ds.range_max.y), (3.0, -2.0));
+    }
+
+    #[test]
+    fn range_covers_all_points() {
+        let ds = Dataset::new(vec![(1.0, 5.0), (-4.0, 2.0), (3.0, -1.0)]);
+        assert_eq!((ds.range_min.x, ds.range_min.y), (-4.0, -1.0));
+        assert_eq!((ds.range_max.x, ds.range_max.y), (3.0, 5.0));
+    }
+
+    #[test]
+    fn from_fn_includes_both_ends_and_skips_non_finite() {
+        let ds = Dataset::from_fn(0.0..4.0, 5, |x| x * 2.0);
+        let xs: Vec<f32> = ds.data.iter().map(|p| p.x).collect();
+        assert_eq!(xs, vec![0.0, 1.0, 2.0, 3.0, 4.0]);
+        assert_eq!((ds.range_min.y, ds.range_max.y), (0.0, 8.0));
+
+        let ds = Dataset::from_fn(-1.0..1.0, 3, |x| 1.0 / x);
+        assert_eq!(ds.data.len(), 2);
+        assert_eq!((ds.range_min.y, ds.range_max.y), (-1.0, 1.0));
+    }
+
+    #[test]
+    fn summary_statistics() {
+        let ds = Dataset::new(vec![(1.0, 10.0), (2.0, 10.0), (3.0, 40.0), (6.0, 20.0)]);
+        assert_eq!(ds.len(), 4);
+        let mean = ds.mean();
+        assert_eq!((mean.x, mean.y), (3.0, 20.0));
+        let std = ds.std_dev();
+        assert!((std.x - 3.5_f32.sqrt()).abs() < 1e-5);
+        assert!((std.y - 150.0_f32.sqrt()).abs() < 1e-4);
+        assert_eq!(ds.median_x(), Some(2.5));
+        assert_eq!(ds.median_y(), Some(15.0));
+        assert_eq!(
+            Dataset::new(vec![(5.0, 1.0), (1.0, 2.0), (3.0, 3.0)]).median_x(),
+            Some(3.0)
+        );
+        assert_eq!(Dataset::new(Vec::<(f32, f32)>::new()).median_y(), None);
+    }
+
+    #[test]
+    fn normalized_maps_range_to_unit_square() {
+        let ds = Dataset::new(vec![(2.0, 5.0), (4.0, 5.0), (6.0, 5.0)]);
+        let norm = ds.normalized();
+        let xs: Vec<f32> = norm.data.iter().map(|p| p.x).collect();
+        assert_eq!(xs, vec![0.0, 0.5, 1.0]);
+        // Constant y has no range and is left alone.
+        assert!(norm.data.iter().all(|p| (p.y - 5.0).abs() < 1e-6));
+        assert_eq!((norm.range_min.x, norm.range_max.x), (0.0, 1.0));
+        // The original is untouched.
+        assert_eq!(ds.range_max.x, 6.0);
+    }
+
+    #[test]
+    fn standardized_has_zero_mean_unit_variance() {
+        let ds = Dataset::new(vec![(1.0, 3.0), (2.0, 3.0), (3.0, 3.0), (6.0, 3.0)]);
+        let std = ds.standardized();
+        let (mean, dev) = (std.mean(), std.std_dev());
+        assert!(mean.x.abs() < 1e-5);
+        assert!((dev.x - 1.0).abs() < 1e-5);
+        assert!(std.data.iter().all(|p| (p.y - 3.0).abs() < 1e-6));
+    }
+
+    #[test]
+    fn collects_from_iterator() {
+        let ds: Dataset = [(1.0, 2.0), (3.0, -4.0)].into_iter().collect();
+        assert_eq!(ds.data.len(), 2);
+        assert_eq!((ds.range_min.x, ds.range_min.y), (1.0, -4.0));
+    }
+
+    #[test]
+    fn random_points_fall_within_range() {
+        use rand::{Rng, SeedableRng, rngs::StdRng};
+        let mut rng = StdRng::seed_from_u64(17);
+        let points: Vec<(f32, f32)> = (0..500)
+            .map(|_| (rng.random_range(-50.0..50.0), rng.random_range(-5.0..5.0)))
+            .collect();
+        let ds = Dataset::new(points);
+        for p in &ds.data {
+            assert!(ds.range_min.x <= p.x && p.x <= ds.range_max.x);
+            assert!(ds.range_min.y <= p.y && p.y <= ds.range_max.y);
+        }
+        assert!(ds.data.iter().any(|p| (p.x - ds.range_min.x).abs() < 1e-6));
+        assert!(ds.data.iter().any(|p| (p.y - ds.range_max.y).abs() < 1e-6));
+    }
+
+    #[test]
+    fn linear_fit_reports_r2_and_rejects_vertical_data() {
+        let ds = Dataset::new(vec![(0.0, 0.0), (1.0, 2.0), (2.0, 1.0), (3.0, 3.0)]);
+        let (slope, intercept, r2) = linear_fit(&ds).unwrap();
+        assert!((slope - 0.8).abs() < 1e-5);
+        assert!((intercept - 0.3).abs() < 1e-5);
+        assert!((r2 - 0.64).abs() < 1e-5);
+
+        let vertical = Dataset::new(vec![(1.0, 0.0), (1.0, 5.0)]);
+        assert!(linear_fit(&vertical).is_none());
+        assert!(trend_line(&vertical).is_none());
+    }
+
+    #[test]
+    fn linear_fit_tolerance_scales_with_the_data() {
+        // Far below an absolute epsilon, but a clear slope.
+        let tiny = Dataset::new(vec![(0.0, 0.0), (1e-4, 2e-4), (2e-4, 4e-4)]);
+        let (slope, _, r2) = linear_fit(&tiny).unwrap();
+        assert!((slope - 2.0).abs() < 1e-3);
+        assert!((r2 - 1.0).abs() < 1e-3);
+
+        let far = Dataset::new(vec![(1e6, 0.0), (1e6 + 1.0, 1.0), (1e6 + 2.0, 2.0)]);
+        let (slope, _, _) = linear_fit(&far).unwrap();
+        assert!((slope - 1.0).abs() < 1e-3);
+
+        let far_vertical = Dataset::new(vec![(1e6, 0.0), (1e6, 5.0)]);
+        assert!(linear_fit(&far_vertical).is_none());
+    }
+
+    #[test]
+    fn trend_line_spans_x_range() {
+        let ds = Dataset::new(vec![(-1.0, -1.0), (0.5, 0.5), (4.0, 4.0)]);
+        let line = trend_line(&ds).unwrap();
+        assert!((line.from.x + 1.0).abs() < 1e-6 && (line.from.y + 1.0).abs() < 1e-5);
+        assert!((line.to.x - 4.0).abs() < 1e-6 && (line.to.y - 4.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn moving_average_shrinks_at_edges() {
+        let ds = Dataset::new(vec![
+            (0.0, 1.0),
+            (1.0, 2.0),
+            (2.0, 3.0),
+            (3.0, 4.0),
+            (4.0, 10.0),
+        ]);
+        let smooth = ds.moving_average(3);
+        let ys: Vec<f32> = smooth.data.iter().map(|p| p.y).collect();
+        assert_eq!(ys, vec![1.5, 2.0, 3.0, 17.0 / 3.0, 7.0]);
+        assert!(
+            smooth
+                .data
+                .iter()
+                .zip(&ds.data)
+                .all(|(a, b)| (a.x - b.x).abs() < 1e-6)
+        );
+        assert_eq!(ds.moving_average(1).data.len(), ds.len());
+    }
+
+    #[test]
+    fn ewma_weights_recent_points() {
+        let ds = Dataset::new(vec![(0.0, 0.0), (1.0, 10.0), (2.0, 10.0)]);
+        let smooth = ds.ewma(0.5);
+        let ys: Vec<f32> = smooth.data.iter().map(|p| p.y).collect();
+        assert_eq!(ys, vec![0.0, 5.0, 7.5]);
+    }
+
+    #[test]
+    fn accessors_and_iteration_match_fields() {
+        let ds = Dataset::new(vec![(1.0, 5.0), (-2.0, 3.0)]);
+        assert_eq!(ds.points().len(), 2);
+        assert_eq!((ds.range_min().x, ds.range_max().y), (-2.0, 5.0));
+        let xs: Vec<f32> = (&ds).into_iter().map(|p| p.x).collect();
+        assert_eq!(xs, vec![1.0, -2.0]);
+        assert_eq!(ds.into_iter().count(), 2);
+    }
 }
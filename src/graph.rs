@@ -62,16 +62,27 @@ use crate::{
     TextLabel,
     colorscheme::{Colorscheme, Themable},
     plottable::{
-        annotation::{Annotation, AnnotationConfig},
+        annotation::{Annotation, AnnotationConfig, avoid_overlaps},
         legend::{Legend, LegendConfig, LegendEntry},
-        line::{Axis, AxisConfigs, GridLines, GridLinesConfig, TickLabels, TickLabelsConfig},
-        point::Datapoint,
-        text::{Anchor, TextStyle, TextStyleBuilder},
-        view::{ScreenBBox, ViewTransformer, Viewport},
+        line::{
+            Axis, AxisConfigs, AxisTicks, GridLines, GridLinesConfig, TickLabels, TickLabelsConfig,
+        },
+        point::{Datapoint, Screenpoint},
+        reference::{HLine, ReferenceLineConfig, Region, RegionConfig, VLine},
+        text::{Anchor, HAlign, TextStyle, TextStyleBuilder, VAlign},
+        ticks::Scale,
+        view::{DataBBox, Margins, ScreenBBox, ViewTransformer, Viewport},
     },
     plotter::{ChartElement, PlotElement},
 };
-use raylib::prelude::RaylibScissorModeExt;
+use raylib::{
+    RaylibHandle,
+    color::Color,
+    ffi,
+    math::{Rectangle, Vector2},
+    prelude::{RaylibDraw, RaylibDrawHandle, RaylibScissorModeExt},
+    text::WeakFont,
+};
 /// Represents a graph over `subject`, orchestrating elements such as axes,
 /// grid lines, tick marks, labels, legends, and annotations.
 ///
@@ -79,8 +90,11 @@ use raylib::prelude::RaylibScissorModeExt;
 /// be drawn with a single call to [`plot`](PlotElement::plot). Internally it
 /// constructs a [`ViewTransformer`] from the subject's data bounds (or the
 /// explicit axis bounds) and the configured [`Viewport`], then renders each
-/// sub-element in the correct order (background grid, data, axes, ticks,
-/// labels, legend, annotations).
+/// sub-element in the correct order (plot background, grid, shaded regions,
+/// data, reference lines, plot border, axes, ticks, labels, legend,
+/// annotations). When the subject is a
+/// [`Layers`](crate::plottable::layers::Layers) stack, its z-indices order
+/// the layers within the data step only.
 ///
 /// Configuration is provided through [`GraphConfig`], which is most
 /// conveniently built via [`GraphBuilder`].
@@ -103,6 +117,160 @@ where
     }
 }
 
+impl<T> Graph<T>
+where
+    T: ChartElement,
+    <T as ChartElement>::Config: Default + Themable,
+{
+    /// The transform [`plot`](PlotElement::plot) draws with under
+    /// `configs`, computed without drawing anything.
+    ///
+    /// Compute it once per frame and use it both for hit-testing (e.g.
+    /// [`ViewTransformer::to_data`] on the cursor, a
+    /// [`Crosshair`](crate::plottable::interaction::Crosshair), or a
+    /// [`BoxSelect`](crate::plottable::interaction::BoxSelect)) and for
+    /// drawing, so the two can never disagree.
+    ///
+    /// ```rust,no_run
+    /// use locus::prelude::*;
+    /// # let (mut rl, thread) = raylib::init().build();
+    /// # let data = Dataset::new(vec![(0.0, 0.0), (1.0, 1.0)]);
+    /// let graph = Graph::new(ScatterPlot::new(&data));
+    /// let config = GraphBuilder::default().build().unwrap();
+    /// let view = graph.view_transformer(&config);
+    /// let under_cursor = view.to_data(&Screenpoint::from(rl.get_mouse_position()));
+    /// let mut d = rl.begin_drawing(&thread);
+    /// graph.plot(&mut d, &config);
+    /// ```
+    #[must_use]
+    pub fn view_transformer(&self, configs: &GraphConfig<T>) -> ViewTransformer {
+        // The view is built from the screen-bounds, given by the configs, and
+        // the data-bounds, given by the axis or `subject.data_bounds()`.
+        let screen = configs.viewport;
+        let mut data_bbox = if let Some(axis) = &configs.axis {
+            axis.element.data_bounds()
+        } else {
+            self.subject.data_bounds()
+        };
+        // A log axis reaching zero or below starts at the smallest positive data value.
+        if let Some(ticks) = &configs.ticks {
+            let (min_x, min_y) = self.subject.min_positive();
+            if matches!(ticks.configs.x_axis_scale, Scale::Log { .. })
+                && data_bbox.minimum.x <= 0.0
+                && let Some(min_x) = min_x
+            {
+                data_bbox.minimum.x = min_x;
+            }
+            if matches!(ticks.configs.y_axis_scale, Scale::Log { .. })
+                && data_bbox.minimum.y <= 0.0
+                && let Some(min_y) = min_y
+            {
+                data_bbox.minimum.y = min_y;
+            }
+        }
+        let inner = screen.inner_bbox();
+        let mut inner_viewport = Viewport::new(
+            inner.minimum.x,
+            inner.minimum.y,
+            inner.width(),
+            inner.height(),
+        );
+        if configs.equal_aspect && data_bbox.height() > 0.0 {
+            inner_viewport = inner_viewport.letterboxed(data_bbox.width() / data_bbox.height());
+        }
+        let mut view = ViewTransformer::new(data_bbox, inner_viewport)
+            .with_inverted_x(configs.invert_x)
+            .with_inverted_y(configs.invert_y);
+        // Space the data like the tick labels, so points line up with log ticks.
+        if let Some(ticks) = &configs.ticks {
+            view = view
+                .with_x_scale(ticks.configs.x_axis_scale)
+                .with_y_scale(ticks.configs.y_axis_scale);
+        }
+        // The secondary axis shares the primary x range and only brings its own y range.
+        let secondary_y = match (&configs.secondary_axis, &configs.secondary_ticks) {
+            (Some(axis), _) => Some(axis.element.data_bounds()),
+            (None, Some(ticks)) => Some(ticks.element.data_bounds()),
+            (None, None) => None,
+        };
+        if let Some(secondary_y) = secondary_y {
+            view = view.with_secondary_bounds(DataBBox::from_min_max(
+                (data_bbox.minimum.x, secondary_y.minimum.y),
+                (data_bbox.maximum.x, secondary_y.maximum.y),
+            ));
+        }
+        if let Some(ticks) = &configs.secondary_ticks {
+            view = view.with_secondary_y_scale(ticks.configs.y_axis_scale);
+        }
+        view
+    }
+
+    /// Draw everything that lives inside the plotting area: the grid,
+    /// regions, subject, and reference lines.
+    fn draw_plot_area(
+        &self,
+        rl: &mut RaylibDrawHandle,
+        configs: &GraphConfig<T>,
+        view: &ViewTransformer,
+        ticks: Option<&AxisTicks>,
+    ) {
+        // We have all the necessary parts for constructing the graph. With that is a job of
+        // seeing what we have and what don't.
+        if let Some(grid) = &configs.grid {
+            match ticks {
+                Some(ticks) => {
+                    grid.element.draw_with_ticks(rl, &grid.configs, view, ticks);
+                }
+                None => grid.draw_in_view(rl, view),
+            }
+        }
+        for region in &configs.regions {
+            region.draw_in_view(rl, view);
+        }
+
+        // We plot the subject inside the view.
+        self.subject
+            .draw_in_view(rl, &configs.subject_configs, view);
+        for hline in &configs.hlines {
+            hline.draw_in_view(rl, view);
+        }
+        for vline in &configs.vlines {
+            vline.draw_in_view(rl, view);
+        }
+    }
+}
+
+/// Wraps a chart element so that it is projected against the secondary
+/// y-axis of the graph (see [`GraphBuilder::secondary_axis`]).
+///
+/// When the graph has no secondary axis the element is drawn in the primary
+/// view. Its [`data_bounds`](ChartElement::data_bounds) are those of the
+/// wrapped element, expressed in secondary units.
+#[derive(Debug, Clone)]
+pub struct Secondary<E>(pub E);
+
+impl<E: ChartElement> ChartElement for Secondary<E> {
+    type Config = E::Config;
+
+    fn draw_in_view(
+        &self,
+        rl: &mut raylib::prelude::RaylibDrawHandle,
+        configs: &Self::Config,
+        view: &ViewTransformer,
+    ) {
+        let secondary = view.secondary().unwrap_or(*view);
+        self.0.draw_in_view(rl, configs, &secondary);
+    }
+
+    fn data_bounds(&self) -> DataBBox {
+        self.0.data_bounds()
+    }
+
+    fn min_positive(&self) -> (Option<f32>, Option<f32>) {
+        self.0.min_positive()
+    }
+}
+
 /// A visual element paired with its configuration.
 ///
 /// `ConfiguredElement` binds any drawable element (`E`) to the configuration
@@ -200,10 +368,24 @@ where
     colorscheme: Colorscheme,
     ticks: Option<ConfiguredElement<TickLabels, TickLabelsConfig>>,
     title: Option<ConfiguredElement<TextLabel, TextStyle>>,
+    subtitle: Option<ConfiguredElement<TextLabel, TextStyle>>,
+    caption: Option<ConfiguredElement<TextLabel, TextStyle>>,
     xlabel: Option<ConfiguredElement<TextLabel, TextStyle>>,
     ylabel: Option<ConfiguredElement<TextLabel, TextStyle>>,
     legend: Option<ConfiguredElement<Legend, LegendConfig>>,
     annotations: Option<Vec<ConfiguredElement<Annotation, AnnotationConfig>>>,
+    invert_x: bool,
+    invert_y: bool,
+    equal_aspect: bool,
+    secondary_axis: Option<ConfiguredElement<Axis, AxisConfigs>>,
+    secondary_ticks: Option<ConfiguredElement<TickLabels, TickLabelsConfig>>,
+    hlines: Vec<ConfiguredElement<HLine, ReferenceLineConfig>>,
+    vlines: Vec<ConfiguredElement<VLine, ReferenceLineConfig>>,
+    regions: Vec<ConfiguredElement<Region, RegionConfig>>,
+    avoid_overlap: bool,
+    plot_background: Option<Color>,
+    plot_border: Option<(Color, f32)>,
+    clip_data: bool,
 }
 
 /// Error returned when [`GraphBuilder::build`] fails due to missing or
@@ -287,10 +469,26 @@ where
     colorscheme: Option<Cow<'static, Colorscheme>>,
     ticks: Option<ConfiguredElement<TickLabels, TickLabelsConfig>>,
     title: Option<(String, TextStyle)>,
+    subtitle: Option<(String, TextStyle)>,
+    caption: Option<(String, TextStyle)>,
     xlabel: Option<(String, TextStyle)>,
     ylabel: Option<(String, TextStyle)>,
     legend: Option<ConfiguredElement<Legend, LegendConfig>>,
     annotations: Option<Vec<ConfiguredElement<Annotation, AnnotationConfig>>>,
+    invert_x: bool,
+    invert_y: bool,
+    equal_aspect: bool,
+    secondary_axis: Option<ConfiguredElement<Axis, AxisConfigs>>,
+    secondary_ticks: Option<ConfiguredElement<TickLabels, TickLabelsConfig>>,
+    hlines: Vec<ConfiguredElement<HLine, ReferenceLineConfig>>,
+    vlines: Vec<ConfiguredElement<VLine, ReferenceLineConfig>>,
+    regions: Vec<ConfiguredElement<Region, RegionConfig>>,
+    avoid_overlap: bool,
+    auto_margins: Option<WeakFont>,
+    fit_font: Option<WeakFont>,
+    plot_background: Option<Color>,
+    plot_border: Option<(Color, f32)>,
+    clip_data: bool,
 }
 
 impl<T> Default for GraphBuilder<T>
@@ -307,10 +505,26 @@ where
             colorscheme: None,
             ticks: None,
             title: None,
+            subtitle: None,
+            caption: None,
             xlabel: None,
             ylabel: None,
             legend: None,
             annotations: None,
+            invert_x: false,
+            invert_y: false,
+            equal_aspect: false,
+            secondary_axis: None,
+            secondary_ticks: None,
+            hlines: Vec::new(),
+            vlines: Vec::new(),
+            regions: Vec::new(),
+            avoid_overlap: false,
+            auto_margins: None,
+            fit_font: None,
+            plot_background: None,
+            plot_border: None,
+            clip_data: true,
         }
     }
 }
@@ -396,6 +610,61 @@ where
         self
     }
 
+    /// Set a subtitle with sensible defaults (smaller than the title, centred
+    /// just below it).
+    #[must_use]
+    #[allow(clippy::missing_panics_doc)]
+    pub fn subtitle(self, text: impl Into<String>) -> Self {
+        self.subtitle_styled(text, |b| b)
+    }
+
+    /// Set the subtitle with a customised style.
+    ///
+    /// The closure receives a `TextStyleBuilder` pre-configured with the
+    /// default subtitle settings.
+    #[must_use]
+    #[allow(clippy::missing_panics_doc)]
+    pub fn subtitle_styled(
+        mut self,
+        text: impl Into<String>,
+        f: impl FnOnce(TextStyleBuilder) -> TextStyleBuilder,
+    ) -> Self {
+        let base = TextStyleBuilder::default()
+            .font_size(18.0)
+            .anchor(Anchor::TOP_CENTER);
+        let style = f(base).build().unwrap();
+        self.subtitle = Some((text.into(), style));
+        self
+    }
+
+    /// Set a caption with sensible defaults (small, in the bottom-right
+    /// corner of the viewport), e.g. for source notes.
+    #[must_use]
+    #[allow(clippy::missing_panics_doc)]
+    pub fn caption(self, text: impl Into<String>) -> Self {
+        self.caption_styled(text, |b| b)
+    }
+
+    /// Set the caption with a customised style.
+    ///
+    /// The closure receives a `TextStyleBuilder` pre-configured with the
+    /// default caption settings.
+    #[must_use]
+    #[allow(clippy::missing_panics_doc)]
+    pub fn caption_styled(
+        mut self,
+        text: impl Into<String>,
+        f: impl FnOnce(TextStyleBuilder) -> TextStyleBuilder,
+    ) -> Self {
+        let base = TextStyleBuilder::default().font_size(14.0).anchor(Anchor {
+            h: HAlign::Right,
+            v: VAlign::Bottom,
+        });
+        let style = f(base).build().unwrap();
+        self.caption = Some((text.into(), style));
+        self
+    }
+
     /// Set the x-axis label with sensible defaults (centred below the plot).
     #[must_use]
     #[allow(clippy::missing_panics_doc)]
@@ -465,6 +734,13 @@ where
         self
     }
 
+    /// Add a legend built from entries emitted by plotted series, e.g.
+    /// [`ScatterPlotConfig::legend_entry`](crate::plottable::scatter::ScatterPlotConfig::legend_entry).
+    #[must_use]
+    pub fn legend_from(self, entries: impl IntoIterator<Item = LegendEntry>) -> Self {
+        self.legend(entries.into_iter().collect())
+    }
+
     /// Add a legend with customised configuration.
     #[must_use]
     pub fn legend_styled(
@@ -517,23 +793,302 @@ where
         self
     }
 
+    /// Add a text-less leader arrow from `from` pointing at `to`, both in
+    /// data space.
+    #[must_use]
+    pub fn arrow(self, from: impl Into<Datapoint>, to: impl Into<Datapoint>) -> Self {
+        let (annotation, configs) = Annotation::arrow(from, to);
+        self.annotate_styled(annotation, |c| *c = configs)
+    }
+
+    /// Add a horizontal reference line at data coordinate `y`.
+    #[must_use]
+    pub fn hline(self, y: f32) -> Self {
+        self.hline_styled(y, |_| {})
+    }
+
+    /// Add a horizontal reference line with customised style.
+    #[must_use]
+    pub fn hline_styled(mut self, y: f32, f: impl FnOnce(&mut ReferenceLineConfig)) -> Self {
+        let mut configs = ReferenceLineConfig::default();
+        f(&mut configs);
+        self.hlines.push(ConfiguredElement::new(HLine(y), configs));
+        self
+    }
+
+    /// Add a vertical reference line at data coordinate `x`.
+    #[must_use]
+    pub fn vline(self, x: f32) -> Self {
+        self.vline_styled(x, |_| {})
+    }
+
+    /// Add a vertical reference line with customised style.
+    #[must_use]
+    pub fn vline_styled(mut self, x: f32, f: impl FnOnce(&mut ReferenceLineConfig)) -> Self {
+        let mut configs = ReferenceLineConfig::default();
+        f(&mut configs);
+        self.vlines.push(ConfiguredElement::new(VLine(x), configs));
+        self
+    }
+
+    /// Shade a region ([`VSpan`](crate::plottable::reference::VSpan),
+    /// [`HSpan`](crate::plottable::reference::HSpan), or
+    /// [`RectRegion`](crate::plottable::reference::RectRegion)) behind the data.
+    #[must_use]
+    pub fn shade(self, region: impl Into<Region>) -> Self {
+        self.shade_styled(region, |_| {})
+    }
+
+    /// Shade a region behind the data with customised style.
+    #[must_use]
+    pub fn shade_styled(
+        mut self,
+        region: impl Into<Region>,
+        f: impl FnOnce(&mut RegionConfig),
+    ) -> Self {
+        let mut configs = RegionConfig::default();
+        f(&mut configs);
+        self.regions
+            .push(ConfiguredElement::new(region.into(), configs));
+        self
+    }
+
+    /// Add a secondary y-axis on the right side of the plot.
+    ///
+    /// Only the y range of `val` is used; the x range is shared with the
+    /// primary axis. Wrap a subject in [`Secondary`] to project it against
+    /// this range.
+    #[must_use]
+    pub fn secondary_axis(mut self, val: impl Into<ConfiguredElement<Axis, AxisConfigs>>) -> Self {
+        self.secondary_axis = Some(val.into());
+        self
+    }
+
+    /// Add y-axis tick marks and labels for the secondary axis, drawn to the
+    /// right of the plot area.
+    #[must_use]
+    pub fn secondary_ticks(
+        mut self,
+        val: impl Into<ConfiguredElement<TickLabels, TickLabelsConfig>>,
+    ) -> Self {
+        self.secondary_ticks = Some(val.into());
+        self
+    }
+
+    /// Reverse the x direction so that data-x grows leftward.
+    #[must_use]
+    pub fn invert_x(mut self, invert: bool) -> Self {
+        self.invert_x = invert;
+        self
+    }
+
+    /// Reverse the y direction so that data-y grows downward (e.g. depth).
+    #[must_use]
+    pub fn invert_y(mut self, invert: bool) -> Self {
+        self.invert_y = invert;
+        self
+    }
+
+    /// Keep one data unit in x the same on-screen length as one data unit
+    /// in y.
+    ///
+    /// The plotting area is shrunk and centred within the margins to match
+    /// the data aspect ratio, which can leave unused space on one axis.
+    #[must_use]
+    pub fn equal_aspect(mut self, equal: bool) -> Self {
+        self.equal_aspect = equal;
+        self
+    }
+
+    /// Nudge annotation labels apart vertically so their text doesn't
+    /// overlap. Leader lines stay attached to their original targets.
+    #[must_use]
+    pub fn avoid_overlap(mut self, avoid: bool) -> Self {
+        self.avoid_overlap = avoid;
+        self
+    }
+
+    /// Fill the plotting area with `color` before the grid is drawn. By
+    /// default the plotting area is left unfilled.
+    #[must_use]
+    pub fn plot_background(mut self, color: Color) -> Self {
+        self.plot_background = Some(color);
+        self
+    }
+
+    /// Frame the plotting area with a `thickness`-pixel border in `color`,
+    /// drawn over the data and grid but under the axes.
+    #[must_use]
+    pub fn plot_border(mut self, color: Color, thickness: f32) -> Self {
+        self.plot_border = Some((color, thickness));
+        self
+    }
+
+    /// Clip the grid, regions, subject, and reference lines to the plotting
+    /// area (the default). Pass `false` to let elements near the edges, such
+    /// as large markers, spill over into the margins.
+    #[must_use]
+    pub fn clip_data(mut self, clip: bool) -> Self {
+        self.clip_data = clip;
+        self
+    }
+
+    /// Grow the viewport margins at build time so tick labels, the title,
+    /// and the axis labels fit outside the plotting area.
+    ///
+    /// Text is measured with each style's font, falling back to `rl`'s
+    /// default font. Margins already larger than needed are kept.
+    #[must_use]
+    pub fn auto_margins(mut self, rl: &RaylibHandle) -> Self {
+        self.auto_margins = Some(rl.get_font_default());
+        self
+    }
+
+    /// Shrink or wrap, at build time, the title, subtitle, caption, and axis
+    /// labels whose style sets [`fit`](TextStyle::fit) so they fit the
+    /// viewport.
+    ///
+    /// Text is measured with each style's font, falling back to `rl`'s
+    /// default font. [`auto_margins`](Self::auto_margins) fits the labels as
+    /// well, so this is only needed without it.
+    #[must_use]
+    pub fn fit_text(mut self, rl: &RaylibHandle) -> Self {
+        self.fit_font = Some(rl.get_font_default());
+        self
+    }
+
+    /// Fit the text labels that ask for it to `viewport`: the y label along
+    /// its height, since it is drawn rotated a quarter turn, and the others
+    /// along its width.
+    fn fit_labels(&mut self, viewport: &Viewport) {
+        let Some(font) = self.fit_font.as_ref().or(self.auto_margins.as_ref()) else {
+            return;
+        };
+        let outer = viewport.outer_bbox();
+        let width = outer.width() - 2.0 * AUTO_MARGIN_PADDING;
+        let height = outer.height() - 2.0 * AUTO_MARGIN_PADDING;
+        for (label, max_width) in [
+            (&mut self.title, width),
+            (&mut self.subtitle, width),
+            (&mut self.caption, width),
+            (&mut self.xlabel, width),
+            (&mut self.ylabel, height),
+        ] {
+            if let Some((text, style)) = label {
+                (*text, *style) = style.fitted(text, max_width, font);
+            }
+        }
+    }
+
+    /// Margins of `viewport` that fit the ticks and text labels, never
+    /// smaller than its current ones.
+    ///
+    /// Ticks with a per-pixel density are counted for the plotting area
+    /// left by the current margins.
+    fn fitted_margins(&self, viewport: &Viewport, default_font: &WeakFont) -> Margins {
+        let current = viewport.margins;
+        let inner = viewport.inner_bbox();
+        let plot_size = Vector2::new(inner.width(), inner.height());
+        let text_size = |label: Option<&(String, TextStyle)>| {
+            label.map_or(Vector2::zero(), |(text, style)| {
+                style.measure_text(text, default_font)
+            })
+        };
+        let ticks = self.ticks.as_ref().map_or(Vector2::zero(), |t| {
+            t.element.extents(&t.configs, default_font, plot_size)
+        });
+        let secondary = self.secondary_ticks.as_ref().map_or(Vector2::zero(), |t| {
+            t.element.extents(&t.configs, default_font, plot_size)
+        });
+        // The y label is drawn rotated a quarter turn, so its height is the
+        // width it takes up.
+        // The subtitle stacks under the title; the caption shares the bottom
+        // margin with the x label.
+        let (title, xlabel, ylabel) = (
+            text_size(self.title.as_ref()).y + text_size(self.subtitle.as_ref()).y,
+            text_size(self.xlabel.as_ref())
+                .y
+                .max(text_size(self.caption.as_ref()).y),
+            text_size(self.ylabel.as_ref()).y,
+        );
+        let fit = |extent: f32, label: f32| {
+            let label = if label > 0.0 {
+                label + AUTO_MARGIN_PADDING
+            } else {
+                0.0
+            };
+            extent + label + AUTO_MARGIN_PADDING
+        };
+        // Ticks moved to the right or top take their room on that side; the
+        // axis labels stay at the left and bottom.
+        let (y_on_right, x_on_top) = self.ticks.as_ref().map_or((false, false), |t| {
+            (t.configs.y_on_right(), t.configs.x_on_top())
+        });
+        let (left_ticks, right_ticks) = if y_on_right {
+            (0.0, ticks.x.max(secondary.x))
+        } else {
+            (ticks.x, secondary.x)
+        };
+        let (top_ticks, bottom_ticks) = if x_on_top {
+            (ticks.y, 0.0)
+        } else {
+            (0.0, ticks.y)
+        };
+        Margins {
+            left: current.left.max(fit(left_ticks, ylabel)),
+            right: current.right.max(fit(right_ticks, 0.0)),
+            top: current.top.max(fit(top_ticks, title)),
+            bottom: current.bottom.max(fit(bottom_ticks, xlabel)),
+        }
+    }
+
     /// Consume the builder and produce a fully resolved [`GraphConfig`].
     ///
     /// Returns an error if required fields are missing or inconsistent.
     /// On success the returned config has all theme-dependent colors resolved,
     /// making it safe to reuse across frames without further mutation.
-    #[allow(clippy::missing_errors_doc)]
-    pub fn build(self) -> Result<GraphConfig<T>, GraphBuilderError> {
-        let viewport = self.viewport.unwrap_or_default();
+    #[allow(
+        clippy::missing_errors_doc,
+        clippy::cast_precision_loss,
+        clippy::too_many_lines
+    )]
+    pub fn build(mut self) -> Result<GraphConfig<T>, GraphBuilderError> {
+        let mut viewport = self.viewport.unwrap_or_default();
+        // Before the margins, so they are measured for the fitted text.
+        self.fit_labels(&viewport);
+        if let Some(font) = &self.auto_margins {
+            viewport = viewport.with_margins(self.fitted_margins(&viewport, font));
+        }
         let inner = viewport.inner_bbox();
         let outer = viewport.outer_bbox();
+        // Centred horizontally at the top of the outer viewport, above the inner bbox.
+        let title_origin = Screenpoint::new(
+            (inner.minimum.x + inner.maximum.x) * 0.5,
+            (outer.minimum.y + inner.minimum.y) * 0.5,
+        );
         let title: Option<ConfiguredElement<TextLabel, TextStyle>> =
             if let Some((text, configs)) = self.title {
-                // Centred horizontally at the top of the outer viewport, above the inner bbox.
-                let origin = crate::plottable::point::Screenpoint::new(
-                    (inner.minimum.x + inner.maximum.x) * 0.5,
-                    (outer.minimum.y + inner.minimum.y) * 0.5,
-                );
+                let element = TextLabel::new(text, title_origin);
+                Some(ConfiguredElement { element, configs })
+            } else {
+                None
+            };
+        let subtitle: Option<ConfiguredElement<TextLabel, TextStyle>> =
+            if let Some((text, configs)) = self.subtitle {
+                // Right under the title's lines, or in its place without one.
+                let below = title.as_ref().map_or(0.0, |t| {
+                    t.configs.line_height() * t.element.text.lines().count() as f32
+                });
+                let origin = Screenpoint::new(title_origin.x, title_origin.y + below);
+                let element = TextLabel::new(text, origin);
+                Some(ConfiguredElement { element, configs })
+            } else {
+                None
+            };
+        let caption: Option<ConfiguredElement<TextLabel, TextStyle>> =
+            if let Some((text, configs)) = self.caption {
+                // Tucked into the bottom-right corner of the outer viewport.
+                let origin = Screenpoint::new(outer.maximum.x, outer.maximum.y);
                 let element = TextLabel::new(text, origin);
                 Some(ConfiguredElement { element, configs })
             } else {
@@ -542,10 +1097,11 @@ where
 
         let xlabel: Option<ConfiguredElement<TextLabel, TextStyle>> =
             if let Some((text, configs)) = self.xlabel {
-                // Centred horizontally below the inner bbox.
+                // Centred horizontally below the inner bbox, resting on the bottom edge of
+                // the outer viewport so it clears the tick labels.
                 let origin = crate::plottable::point::Screenpoint::new(
                     (inner.minimum.x + inner.maximum.x) * 0.5,
-                    (outer.maximum.y + outer.maximum.y) * 0.5,
+                    outer.maximum.y,
                 );
                 let element = TextLabel::new(text, origin);
                 Some(ConfiguredElement { element, configs })
@@ -554,9 +1110,9 @@ where
             };
         let ylabel: Option<ConfiguredElement<TextLabel, TextStyle>> =
             if let Some((text, configs)) = self.ylabel {
-                // Centred vertically to the left of the inner bbox.
+                // Centred vertically, in the middle of the left margin.
                 let origin = crate::plottable::point::Screenpoint::new(
-                    (inner.minimum.x + inner.minimum.x) * 0.5,
+                    (outer.minimum.x + inner.minimum.x) * 0.5,
                     (inner.minimum.y + inner.maximum.y) * 0.5,
                 );
                 let element = TextLabel::new(text, origin);
@@ -564,21 +1120,39 @@ where
             } else {
                 None
             };
+        // The axis owns the placement; its tick marks follow it.
+        if let (Some(axis), Some(ticks)) = (&self.axis, &mut self.ticks) {
+            ticks.configs.placement = axis.configs.placement;
+        }
         let scheme = self
             .colorscheme
             .unwrap_or(Cow::Owned(Colorscheme::default()));
         Ok(GraphConfig {
             subject_configs: self.subject_configs.unwrap_or_default(),
-            viewport: self.viewport.unwrap_or_default(),
+            viewport,
             axis: self.axis,
             grid: self.grid,
             colorscheme: scheme.into_owned(),
             ticks: self.ticks,
             title,
+            subtitle,
+            caption,
             xlabel,
             ylabel,
             legend: self.legend,
             annotations: self.annotations,
+            invert_x: self.invert_x,
+            invert_y: self.invert_y,
+            equal_aspect: self.equal_aspect,
+            secondary_axis: self.secondary_axis,
+            secondary_ticks: self.secondary_ticks,
+            hlines: self.hlines,
+            vlines: self.vlines,
+            regions: self.regions,
+            avoid_overlap: self.avoid_overlap,
+            plot_background: self.plot_background,
+            plot_border: self.plot_border,
+            clip_data: self.clip_data,
         }
         .resolve_theme())
     }
@@ -588,6 +1162,32 @@ where
     T: ChartElement,
     <T as ChartElement>::Config: Default + Themable,
 {
+    /// The ticks of the primary axes as drawn in `view`, generated once
+    /// from the tick labels.
+    ///
+    /// [`Graph`] draws both the tick marks and the grid from this set, so
+    /// every grid line passes through a tick. `None` without tick labels,
+    /// in which case the grid generates its own ticks.
+    #[must_use]
+    pub fn axis_ticks(&self, view: &ViewTransformer) -> Option<AxisTicks> {
+        self.ticks
+            .as_ref()
+            .map(|ticks| ticks.element.tick_sets(&ticks.configs, view))
+    }
+
+    /// The ticks of the primary axes exactly as [`Graph`] draws them in
+    /// `view`, with their label text; see [`TickLabels::labelled_ticks`].
+    /// `None` without tick labels.
+    ///
+    /// Pair it with [`Graph::view_transformer`] to read the ticks without
+    /// rendering.
+    #[must_use]
+    pub fn labelled_ticks(&self, view: &ViewTransformer) -> Option<AxisTicks> {
+        self.ticks
+            .as_ref()
+            .map(|ticks| ticks.element.labelled_ticks(&ticks.configs, view))
+    }
+
     /// Resolves theme-driven defaults (subject + axis/grid configs) once.
     /// Call this after `build()` and reuse the returned config across frames.
     #[must_use]
@@ -602,9 +1202,21 @@ where
         if let Some(ticks) = &mut self.ticks {
             ticks.apply_theme(&self.colorscheme);
         }
+        if let Some(axis) = &mut self.secondary_axis {
+            axis.apply_theme(&self.colorscheme);
+        }
+        if let Some(ticks) = &mut self.secondary_ticks {
+            ticks.apply_theme(&self.colorscheme);
+        }
         if let Some(title) = &mut self.title {
             title.apply_theme(&self.colorscheme);
         }
+        if let Some(subtitle) = &mut self.subtitle {
+            subtitle.apply_theme(&self.colorscheme);
+        }
+        if let Some(caption) = &mut self.caption {
+            caption.apply_theme(&self.colorscheme);
+        }
         if let Some(xlabel) = &mut self.xlabel {
             xlabel.apply_theme(&self.colorscheme);
         }
@@ -619,6 +1231,15 @@ where
                 ann.apply_theme(&self.colorscheme);
             }
         }
+        for hline in &mut self.hlines {
+            hline.apply_theme(&self.colorscheme);
+        }
+        for vline in &mut self.vlines {
+            vline.apply_theme(&self.colorscheme);
+        }
+        for region in &mut self.regions {
+            region.apply_theme(&self.colorscheme);
+        }
         self.subject_configs.apply_theme(&self.colorscheme);
         self
     }
@@ -631,53 +1252,58 @@ where
     type Config = GraphConfig<T>;
 
     fn plot(&self, rl: &mut raylib::prelude::RaylibDrawHandle, configs: &GraphConfig<T>) {
-        // We need to construct the view where the graph elements will live.
-        // As such, we need to provide the screen-bounds, given by the configs
-        // and the data-bounds, given by the `subject.data_bounds()`
-        let screen = configs.viewport;
-        let data_bbox = if let Some(axis) = &configs.axis {
-            axis.element.data_bounds()
-        } else {
-            self.subject.data_bounds()
-        };
-        let inner = screen.inner_bbox();
-        let inner_viewport = Viewport::new(
-            inner.minimum.x,
-            inner.minimum.y,
-            inner.width(),
-            inner.height(),
-        );
-        let view = ViewTransformer::new(data_bbox, inner_viewport);
-        {
-            let inner_bbox = screen.inner_bbox();
-            let (x, y, w, h) = scissor_rect_from_bbox(inner_bbox);
+        let view = self.view_transformer(configs);
+        let ticks = configs.axis_ticks(&view);
+        let inner_viewport = view.screen_bounds;
+        let plot_area = bbox_rect(inner_viewport.inner_bbox());
+        if let Some(background) = configs.plot_background {
+            rl.draw_rectangle_rec(plot_area, background);
+        }
+        if configs.clip_data {
+            let screen = render_target_size(rl);
+            let (x, y, w, h) = scissor_rect_from_bbox(inner_viewport.inner_bbox(), screen);
             let mut scissors = rl.begin_scissor_mode(x, y, w, h);
-            // We have all the necessary parts for constructing the graph. With that is a job of
-            // seeing what we have and what don't.
-            if let Some(grid) = &configs.grid {
-                grid.draw_in_view(&mut scissors, &view);
-            }
-
-            // We plot the subject inside the view.
-            // configs.subject_configs.apply_theme(&configs.colorscheme);
-            self.subject
-                .draw_in_view(&mut scissors, &configs.subject_configs, &view);
+            self.draw_plot_area(&mut scissors, configs, &view, ticks.as_ref());
+        } else {
+            self.draw_plot_area(rl, configs, &view, ticks.as_ref());
+        }
+        if let Some((color, thickness)) = configs.plot_border {
+            rl.draw_rectangle_lines_ex(plot_area, thickness, color);
         }
         // NOTE: Axis shouldn't be scissored, neither the ticks;
         if let Some(axis) = &configs.axis {
             axis.draw_in_view(rl, &view);
         }
-        if let Some(ticks) = &configs.ticks {
-            ticks.draw_in_view(rl, &view);
+        if let (Some(element), Some(ticks)) = (&configs.ticks, &ticks) {
+            element
+                .element
+                .draw_with_ticks(rl, &element.configs, &view, ticks);
+        }
+        if let Some(secondary_view) = view.secondary() {
+            if let Some(axis) = &configs.secondary_axis {
+                axis.element
+                    .draw_right_in_view(rl, &axis.configs, &secondary_view);
+            }
+            if let Some(ticks) = &configs.secondary_ticks {
+                ticks
+                    .element
+                    .draw_right_in_view(rl, &ticks.configs, &secondary_view);
+            }
         }
 
         if let Some(title) = &configs.title {
             title.draw(rl);
         }
-        if let Some(xlabel) = &configs.title {
+        if let Some(subtitle) = &configs.subtitle {
+            subtitle.draw(rl);
+        }
+        if let Some(caption) = &configs.caption {
+            caption.draw(rl);
+        }
+        if let Some(xlabel) = &configs.xlabel {
             xlabel.draw(rl);
         }
-        if let Some(ylabel) = &configs.title {
+        if let Some(ylabel) = &configs.ylabel {
             ylabel.draw(rl);
         }
 
@@ -685,18 +1311,224 @@ where
             legend.draw_in_view(rl, &view);
         }
         if let Some(annotations) = &configs.annotations {
-            for annot in annotations {
-                annot.draw_in_view(rl, &view);
+            if configs.avoid_overlap {
+                draw_annotations_apart(rl, annotations, &view);
+            } else {
+                for annot in annotations {
+                    annot.draw_in_view(rl, &view);
+                }
             }
         }
     }
 }
+/// Gap in pixels [`GraphBuilder::auto_margins`] leaves around each piece of
+/// chrome and at the viewport edge.
+const AUTO_MARGIN_PADDING: f32 = 8.0;
+
+/// Gap in pixels kept between labels moved apart by [`avoid_overlaps`].
+const ANNOTATION_PADDING: f32 = 2.0;
+
+/// Draw annotations after pushing overlapping labels apart.
+fn draw_annotations_apart(
+    rl: &mut raylib::prelude::RaylibDrawHandle,
+    annotations: &[ConfiguredElement<Annotation, AnnotationConfig>],
+    view: &ViewTransformer,
+) {
+    let font = rl.get_font_default();
+    let origins: Vec<_> = annotations
+        .iter()
+        .map(|annot| annot.element.screen_origin(view))
+        .collect();
+    // Text-less annotations take no room; give them an empty rectangle far
+    // away so they neither move nor block anything.
+    let rects: Vec<_> = annotations
+        .iter()
+        .zip(&origins)
+        .map(|(annot, origin)| {
+            annot
+                .element
+                .label_rect(&annot.configs, *origin, &font)
+                .unwrap_or(Rectangle::new(f32::MIN, f32::MIN, 0.0, 0.0))
+        })
+        .collect();
+    let shifts = avoid_overlaps(&rects, ANNOTATION_PADDING);
+    for ((annot, origin), dy) in annotations.iter().zip(origins).zip(shifts) {
+        let origin = Screenpoint::new(origin.x, origin.y + dy);
+        annot.element.draw_at(rl, &annot.configs, view, origin);
+    }
+}
+
+/// `b` as a raylib rectangle.
+fn bbox_rect(b: ScreenBBox) -> Rectangle {
+    Rectangle::new(b.minimum.x, b.minimum.y, b.width(), b.height())
+}
+
+/// Size in pixels of what is being drawn to: the render texture in texture
+/// mode, the window otherwise.
+fn render_target_size(rl: &RaylibHandle) -> (i32, i32) {
+    // SAFETY: plain reads of rlgl state, which exists for as long as a
+    // handle does. `BeginTextureMode` sets the framebuffer size to the
+    // texture's.
+    let (target, width, height) = unsafe {
+        (
+            ffi::rlGetActiveFramebuffer(),
+            ffi::rlGetFramebufferWidth(),
+            ffi::rlGetFramebufferHeight(),
+        )
+    };
+    if target == 0 {
+        (rl.get_screen_width(), rl.get_screen_height())
+    } else {
+        (width, height)
+    }
+}
+
+/// `b` rounded to the pixel grid and clamped to a `screen` of
+/// `(width, height)` pixels, as `(x, y, width, height)`.
+///
+/// raylib scissors off-screen rectangles unreliably, so a viewport hanging
+/// past the edges of the render target is cut down to its visible part.
 #[allow(clippy::cast_possible_truncation)]
-fn scissor_rect_from_bbox(b: ScreenBBox) -> (i32, i32, i32, i32) {
-    // Round to pixel grid; clamp sizes to >= 0
-    let x = b.minimum.x.round() as i32;
-    let y = b.minimum.y.round() as i32;
-    let width = b.width().round().max(0.0) as i32;
-    let height = b.height().round().max(0.0) as i32;
-    (x, y, width, height)
+fn scissor_rect_from_bbox(b: ScreenBBox, screen: (i32, i32)) -> (i32, i32, i32, i32) {
+    let x = (b.minimum.x.round() as i32).clamp(0, screen.0);
+    let y = (b.minimum.y.round() as i32).clamp(0, screen.1);
+    let right = (b.maximum.x.round() as i32).clamp(x, screen.0);
+    let bottom = (b.maximum.y.round() as i32).clamp(y, screen.1);
+    (x, y, right - x, bottom - y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        dataset::Dataset,
+        plottable::{
+            line::{AxisConfigsBuilder, AxisPlacement, Line, TickLabelsBuilder},
+            scatter::ScatterPlot,
+            view::ScaleSpec,
+        },
+    };
+
+    #[test]
+    fn scissor_rect_is_clamped_to_the_screen() {
+        let inside = ScreenBBox::from_min_max((10.2, 20.6), (110.0, 70.0));
+        assert_eq!(
+            scissor_rect_from_bbox(inside, (800, 600)),
+            (10, 21, 100, 49)
+        );
+        let hanging = ScreenBBox::from_min_max((-50.0, 500.0), (300.0, 900.0));
+        assert_eq!(
+            scissor_rect_from_bbox(hanging, (800, 600)),
+            (0, 500, 300, 100)
+        );
+        let offscreen = ScreenBBox::from_min_max((900.0, 10.0), (1000.0, 50.0));
+        assert_eq!(
+            scissor_rect_from_bbox(offscreen, (800, 600)),
+            (800, 10, 0, 40)
+        );
+    }
+
+    fn log_ticks() -> ConfiguredElement<TickLabels, TickLabelsConfig> {
+        let configs = TickLabelsBuilder::default()
+            .with_y_scale(Scale::Log {
+                base: 10.0,
+                include_minor: false,
+            })
+            .build()
+            .unwrap();
+        ConfiguredElement::new(TickLabels::new(Axis::fitting(0.0..1.0, 1.0..10.0)), configs)
+    }
+
+    #[test]
+    fn log_axis_starts_at_the_smallest_positive_value() {
+        let data = Dataset::new(vec![(0.0, 0.0), (1.0, 0.5), (10.0, 100.0)]);
+        let configs = GraphBuilder::default()
+            .viewport(Viewport::new(0.0, 0.0, 100.0, 100.0))
+            .ticks(log_ticks())
+            .build()
+            .unwrap();
+        let view = Graph::new(ScatterPlot::new(&data)).view_transformer(&configs);
+        assert!((view.data_bounds.minimum.y - 0.5).abs() < 1e-6);
+        // The x axis is linear and keeps its zero.
+        assert!(view.data_bounds.minimum.x.abs() < 1e-6);
+        let bottom = view.to_screen(&Datapoint::new(1.0, 0.5));
+        assert!((bottom.y - 100.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn ticks_follow_the_axis_placement() {
+        let axis = Axis::fitting(-1.0..1.0, -1.0..1.0);
+        let origin = AxisConfigsBuilder::default()
+            .placement(AxisPlacement::Origin)
+            .build()
+            .unwrap();
+        let configs: GraphConfig<ScatterPlot<'_>> = GraphBuilder::default()
+            .axis(ConfiguredElement::new(axis, origin))
+            .ticks(ConfiguredElement::with_defaults(TickLabels::new(axis)))
+            .build()
+            .unwrap();
+        let ticks = configs.ticks.unwrap();
+        assert!(matches!(ticks.configs.placement, AxisPlacement::Origin));
+    }
+
+    #[test]
+    fn plot_background_stays_unset_unless_asked_for() {
+        let plain: GraphConfig<ScatterPlot<'_>> = GraphBuilder::default().build().unwrap();
+        assert!(plain.resolve_theme().plot_background.is_none());
+        let filled: GraphConfig<ScatterPlot<'_>> = GraphBuilder::default()
+            .plot_background(Color::WHITE)
+            .build()
+            .unwrap();
+        assert_eq!(filled.resolve_theme().plot_background, Some(Color::WHITE));
+    }
+
+    #[test]
+    fn secondary_view_maps_with_its_own_bounds() {
+        let data = Dataset::new(vec![(0.0, 0.0), (10.0, 10.0)]);
+        let graph = Graph::new(ScatterPlot::new(&data));
+        let viewport = Viewport::new(0.0, 0.0, 100.0, 100.0);
+        let plain = GraphBuilder::default().viewport(viewport).build().unwrap();
+        assert!(graph.view_transformer(&plain).secondary().is_none());
+
+        let configs = GraphBuilder::default()
+            .viewport(viewport)
+            .secondary_axis(ConfiguredElement::with_defaults(Axis::new(
+                Line::new((0.0, -100.0), (10.0, -100.0)),
+                Line::new((0.0, -100.0), (0.0, 300.0)),
+            )))
+            .build()
+            .unwrap();
+        let view = graph.view_transformer(&configs);
+        let secondary = view.secondary().unwrap();
+        // The x range is shared with the primary view, the y range is not.
+        assert!((secondary.data_bounds.maximum.x - 10.0).abs() < 1e-6);
+        assert!((secondary.data_bounds.maximum.y - 300.0).abs() < 1e-6);
+        let primary = view.to_screen(&Datapoint::new(5.0, 5.0));
+        let mapped = secondary.to_screen(&Datapoint::new(5.0, 100.0));
+        assert!((mapped.x - primary.x).abs() < 1e-3);
+        assert!((mapped.y - primary.y).abs() < 1e-3);
+        let top = secondary.to_screen(&Datapoint::new(0.0, 300.0));
+        assert!(top.y.abs() < 1e-3);
+    }
+
+    #[test]
+    fn secondary_view_uses_the_secondary_tick_scale() {
+        let data = Dataset::new(vec![(0.0, 1.0), (1.0, 10.0)]);
+        let configs = GraphBuilder::default()
+            .viewport(Viewport::new(0.0, 0.0, 100.0, 100.0))
+            .secondary_axis(ConfiguredElement::with_defaults(Axis::new(
+                Line::new((0.0, 1.0), (1.0, 1.0)),
+                Line::new((0.0, 1.0), (0.0, 1000.0)),
+            )))
+            .secondary_ticks(log_ticks())
+            .build()
+            .unwrap();
+        let view = Graph::new(ScatterPlot::new(&data)).view_transformer(&configs);
+        assert_eq!(view.y_scale, ScaleSpec::Linear);
+        let secondary = view.secondary().unwrap();
+        assert_eq!(secondary.y_scale, ScaleSpec::Log { base: 10.0 });
+        // 10 sits a third of the way up a log axis from 1 to 1000.
+        let ten = secondary.to_screen(&Datapoint::new(0.0, 10.0));
+        assert!((ten.y - 200.0 / 3.0).abs() < 1e-3);
+    }
 }
@@ -0,0 +1,92 @@
+//! Rendering without a visible window, for CI jobs and servers.
+//!
+//! Enabled by the `headless` feature. [`with_hidden_window`] initialises
+//! raylib with `FLAG_WINDOW_HIDDEN`, runs a closure, and closes the window
+//! again; inside it, [`render_to_image`] draws a frame into an off-screen
+//! texture and reads it back as an [`Image`], so tests can assert on the
+//! produced pixels.
+//!
+//! A hidden window is still an OpenGL window: it needs a display server
+//! even though nothing is shown. On a Linux CI machine without one, run
+//! under a virtual display such as `xvfb-run cargo test --features headless`.
+//! raylib only supports one window per process, so tests that open one must
+//! not run concurrently; use `--test-threads=1`.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use locus::headless::{render_to_image, with_hidden_window};
+//! use locus::prelude::*;
+//! use raylib::prelude::*;
+//!
+//! let mut image = with_hidden_window(200, 100, |rl, thread| {
+//!     render_to_image(rl, thread, 200, 100, |d| {
+//!         d.clear_background(Color::WHITE);
+//!         Screenpoint::new(100.0, 50.0).plot(d, &PointConfigBuilder::default().build().unwrap());
+//!     })
+//!     .expect("render texture")
+//! });
+//! assert_eq!(image.get_color(100, 50), Color::RED);
+//! assert_eq!(image.get_color(0, 0), Color::WHITE);
+//! ```
+
+use raylib::{core::error::Error, ffi, prelude::*};
+
+/// Open a hidden `width` by `height` window, run `f` with its handle, and
+/// close the window once `f` returns.
+///
+/// Returns whatever `f` returns, typically images produced with
+/// [`render_to_image`].
+///
+/// # Panics
+///
+/// Panics if a raylib window is already open in this process, or if the
+/// window cannot be created (e.g. no display server is available).
+pub fn with_hidden_window<R>(
+    width: i32,
+    height: i32,
+    f: impl FnOnce(&mut RaylibHandle, &RaylibThread) -> R,
+) -> R {
+    // SAFETY: only sets a flag read by the next `InitWindow`. raylib ORs
+    // configuration flags together, so the builder's own flags keep the
+    // window hidden.
+    unsafe {
+        ffi::SetConfigFlags(ConfigFlags::FLAG_WINDOW_HIDDEN as u32);
+    }
+    let (mut rl, thread) = raylib::init()
+        .size(width, height)
+        .title("locus (hidden)")
+        .log_level(TraceLogLevel::LOG_WARNING)
+        .build();
+    // Dropping the handle closes the window.
+    f(&mut rl, &thread)
+}
+
+/// Draw one frame with `draw` into an off-screen `width` by `height`
+/// texture and read it back as an [`Image`], top row first.
+///
+/// Everything `draw` renders through its handle, including whole
+/// [`Graph`](crate::graph::Graph)s, lands in the texture instead of the
+/// window, so the window's own size does not limit the image.
+///
+/// # Errors
+///
+/// Returns an error if the render texture cannot be created or read back.
+pub fn render_to_image(
+    rl: &mut RaylibHandle,
+    thread: &RaylibThread,
+    width: u32,
+    height: u32,
+    draw: impl FnOnce(&mut RaylibDrawHandle),
+) -> Result<Image, Error> {
+    let mut target = rl.load_render_texture(thread, width, height)?;
+    {
+        let mut d = rl.begin_drawing(thread);
+        let mut texture = d.begin_texture_mode(thread, &mut target);
+        draw(&mut texture);
+    }
+    let mut image = target.texture().load_image()?;
+    // OpenGL stores render textures bottom row first.
+    image.flip_vertical();
+    Ok(image)
+}
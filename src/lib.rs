@@ -71,9 +71,12 @@
 //!
 //! | Module | Purpose |
 //! |---|---|
+//! | [`animation`] | Eased [`Animator`](animation::Animator) transitions between data states |
+//! | [`colormap`] | Continuous and diverging [`Colormap`](colormap::Colormap)s for mapping values to colors |
 //! | [`colorscheme`] | Predefined color themes and the [`Themable`](colorscheme::Themable) trait |
 //! | [`dataset`] | The [`Dataset`](dataset::Dataset) container for collections of data points |
 //! | [`graph`] | The [`Graph`](graph::Graph) orchestrator and its builder |
+//! | `headless` | Hidden-window, off-screen rendering to images (`headless` feature) |
 //! | [`plottable`] | Primitive visual elements: points, lines, scatter plots, text, ticks, legends, annotations, and the view transform |
 //! | [`plotter`] | Core rendering traits ([`PlotElement`](plotter::PlotElement), [`ChartElement`](plotter::ChartElement)) |
 //!
@@ -83,32 +86,66 @@
 //! * Automatic "nice number" axis snapping and tick generation (linear,
 //!   logarithmic, and symmetric-log scales).
 //! * Multiple built-in color schemes (Dracula, Nord, Viridis, Solarized,
-//!   GitHub, Matplotlib).
+//!   GitHub, Matplotlib, and the colorblind-safe Okabe-Ito).
 //! * Per-point dynamic size, color, and shape mapping on scatter plots.
+//! * Diverging colormaps that keep a chosen center neutral.
 //! * Data-space annotations with optional leader arrows.
 //! * Legends with configurable position, indicator shapes, and styling.
+//! * Polar grids and scatter plots.
+//! * Kernel density curves from raw samples.
+//! * 2D histograms for dense point clouds.
+//! * Grouped and stacked bar charts, with named categories on the x axis.
+//! * Pie and donut charts.
+//! * Radar (spider) charts for multivariate comparison.
+//! * Gantt-style timelines of tasks in rows.
+//! * Multi-series scatter and line charts with per-series colors.
+//! * Line charts that break at missing (`NaN`) values.
+//! * Shaded confidence bands around a center line.
+//! * Cursor crosshair overlay and drag-to-zoom box selection.
+//! * Eased animated transitions between data states.
+//! * Off-screen rendering to images in a hidden window (`headless`
+//!   feature), for CI and servers.
 
+pub mod animation;
+pub mod colormap;
 pub mod colorscheme;
 pub mod dataset;
 pub mod graph;
+#[cfg(feature = "headless")]
+pub mod headless;
 pub mod plottable;
 pub mod plotter;
 
 pub use plottable::annotation::{Annotation, AnnotationPosition};
-pub use plottable::legend::{Legend, LegendEntry, LegendPosition};
+pub use plottable::legend::{IndicatorKind, Legend, LegendEntry, LegendPosition};
 pub use plottable::text::{Anchor, FontHandle, HAlign, TextLabel, TextStyle, VAlign};
 
 pub mod prelude {
+    pub use super::animation::*;
+    pub use super::colormap::*;
     pub use super::colorscheme::*;
     pub use super::dataset::*;
     pub use super::graph::*;
     pub use super::plottable::annotation::*;
+    pub use super::plottable::band::*;
+    pub use super::plottable::bar::*;
+    pub use super::plottable::density::*;
+    pub use super::plottable::histogram::*;
+    pub use super::plottable::interaction::*;
+    pub use super::plottable::layers::*;
     pub use super::plottable::legend::*;
     pub use super::plottable::line::*;
+    pub use super::plottable::lineplot::*;
+    pub use super::plottable::pie::*;
     pub use super::plottable::point::*;
+    pub use super::plottable::polar::*;
+    pub use super::plottable::radar::*;
+    pub use super::plottable::reference::*;
     pub use super::plottable::scatter::*;
+    pub use super::plottable::series::*;
     pub use super::plottable::text::*;
     pub use super::plottable::ticks::*;
+    pub use super::plottable::timeline::*;
     pub use super::plottable::view::*;
     pub use super::plotter::*;
 }
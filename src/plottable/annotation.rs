@@ -25,6 +25,7 @@
 //!         AnnotLineConfigBuilder::default()
 //!             .target((3.5, 10.0).into())
 //!             .arrow(Visibility::Visible)
+//!             .dash((4.0, 3.0))
 //!             .build()
 //!             .unwrap(),
 //!     );
@@ -32,15 +33,15 @@
 //! ```
 
 use derive_builder::Builder;
-use raylib::color::Color;
+use raylib::{color::Color, math::Rectangle, text::WeakFont};
 
 use crate::{
     TextLabel,
     colorscheme::Themable,
     plottable::{
-        line::{Line, LineConfigBuilder, Visibility},
+        line::{Line, LineConfig, LineConfigBuilder, Visibility},
         point::{Datapoint, Screenpoint},
-        text::TextStyle,
+        text::{TextStyle, anchor_text_top_left},
         view::ViewTransformer,
     },
     plotter::{ChartElement, PlotElement},
@@ -55,8 +56,10 @@ pub enum AnnotationPosition {
     Screen(Screenpoint),
 }
 
-/// A text annotation placed at a specific location;
+/// A text annotation placed at a specific location.
 ///
+/// An empty `text` draws no label, which together with a leader line gives
+/// an arrow-only annotation (see [`Annotation::arrow`]).
 #[derive(Debug, Clone)]
 pub struct Annotation {
     pub text: String,
@@ -83,10 +86,30 @@ pub struct AnnotLineConfig {
     /// Half-width of the arrowhead perpendicular to the line (pixels).
     #[builder(default = "3.5 * 1.5")]
     pub arrow_width: f32,
+    /// Optional dash pattern as `(dash, gap)` lengths in pixels, e.g. to
+    /// set leaders apart from data lines. `None` draws a solid line.
+    #[builder(setter(into, strip_option), default = "None")]
+    pub dash: Option<(f32, f32)>,
     /// The data-space point that the leader line points toward.
     pub target: Datapoint,
 }
 
+impl AnnotLineConfig {
+    /// The [`LineConfig`] the leader line is drawn with.
+    fn leader_config(&self) -> LineConfig {
+        let mut line_configs = LineConfigBuilder::default()
+            .arrow_width(self.arrow_width)
+            .thickness(self.thickness)
+            .arrow_length(self.arrow_length)
+            .arrow(self.arrow)
+            .build()
+            .unwrap();
+        line_configs.color = self.color;
+        line_configs.dash = self.dash;
+        line_configs
+    }
+}
+
 /// Configuration for an [`Annotation`], controlling text style and the
 /// optional leader line.
 #[derive(Debug, Clone, Builder, Default)]
@@ -120,6 +143,7 @@ impl AnnotationConfigBuilder {
                     arrow: Visibility::Visible,
                     arrow_length: 1.5,
                     arrow_width: 1.5,
+                    dash: None,
                 })),
                 ..self
             }
@@ -137,6 +161,26 @@ impl Annotation {
         }
     }
 
+    /// Create a text-less annotation: a leader arrow from `from` pointing at
+    /// `to`, both in data space.
+    ///
+    /// Returns the annotation together with the config carrying the arrow,
+    /// ready for [`GraphBuilder::arrow`](crate::graph::GraphBuilder::arrow)
+    /// or a [`ConfiguredElement`](crate::graph::ConfiguredElement).
+    #[must_use]
+    #[allow(clippy::missing_panics_doc)]
+    pub fn arrow(from: impl Into<Datapoint>, to: impl Into<Datapoint>) -> (Self, AnnotationConfig) {
+        let line = AnnotLineConfigBuilder::default()
+            .target(to.into())
+            .build()
+            .expect("Only the target is required");
+        let configs = AnnotationConfig {
+            line: Some(line),
+            ..AnnotationConfig::default()
+        };
+        (Self::at_data("", from), configs)
+    }
+
     /// Create an annotation at a fixed screen-space position.
     #[must_use]
     pub fn at_screen(text: impl Into<String>, point: impl Into<Screenpoint>) -> Self {
@@ -156,31 +200,120 @@ impl ChartElement for Annotation {
         configs: &Self::Config,
         view: &ViewTransformer,
     ) {
-        let origin = match self.position {
+        self.draw_at(rl, configs, view, self.screen_origin(view));
+    }
+
+    fn data_bounds(&self) -> super::view::DataBBox {
+        unimplemented!("Doesn't make sense for annotation")
+    }
+}
+
+impl Annotation {
+    /// Where the label is anchored on screen.
+    pub(crate) fn screen_origin(&self, view: &ViewTransformer) -> Screenpoint {
+        match self.position {
             AnnotationPosition::Data(dp) => view.to_screen(&dp),
             AnnotationPosition::Screen(sp) => sp,
-        };
+        }
+    }
 
+    /// Screen rectangle covered by the label when anchored at `origin`, or
+    /// `None` for a text-less annotation.
+    pub(crate) fn label_rect(
+        &self,
+        configs: &AnnotationConfig,
+        origin: Screenpoint,
+        default_font: &WeakFont,
+    ) -> Option<Rectangle> {
+        if self.text.is_empty() {
+            return None;
+        }
+        let size = configs.style.measure_text(&self.text, default_font);
+        let tl = *origin + anchor_text_top_left(size, configs.style.anchor, configs.style.offset);
+        Some(Rectangle::new(tl.x, tl.y, size.x, size.y))
+    }
+
+    /// Draw with the label anchored at `origin` instead of the annotation's
+    /// own position. The leader line still ends at its data target.
+    pub(crate) fn draw_at(
+        &self,
+        rl: &mut raylib::prelude::RaylibDrawHandle,
+        configs: &AnnotationConfig,
+        view: &ViewTransformer,
+        origin: Screenpoint,
+    ) {
         // Draw leader line first (under text).
         if let Some(annot_line_configs) = &configs.line {
             let target_screen = view.to_screen(&annot_line_configs.target);
             let line = Line::new(*origin, *target_screen);
-            let mut line_configs = LineConfigBuilder::default()
-                .arrow_width(annot_line_configs.arrow_width)
-                .thickness(annot_line_configs.thickness)
-                .arrow_length(annot_line_configs.arrow_length)
-                .arrow(annot_line_configs.arrow)
-                .build()
-                .unwrap();
-            line_configs.color = annot_line_configs.color;
-            line.plot(rl, &line_configs);
+            line.plot(rl, &annot_line_configs.leader_config());
+        }
+        if !self.text.is_empty() {
+            let text = TextLabel::new(&self.text, origin);
+            text.plot(rl, &configs.style);
         }
-        let text = TextLabel::new(&self.text, origin);
-        text.plot(rl, &configs.style);
     }
+}
 
-    fn data_bounds(&self) -> super::view::DataBBox {
-        unimplemented!("Doesn't make sense for annotation")
+/// Greedy vertical placement of label rectangles.
+///
+/// Visits labels top to bottom and pushes each one down until it clears
+/// every label already placed, leaving `padding` pixels between them.
+/// Returns the vertical shift for each input rectangle, in input order.
+pub(crate) fn avoid_overlaps(rects: &[Rectangle], padding: f32) -> Vec<f32> {
+    let mut order: Vec<usize> = (0..rects.len()).collect();
+    order.sort_by(|&a, &b| rects[a].y.total_cmp(&rects[b].y));
+    let mut shifts = vec![0.0; rects.len()];
+    let mut placed: Vec<Rectangle> = Vec::with_capacity(rects.len());
+    for i in order {
+        let mut rect = rects[i];
+        while let Some(blocker) = placed.iter().find(|p| {
+            rect.x < p.x + p.width
+                && p.x < rect.x + rect.width
+                && rect.y < p.y + p.height + padding
+                && p.y < rect.y + rect.height + padding
+        }) {
+            rect.y = blocker.y + blocker.height + padding;
+        }
+        shifts[i] = rect.y - rects[i].y;
+        placed.push(rect);
+    }
+    shifts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn leader_line_keeps_the_dash_and_arrow() {
+        let leader = AnnotLineConfigBuilder::default()
+            .target(Datapoint::new(1.0, 2.0))
+            .dash((5.0, 3.0))
+            .color(Color::BLUE)
+            .build()
+            .unwrap()
+            .leader_config();
+        assert_eq!(leader.dash, Some((5.0, 3.0)));
+        assert_eq!(leader.color, Some(Color::BLUE));
+        assert!(matches!(leader.arrow, Visibility::Visible));
+    }
+
+    #[test]
+    fn overlapping_labels_are_pushed_down() {
+        let rects = [
+            Rectangle::new(0.0, 10.0, 50.0, 10.0),
+            Rectangle::new(5.0, 0.0, 50.0, 10.0),
+            Rectangle::new(20.0, 5.0, 50.0, 10.0),
+            // Far to the right: untouched.
+            Rectangle::new(200.0, 0.0, 50.0, 10.0),
+        ];
+        let shifts = avoid_overlaps(&rects, 2.0);
+        // Top-most label stays; the others stack below it.
+        assert_eq!(shifts[1], 0.0);
+        assert_eq!(rects[2].y + shifts[2], 12.0);
+        assert_eq!(rects[0].y + shifts[0], 24.0);
+        assert_eq!(shifts[3], 0.0);
     }
 }
 
@@ -0,0 +1,166 @@
+//! Shaded uncertainty bands around a line.
+//!
+//! A [`Band`] fills the area between a lower and an upper boundary and
+//! draws its center line on top: the classic shaded confidence interval
+//! around a regression fit, or a rolling mean ± one standard deviation.
+//! The center line is styled with a [`LinePlotConfig`] and, like a
+//! [`LinePlot`](crate::plottable::lineplot::LinePlot), joins its bends and
+//! breaks at non-finite points; the fill skips any interval with a
+//! non-finite corner.
+//!
+//! # Example
+//!
+//! ```rust
+//! use locus::prelude::*;
+//! let fit = [(0.0, 1.0), (1.0, 1.8), (2.0, 3.1), (3.0, 3.9)];
+//! let spread = [0.2, 0.3, 0.5, 0.4];
+//! let lower: Vec<f32> = fit.iter().zip(&spread).map(|((_, y), s)| y - s).collect();
+//! let upper: Vec<f32> = fit.iter().zip(&spread).map(|((_, y), s)| y + s).collect();
+//! let band = Band::new(&fit, &lower, &upper);
+//! let config = BandConfigBuilder::default().fill_alpha(0.3).build().unwrap();
+//! let graph = Graph::new(band);
+//! ```
+
+use derive_builder::Builder;
+use raylib::prelude::*;
+
+use crate::{
+    colorscheme::{Colorscheme, Themable},
+    plottable::{
+        lineplot::{LinePlotConfig, draw_polyline},
+        point::{Datapoint, fill_triangle},
+        view::{DataBBox, ViewTransformer},
+    },
+    plotter::ChartElement,
+};
+
+/// A center line with a filled band between lower and upper bounds.
+///
+/// See the [module documentation](self) for an example.
+#[derive(Debug, Clone)]
+pub struct Band {
+    /// Center line points, in drawing order.
+    pub center: Vec<Datapoint>,
+    /// Lower boundary at each center point's x.
+    pub lower: Vec<f32>,
+    /// Upper boundary at each center point's x.
+    pub upper: Vec<f32>,
+}
+
+impl Band {
+    /// Create a band from its center line and the lower and upper bound at
+    /// each center point.
+    ///
+    /// The three inputs are paired up by index; extra entries of the longer
+    /// ones are ignored.
+    #[must_use]
+    pub fn new(center: &[(f32, f32)], lower: &[f32], upper: &[f32]) -> Self {
+        let len = center.len().min(lower.len()).min(upper.len());
+        Self {
+            center: center[..len].iter().map(|p| Datapoint::from(*p)).collect(),
+            lower: lower[..len].to_vec(),
+            upper: upper[..len].to_vec(),
+        }
+    }
+
+    /// `(x, lower, upper)` for every point, in order.
+    fn bounds(&self) -> impl Iterator<Item = (f32, f32, f32)> + '_ {
+        self.center
+            .iter()
+            .zip(self.lower.iter().zip(&self.upper))
+            .map(|(p, (lo, hi))| (p.x, *lo, *hi))
+    }
+}
+
+/// Configuration for [`Band`] rendering.
+///
+/// The fill uses `fill_color`, or the center line color when unset; the
+/// line color itself is resolved by theming `line` as for a
+/// [`LinePlot`](crate::plottable::lineplot::LinePlot).
+#[derive(Debug, Clone, Copy, Builder)]
+#[builder(pattern = "owned")]
+#[builder(default)]
+pub struct BandConfig {
+    /// Style of the center line.
+    pub line: LinePlotConfig,
+    /// Explicit fill color. `None` means "use the line color".
+    #[builder(setter(into, strip_option))]
+    pub fill_color: Option<Color>,
+    /// Alpha applied to the fill color.
+    pub fill_alpha: f32,
+}
+
+impl Default for BandConfig {
+    fn default() -> Self {
+        Self {
+            line: LinePlotConfig::default(),
+            fill_color: None,
+            fill_alpha: 0.25,
+        }
+    }
+}
+
+impl ChartElement for Band {
+    type Config = BandConfig;
+
+    fn draw_in_view(
+        &self,
+        rl: &mut RaylibDrawHandle,
+        configs: &Self::Config,
+        view: &ViewTransformer,
+    ) {
+        let color = configs.line.color.unwrap_or(Color::BLACK);
+        let fill = configs
+            .fill_color
+            .unwrap_or(color)
+            .alpha(configs.fill_alpha);
+        let corners: Vec<(f32, f32, f32)> = self.bounds().collect();
+        for pair in corners.windows(2) {
+            let ((x0, lo0, hi0), (x1, lo1, hi1)) = (pair[0], pair[1]);
+            if ![x0, lo0, hi0, x1, lo1, hi1].iter().all(|v| v.is_finite()) {
+                continue;
+            }
+            let at = |x: f32, y: f32| *view.to_screen(&Datapoint::new(x, y));
+            let (a, b, c, d) = (at(x0, lo0), at(x1, lo1), at(x1, hi1), at(x0, hi0));
+            fill_triangle(rl, a, b, c, fill);
+            fill_triangle(rl, a, c, d, fill);
+        }
+        draw_polyline(rl, &self.center, view, configs.line.stroke());
+    }
+
+    /// Covers the center line and both boundaries, ignoring non-finite
+    /// values.
+    fn data_bounds(&self) -> DataBBox {
+        self.bounds()
+            .zip(&self.center)
+            .flat_map(|((x, lo, hi), p)| [(x, lo), (x, hi), (p.x, p.y)])
+            .filter(|(x, y)| x.is_finite() && y.is_finite())
+            .map(|p| DataBBox::from_min_max(p, p))
+            .reduce(|a, b| a.union(&b))
+            .unwrap_or_else(|| DataBBox::from_min_max((0.0, 0.0), (0.0, 0.0)))
+    }
+}
+
+impl Themable for BandConfig {
+    fn apply_theme(&mut self, scheme: &Colorscheme) {
+        self.line.apply_theme(scheme);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bounds_cover_the_whole_band() {
+        let band = Band::new(
+            &[(0.0, 1.0), (1.0, 2.0), (2.0, f32::NAN)],
+            &[0.5, 1.0, 1.5, -100.0],
+            &[1.5, 4.0, 2.5],
+        );
+        assert_eq!(band.center.len(), 3);
+        let bounds = band.data_bounds();
+        assert_eq!((bounds.minimum.x, bounds.maximum.x), (0.0, 2.0));
+        assert_eq!((bounds.minimum.y, bounds.maximum.y), (0.5, 4.0));
+    }
+}
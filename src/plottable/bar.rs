@@ -0,0 +1,367 @@
+//! Grouped and stacked bar charts over categorical slots.
+//!
+//! Both charts take several named [`BarSeries`], each holding one value per
+//! category. Category `i` occupies the slot `i - 0.5..i + 0.5` on the x axis,
+//! so tick `i` sits under its bars. A
+//! [`CategoryAxis`](crate::plottable::ticks::CategoryAxis) set as the tick
+//! labels' `x_categories` writes the category names there instead of numbers.
+//!
+//! * [`GroupedBarChart`] : the series' bars sit side by side within each
+//!   category slot.
+//! * [`StackedBarChart`] : the series' bars are stacked on top of each
+//!   other, so each column shows the category total. Negative values stack
+//!   downward from the baseline.
+//!
+//! Both are styled with a [`BarChartConfig`]. Series colors come from the
+//! theme cycle unless set explicitly, and
+//! [`legend_entries`](GroupedBarChart::legend_entries) produces matching
+//! legend swatches for
+//! [`GraphBuilder::legend_from`](crate::graph::GraphBuilder::legend_from).
+//!
+//! # Example
+//!
+//! ```rust
+//! use locus::prelude::*;
+//!
+//! let chart = StackedBarChart::new(vec![
+//!     BarSeries::new("2024", [3.0, 5.0, 2.0]),
+//!     BarSeries::new("2025", [4.0, 1.0, 6.0]),
+//! ]);
+//! let config = BarChartConfigBuilder::default().group_gap(0.3).build().unwrap();
+//! let legend = chart.legend_entries(&config, &DRACULA);
+//! let graph = Graph::new(chart);
+//! ```
+
+use derive_builder::Builder;
+use raylib::prelude::*;
+
+use crate::{
+    colorscheme::{Colorscheme, Themable, indexed_color},
+    plottable::{
+        legend::LegendEntry,
+        point::{Datapoint, Shape},
+        view::{DataBBox, ViewTransformer},
+    },
+    plotter::ChartElement,
+};
+
+/// A named sequence of values, one per category.
+#[derive(Debug, Clone)]
+pub struct BarSeries {
+    /// Series name, used for legend entries.
+    pub name: String,
+    /// One value per category. Shorter series leave later categories empty.
+    pub values: Vec<f32>,
+}
+
+impl BarSeries {
+    /// Create a series named `name` from its per-category values.
+    #[must_use]
+    pub fn new(name: impl Into<String>, values: impl IntoIterator<Item = f32>) -> Self {
+        Self {
+            name: name.into(),
+            values: values.into_iter().collect(),
+        }
+    }
+}
+
+/// Configuration shared by [`GroupedBarChart`] and [`StackedBarChart`].
+///
+/// When `colors` is `None` it is resolved from the
+/// [`Colorscheme`](crate::colorscheme::Colorscheme) cycle during theme
+/// application; series `i` uses `colors[i % colors.len()]`.
+#[derive(Debug, Clone, Builder)]
+#[builder(pattern = "owned")]
+#[builder(default)]
+pub struct BarChartConfig {
+    /// Fraction of each category slot left empty between neighbouring
+    /// groups, in `0.0..1.0`.
+    pub group_gap: f32,
+    /// Fraction of each bar's share left empty between bars of the same
+    /// group. Only used by [`GroupedBarChart`].
+    pub bar_gap: f32,
+    /// Value the bars grow from (and stacks start at).
+    pub baseline: f32,
+    /// Explicit per-series colors. `None` means "use the theme cycle".
+    #[builder(setter(into, strip_option))]
+    pub colors: Option<Vec<Color>>,
+    /// Alpha applied to the bar fill.
+    pub alpha: f32,
+    /// Optional border as `(color, thickness)`. `None` means no border.
+    #[builder(setter(into, strip_option))]
+    pub border: Option<(Color, f32)>,
+}
+
+impl Default for BarChartConfig {
+    fn default() -> Self {
+        Self {
+            group_gap: 0.2,
+            bar_gap: 0.0,
+            baseline: 0.0,
+            colors: None,
+            alpha: 1.0,
+            border: None,
+        }
+    }
+}
+
+impl BarChartConfig {
+    /// Fill color of series `index`, falling back to `scheme`'s cycle when
+    /// no colors are set.
+    fn series_color(&self, index: usize, scheme: Option<&Colorscheme>) -> Color {
+        indexed_color(self.colors.as_deref(), scheme, index)
+    }
+
+    /// Fill the screen rectangle between two data corners.
+    fn draw_bar(
+        &self,
+        rl: &mut RaylibDrawHandle,
+        a: Datapoint,
+        b: Datapoint,
+        view: &ViewTransformer,
+        color: Color,
+    ) {
+        let (a, b) = (view.to_screen(&a), view.to_screen(&b));
+        let rect = Rectangle::new(
+            a.x.min(b.x),
+            a.y.min(b.y),
+            (a.x - b.x).abs(),
+            (a.y - b.y).abs(),
+        );
+        rl.draw_rectangle_rec(rect, color.alpha(self.alpha));
+        if let Some((color, thickness)) = self.border {
+            rl.draw_rectangle_lines_ex(rect, thickness, color);
+        }
+    }
+
+    /// Legend swatches for `series`, colored like the bars.
+    fn legend_entries(&self, series: &[BarSeries], scheme: &Colorscheme) -> Vec<LegendEntry> {
+        series
+            .iter()
+            .enumerate()
+            .map(|(i, s)| {
+                LegendEntry::new(s.name.clone(), self.series_color(i, Some(scheme)))
+                    .with_shape(Shape::Rectangle)
+            })
+            .collect()
+    }
+}
+
+/// Number of category slots spanned by `series`.
+fn category_count(series: &[BarSeries]) -> usize {
+    series.iter().map(|s| s.values.len()).max().unwrap_or(0)
+}
+
+/// x range covering every category slot.
+#[allow(clippy::cast_precision_loss)]
+fn slot_range(series: &[BarSeries]) -> (f32, f32) {
+    (-0.5, category_count(series) as f32 - 0.5)
+}
+
+/// Bars for several series placed side by side within each category.
+///
+/// See the [module documentation](self) for an example.
+#[derive(Debug, Clone)]
+pub struct GroupedBarChart {
+    /// The series, drawn left to right within each group.
+    pub series: Vec<BarSeries>,
+}
+
+impl GroupedBarChart {
+    /// Create a grouped bar chart from its series.
+    #[must_use]
+    pub fn new(series: Vec<BarSeries>) -> Self {
+        Self { series }
+    }
+
+    /// One legend entry per series, colored like the bars.
+    #[must_use]
+    pub fn legend_entries(
+        &self,
+        configs: &BarChartConfig,
+        scheme: &Colorscheme,
+    ) -> Vec<LegendEntry> {
+        configs.legend_entries(&self.series, scheme)
+    }
+}
+
+impl ChartElement for GroupedBarChart {
+    type Config = BarChartConfig;
+
+    #[allow(clippy::cast_precision_loss)]
+    fn draw_in_view(
+        &self,
+        rl: &mut RaylibDrawHandle,
+        configs: &Self::Config,
+        view: &ViewTransformer,
+    ) {
+        if self.series.is_empty() {
+            return;
+        }
+        let group_width = 1.0 - configs.group_gap.clamp(0.0, 1.0);
+        let share = group_width / self.series.len() as f32;
+        let bar_width = share * (1.0 - configs.bar_gap.clamp(0.0, 1.0));
+        for (s, series) in self.series.iter().enumerate() {
+            let color = configs.series_color(s, None);
+            for (category, value) in series.values.iter().enumerate() {
+                let center = category as f32 - group_width * 0.5 + share * (s as f32 + 0.5);
+                configs.draw_bar(
+                    rl,
+                    Datapoint::new(center - bar_width * 0.5, configs.baseline),
+                    Datapoint::new(center + bar_width * 0.5, *value),
+                    view,
+                    color,
+                );
+            }
+        }
+    }
+
+    /// All category slots, and every value together with the baseline.
+    ///
+    /// Uses the default baseline of `0.0`, since bounds are computed without
+    /// the configuration.
+    fn data_bounds(&self) -> DataBBox {
+        let (x0, x1) = slot_range(&self.series);
+        let (low, high) = self
+            .series
+            .iter()
+            .flat_map(|s| s.values.iter())
+            .fold((0.0_f32, 0.0_f32), |(lo, hi), v| (lo.min(*v), hi.max(*v)));
+        DataBBox::from_min_max((x0, low), (x1, high))
+    }
+}
+
+/// Bars for several series stacked within each category.
+///
+/// See the [module documentation](self) for an example.
+#[derive(Debug, Clone)]
+pub struct StackedBarChart {
+    /// The series, stacked bottom to top in order.
+    pub series: Vec<BarSeries>,
+}
+
+impl StackedBarChart {
+    /// Create a stacked bar chart from its series.
+    #[must_use]
+    pub fn new(series: Vec<BarSeries>) -> Self {
+        Self { series }
+    }
+
+    /// One legend entry per series, colored like the bars.
+    #[must_use]
+    pub fn legend_entries(
+        &self,
+        configs: &BarChartConfig,
+        scheme: &Colorscheme,
+    ) -> Vec<LegendEntry> {
+        configs.legend_entries(&self.series, scheme)
+    }
+
+    /// Per-category `(negative, positive)` stack totals, relative to the
+    /// baseline.
+    fn totals(&self) -> Vec<(f32, f32)> {
+        let mut totals = vec![(0.0, 0.0); category_count(&self.series)];
+        for series in &self.series {
+            for (total, value) in totals.iter_mut().zip(&series.values) {
+                if *value < 0.0 {
+                    total.0 += value;
+                } else {
+                    total.1 += value;
+                }
+            }
+        }
+        totals
+    }
+}
+
+impl ChartElement for StackedBarChart {
+    type Config = BarChartConfig;
+
+    #[allow(clippy::cast_precision_loss)]
+    fn draw_in_view(
+        &self,
+        rl: &mut RaylibDrawHandle,
+        configs: &Self::Config,
+        view: &ViewTransformer,
+    ) {
+        let half = (1.0 - configs.group_gap.clamp(0.0, 1.0)) * 0.5;
+        // Running tops of the negative and positive stacks per category.
+        let mut stacks = vec![(configs.baseline, configs.baseline); category_count(&self.series)];
+        for (s, series) in self.series.iter().enumerate() {
+            let color = configs.series_color(s, None);
+            for (category, (value, stack)) in series.values.iter().zip(&mut stacks).enumerate() {
+                let top = if *value < 0.0 {
+                    &mut stack.0
+                } else {
+                    &mut stack.1
+                };
+                let start = *top;
+                *top += value;
+                let x = category as f32;
+                configs.draw_bar(
+                    rl,
+                    Datapoint::new(x - half, start),
+                    Datapoint::new(x + half, *top),
+                    view,
+                    color,
+                );
+            }
+        }
+    }
+
+    /// All category slots, and the tallest positive and negative stacks.
+    ///
+    /// Uses the default baseline of `0.0`, since bounds are computed without
+    /// the configuration.
+    fn data_bounds(&self) -> DataBBox {
+        let (x0, x1) = slot_range(&self.series);
+        let (low, high) = self
+            .totals()
+            .into_iter()
+            .fold((0.0_f32, 0.0_f32), |(lo, hi), (neg, pos)| {
+                (lo.min(neg), hi.max(pos))
+            });
+        DataBBox::from_min_max((x0, low), (x1, high))
+    }
+}
+
+impl Themable for BarChartConfig {
+    fn apply_theme(&mut self, scheme: &Colorscheme) {
+        if self.colors.is_none() && !scheme.cycle.is_empty() {
+            self.colors = Some(scheme.cycle.to_vec());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stacked_bounds_sum_each_sign_separately() {
+        let chart = StackedBarChart::new(vec![
+            BarSeries::new("a", [1.0, -2.0]),
+            BarSeries::new("b", [3.0, -1.0, 4.0]),
+        ]);
+        let bounds = chart.data_bounds();
+        assert_eq!((bounds.minimum.x, bounds.maximum.x), (-0.5, 2.5));
+        assert_eq!((bounds.minimum.y, bounds.maximum.y), (-3.0, 4.0));
+    }
+
+    #[test]
+    fn grouped_bounds_include_baseline() {
+        let chart = GroupedBarChart::new(vec![BarSeries::new("a", [2.0, 5.0])]);
+        let bounds = chart.data_bounds();
+        assert_eq!((bounds.minimum.y, bounds.maximum.y), (0.0, 5.0));
+    }
+
+    #[test]
+    fn explicit_colors_cycle_per_series() {
+        let config = BarChartConfigBuilder::default()
+            .colors(vec![Color::RED, Color::BLUE])
+            .build()
+            .unwrap();
+        assert_eq!(config.series_color(2, None).r, Color::RED.r);
+        assert_eq!(config.series_color(1, None).b, Color::BLUE.b);
+    }
+}
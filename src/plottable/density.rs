@@ -0,0 +1,321 @@
+//! Kernel density estimates of one-dimensional samples.
+//!
+//! A [`DensityPlot`] turns raw samples into a smooth curve by evaluating a
+//! Gaussian kernel density estimate (KDE) on an evenly spaced grid, then
+//! drawing it as a polyline with an optional fill down to zero. The kernel
+//! width is chosen by [`Bandwidth`]: either fixed, or picked automatically
+//! with Silverman's rule of thumb.
+//!
+//! The curve extends three bandwidths past the smallest and largest sample
+//! so the tails fade out inside the view. It is evaluated once, when the
+//! plot is built, and reused every frame.
+//!
+//! # Example
+//!
+//! ```rust
+//! use locus::prelude::*;
+//! let samples = [1.0, 1.2, 1.9, 2.3, 2.4, 3.1, 4.0];
+//! let density = DensityPlot::new(&samples).with_bandwidth(Bandwidth::Fixed(0.4));
+//! let config = DensityConfigBuilder::default().fill(true).build().unwrap();
+//! let graph = Graph::new(density);
+//! ```
+
+use std::f32::consts::TAU;
+
+use derive_builder::Builder;
+use raylib::prelude::*;
+
+use crate::{
+    colorscheme::Themable,
+    plottable::{
+        point::{Datapoint, fill_triangle},
+        view::{DataBBox, ViewTransformer},
+    },
+    plotter::ChartElement,
+};
+
+/// How wide each Gaussian kernel is, in data units.
+#[derive(Debug, Clone, Copy, Default)]
+pub enum Bandwidth {
+    /// Silverman's rule of thumb:
+    /// `0.9 * min(std_dev, IQR / 1.34) * n^(-1/5)`.
+    #[default]
+    Auto,
+    /// A fixed standard deviation for the kernel.
+    Fixed(f32),
+}
+
+impl Bandwidth {
+    /// The kernel width to use for `samples`.
+    ///
+    /// Always positive: degenerate input (fewer than two distinct values, or
+    /// a non-positive fixed width) falls back to `1.0`.
+    #[must_use]
+    pub fn resolve(self, samples: &[f32]) -> f32 {
+        let h = match self {
+            Self::Fixed(h) => h,
+            Self::Auto => silverman(samples),
+        };
+        if h.is_finite() && h > 0.0 { h } else { 1.0 }
+    }
+}
+
+/// Silverman's rule-of-thumb bandwidth, or `0.0` for fewer than two samples.
+#[allow(clippy::cast_precision_loss)]
+fn silverman(samples: &[f32]) -> f32 {
+    if samples.len() < 2 {
+        return 0.0;
+    }
+    let n = samples.len() as f32;
+    let mean = samples.iter().sum::<f32>() / n;
+    let std_dev = (samples.iter().map(|s| (s - mean).powi(2)).sum::<f32>() / (n - 1.0)).sqrt();
+    let mut sorted = samples.to_vec();
+    sorted.sort_by(f32::total_cmp);
+    let iqr = quantile(&sorted, 0.75) - quantile(&sorted, 0.25);
+    // A zero IQR (heavily tied data) would collapse the estimate; fall back
+    // to the standard deviation alone.
+    let spread = if iqr > 0.0 {
+        std_dev.min(iqr / 1.34)
+    } else {
+        std_dev
+    };
+    0.9 * spread * n.powf(-0.2)
+}
+
+/// Linearly interpolated quantile `q` of already sorted, non-empty values.
+#[allow(
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+fn quantile(sorted: &[f32], q: f32) -> f32 {
+    let pos = q * (sorted.len() - 1) as f32;
+    let lower = pos.floor() as usize;
+    let upper = pos.ceil() as usize;
+    let t = pos - lower as f32;
+    sorted[lower] * (1.0 - t) + sorted[upper] * t
+}
+
+/// Evaluate a Gaussian KDE of `samples` with kernel width `bandwidth` at
+/// `resolution` evenly spaced points spanning the samples plus three
+/// bandwidths on either side.
+///
+/// Returns `(x, density)` pairs; empty when there are no samples.
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn kernel_density(samples: &[f32], bandwidth: f32, resolution: usize) -> Vec<Datapoint> {
+    if samples.is_empty() {
+        return Vec::new();
+    }
+    let (min, max) = samples
+        .iter()
+        .fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), &s| {
+            (lo.min(s), hi.max(s))
+        });
+    let (start, end) = (min - 3.0 * bandwidth, max + 3.0 * bandwidth);
+    let steps = resolution.max(2) - 1;
+    let norm = 1.0 / (samples.len() as f32 * bandwidth * TAU.sqrt());
+    (0..=steps)
+        .map(|i| {
+            let x = start + (end - start) * i as f32 / steps as f32;
+            let sum: f32 = samples
+                .iter()
+                .map(|s| (-0.5 * ((x - s) / bandwidth).powi(2)).exp())
+                .sum();
+            Datapoint::new(x, sum * norm)
+        })
+        .collect()
+}
+
+/// A smooth density curve estimated from raw one-dimensional samples.
+///
+/// The bandwidth and resolution shape the curve itself, so they live on the
+/// plot rather than in [`DensityConfig`]; the curve is recomputed only when
+/// they change. See the [module documentation](self) for an example.
+#[derive(Debug, Clone)]
+pub struct DensityPlot<'a> {
+    samples: &'a [f32],
+    bandwidth: Bandwidth,
+    resolution: usize,
+    curve: Vec<Datapoint>,
+}
+
+impl<'a> DensityPlot<'a> {
+    /// Create a density plot over `samples`, with the automatic bandwidth
+    /// evaluated at 200 points.
+    #[must_use]
+    pub fn new(samples: &'a [f32]) -> Self {
+        let mut plot = Self {
+            samples,
+            bandwidth: Bandwidth::Auto,
+            resolution: 200,
+            curve: Vec::new(),
+        };
+        plot.update_curve();
+        plot
+    }
+
+    /// Set the kernel width selection, returning self for chaining.
+    #[must_use]
+    pub fn with_bandwidth(mut self, bandwidth: Bandwidth) -> Self {
+        self.bandwidth = bandwidth;
+        self.update_curve();
+        self
+    }
+
+    /// Set the number of points the density is evaluated at, returning self
+    /// for chaining.
+    #[must_use]
+    pub fn with_resolution(mut self, resolution: usize) -> Self {
+        self.resolution = resolution;
+        self.update_curve();
+        self
+    }
+
+    /// The raw samples being estimated.
+    #[must_use]
+    pub fn samples(&self) -> &'a [f32] {
+        self.samples
+    }
+
+    /// The evaluated `(x, density)` curve that is drawn.
+    #[must_use]
+    pub fn curve(&self) -> &[Datapoint] {
+        &self.curve
+    }
+
+    fn update_curve(&mut self) {
+        let bandwidth = self.bandwidth.resolve(self.samples);
+        self.curve = kernel_density(self.samples, bandwidth, self.resolution);
+    }
+}
+
+/// Configuration for [`DensityPlot`] rendering.
+///
+/// When `color` is `None` it is resolved from the first color of the
+/// [`Colorscheme`](crate::colorscheme::Colorscheme) cycle during theme
+/// application. The fill uses `fill_color`, or the line color when unset.
+#[derive(Debug, Clone, Copy, Builder)]
+#[builder(pattern = "owned")]
+#[builder(default)]
+pub struct DensityConfig {
+    /// Curve thickness in pixels.
+    pub thickness: f32,
+    /// Explicit curve color. `None` means "use the first theme cycle color".
+    #[builder(setter(into, strip_option))]
+    pub color: Option<Color>,
+    /// Whether to shade the area between the curve and zero.
+    pub fill: bool,
+    /// Explicit fill color. `None` means "use the curve color".
+    #[builder(setter(into, strip_option))]
+    pub fill_color: Option<Color>,
+    /// Alpha applied to the fill color.
+    pub fill_alpha: f32,
+}
+
+impl Default for DensityConfig {
+    fn default() -> Self {
+        Self {
+            thickness: 2.0,
+            color: None,
+            fill: false,
+            fill_color: None,
+            fill_alpha: 0.3,
+        }
+    }
+}
+
+impl ChartElement for DensityPlot<'_> {
+    type Config = DensityConfig;
+
+    fn draw_in_view(
+        &self,
+        rl: &mut RaylibDrawHandle,
+        configs: &Self::Config,
+        view: &ViewTransformer,
+    ) {
+        let color = configs.color.unwrap_or(Color::BLACK);
+        if configs.fill {
+            let fill = configs
+                .fill_color
+                .unwrap_or(color)
+                .alpha(configs.fill_alpha);
+            for pair in self.curve.windows(2) {
+                let (a, b) = (*view.to_screen(&pair[0]), *view.to_screen(&pair[1]));
+                let base_a = *view.to_screen(&Datapoint::new(pair[0].x, 0.0));
+                let base_b = *view.to_screen(&Datapoint::new(pair[1].x, 0.0));
+                fill_triangle(rl, base_a, a, b, fill);
+                fill_triangle(rl, base_a, b, base_b, fill);
+            }
+        }
+        let curve: Vec<Vector2> = self.curve.iter().map(|p| *view.to_screen(p)).collect();
+        for pair in curve.windows(2) {
+            rl.draw_line_ex(pair[0], pair[1], configs.thickness, color);
+        }
+    }
+
+    /// Bounds of the drawn curve, from zero up to its peak.
+    fn data_bounds(&self) -> DataBBox {
+        let (Some(first), Some(last)) = (self.curve.first(), self.curve.last()) else {
+            return DataBBox::from_min_max((0.0, 0.0), (0.0, 0.0));
+        };
+        let peak = self.curve.iter().map(|p| p.y).fold(0.0, f32::max);
+        DataBBox::from_min_max((first.x, 0.0), (last.x, peak))
+    }
+}
+
+impl Themable for DensityConfig {
+    fn apply_theme(&mut self, scheme: &crate::colorscheme::Colorscheme) {
+        if self.color.is_none() {
+            self.color = Some(scheme.cycle.first().copied().unwrap_or(Color::BLACK));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn density_integrates_to_one() {
+        let samples = [0.0, 0.5, 1.0, 4.0, 4.2];
+        let h = Bandwidth::Auto.resolve(&samples);
+        let curve = kernel_density(&samples, h, 2000);
+        let area: f32 = curve
+            .windows(2)
+            .map(|w| (w[1].x - w[0].x) * (w[0].y + w[1].y) * 0.5)
+            .sum();
+        assert!((area - 1.0).abs() < 0.01, "area = {area}");
+    }
+
+    #[test]
+    fn bandwidth_falls_back_for_degenerate_input() {
+        assert!((Bandwidth::Auto.resolve(&[2.0, 2.0, 2.0]) - 1.0).abs() < 1e-6);
+        assert!((Bandwidth::Fixed(-1.0).resolve(&[0.0, 1.0]) - 1.0).abs() < 1e-6);
+        assert!((Bandwidth::Fixed(0.25).resolve(&[0.0, 1.0]) - 0.25).abs() < 1e-6);
+    }
+
+    #[test]
+    fn bounds_follow_the_plot_bandwidth() {
+        let samples = [0.0, 1.0, 2.0];
+        let auto = DensityPlot::new(&samples).data_bounds();
+        let narrow = DensityPlot::new(&samples).with_bandwidth(Bandwidth::Fixed(0.1));
+        let bounds = narrow.data_bounds();
+        // Three bandwidths of tail on either side.
+        assert!((bounds.minimum.x + 0.3).abs() < 1e-5);
+        assert!((bounds.maximum.x - 2.3).abs() < 1e-5);
+        // Narrow kernels peak far higher than the automatic ones.
+        assert!(bounds.maximum.y > auto.maximum.y * 2.0);
+        let peak = narrow.curve().iter().map(|p| p.y).fold(0.0, f32::max);
+        assert!((bounds.maximum.y - peak).abs() < 1e-6);
+        assert_eq!(narrow.with_resolution(7).curve().len(), 7);
+    }
+
+    #[test]
+    fn silverman_matches_reference() {
+        // std_dev (n - 1) = 1.5811, IQR = 2.0 -> min(1.5811, 1.4925) = 1.4925
+        let samples = [1.0, 2.0, 3.0, 4.0, 5.0];
+        let expected = 0.9 * 1.492_537 * 5.0_f32.powf(-0.2);
+        assert!((silverman(&samples) - expected).abs() < 1e-4);
+    }
+}
@@ -0,0 +1,221 @@
+//! Two-dimensional histograms of dense point clouds.
+//!
+//! With many thousands of points a scatter plot turns into an opaque blob.
+//! A [`Histogram2D`] instead splits the extent of the data into a regular
+//! grid, counts the points in each cell, and fills every non-empty cell with
+//! the color of its count sampled from a [`Colormap`]. Heavy-tailed
+//! densities read better with
+//! [`log_counts`](Histogram2DConfig::log_counts), which colors by the
+//! logarithm of the count.
+//!
+//! # Example
+//!
+//! ```rust
+//! use locus::prelude::*;
+//! let data = Dataset::from_fn(0.0..10.0, 5_000, |x| (x * 3.0).sin() * x);
+//! let config = Histogram2DConfigBuilder::default()
+//!     .grid_size((60, 40))
+//!     .log_counts(true)
+//!     .build()
+//!     .unwrap();
+//! let graph = Graph::new(Histogram2D::new(&data));
+//! ```
+
+use derive_builder::Builder;
+use raylib::prelude::*;
+
+use crate::{
+    colormap::Colormap,
+    colorscheme::{Themable, VIRIDIS},
+    dataset::Dataset,
+    plottable::{
+        point::Datapoint,
+        view::{DataBBox, ViewTransformer},
+    },
+    plotter::ChartElement,
+};
+
+/// Point counts on a regular grid over the data extent.
+///
+/// See the [module documentation](self) for an example.
+#[derive(Debug, Clone, Copy)]
+pub struct Histogram2D<'a> {
+    /// The points being binned.
+    pub data: &'a Dataset,
+}
+
+impl<'a> Histogram2D<'a> {
+    /// Create a 2D histogram of `data`.
+    #[must_use]
+    pub fn new(data: &'a Dataset) -> Self {
+        Self { data }
+    }
+
+    /// The grid extent and the number of points in each of its
+    /// `columns * rows` cells, row by row from the bottom-left cell.
+    ///
+    /// Non-finite points are skipped. A zero-width or zero-height extent is
+    /// widened by `0.5` on each side so the grid never collapses.
+    #[must_use]
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )]
+    pub fn counts(&self, (columns, rows): (usize, usize)) -> (DataBBox, Vec<usize>) {
+        let (columns, rows) = (columns.max(1), rows.max(1));
+        let widen = |min: f32, max: f32| {
+            if max - min > f32::EPSILON {
+                (min, max)
+            } else {
+                (min - 0.5, max + 0.5)
+            }
+        };
+        let (x0, x1) = widen(self.data.range_min.x, self.data.range_max.x);
+        let (y0, y1) = widen(self.data.range_min.y, self.data.range_max.y);
+        let cell = |v: f32, min: f32, max: f32, n: usize| {
+            (((v - min) / (max - min) * n as f32) as usize).min(n - 1)
+        };
+        let mut counts = vec![0; columns * rows];
+        for p in self
+            .data
+            .data
+            .iter()
+            .filter(|p| p.x.is_finite() && p.y.is_finite())
+        {
+            counts[cell(p.y, y0, y1, rows) * columns + cell(p.x, x0, x1, columns)] += 1;
+        }
+        (DataBBox::from_min_max((x0, y0), (x1, y1)), counts)
+    }
+}
+
+/// Configuration for [`Histogram2D`] rendering.
+#[derive(Debug, Clone, Builder)]
+#[builder(pattern = "owned")]
+#[builder(default)]
+pub struct Histogram2DConfig {
+    /// Number of cells as `(columns, rows)`.
+    pub grid_size: (usize, usize),
+    /// Colors cells from the emptiest (`0.0`) to the fullest (`1.0`).
+    /// Defaults to the ramp of the [`VIRIDIS`] scheme.
+    pub colormap: Colormap,
+    /// Color by `ln(1 + count)` instead of the count, so sparse cells stay
+    /// visible next to very dense ones.
+    pub log_counts: bool,
+}
+
+impl Default for Histogram2DConfig {
+    fn default() -> Self {
+        Self {
+            grid_size: (40, 40),
+            colormap: Colormap {
+                stops: VIRIDIS.cycle.clone(),
+            },
+            log_counts: false,
+        }
+    }
+}
+
+impl Histogram2DConfig {
+    /// Position of `count` on the colormap, given the fullest cell's count.
+    #[allow(clippy::cast_precision_loss)]
+    fn shade(&self, count: usize, max: usize) -> f32 {
+        let (count, max) = (count as f32, max as f32);
+        if self.log_counts {
+            count.ln_1p() / max.ln_1p()
+        } else {
+            count / max
+        }
+    }
+}
+
+impl ChartElement for Histogram2D<'_> {
+    type Config = Histogram2DConfig;
+
+    #[allow(clippy::cast_precision_loss)]
+    fn draw_in_view(
+        &self,
+        rl: &mut RaylibDrawHandle,
+        configs: &Self::Config,
+        view: &ViewTransformer,
+    ) {
+        let (columns, rows) = (configs.grid_size.0.max(1), configs.grid_size.1.max(1));
+        let (extent, counts) = self.counts((columns, rows));
+        let max = counts.iter().copied().max().unwrap_or(0);
+        if max == 0 {
+            return;
+        }
+        let (w, h) = (
+            extent.width() / columns as f32,
+            extent.height() / rows as f32,
+        );
+        for (i, count) in counts.into_iter().enumerate().filter(|(_, c)| *c > 0) {
+            let (column, row) = ((i % columns) as f32, (i / columns) as f32);
+            let corner = |dx: f32, dy: f32| {
+                view.to_screen(&Datapoint::new(
+                    extent.minimum.x + (column + dx) * w,
+                    extent.minimum.y + (row + dy) * h,
+                ))
+            };
+            let (a, b) = (corner(0.0, 0.0), corner(1.0, 1.0));
+            let rect = Rectangle::new(
+                a.x.min(b.x),
+                a.y.min(b.y),
+                (a.x - b.x).abs(),
+                (a.y - b.y).abs(),
+            );
+            rl.draw_rectangle_rec(rect, configs.colormap.sample(configs.shade(count, max)));
+        }
+    }
+
+    /// The extent of the points, widened like the grid when degenerate.
+    fn data_bounds(&self) -> DataBBox {
+        self.counts((1, 1)).0
+    }
+}
+
+/// The colormap is chosen explicitly rather than from the theme cycle,
+/// which is usually qualitative; theming leaves the configuration as is.
+impl Themable for Histogram2DConfig {
+    fn apply_theme(&mut self, _scheme: &crate::colorscheme::Colorscheme) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_bin_points_into_the_grid() {
+        let data = Dataset::new(vec![
+            (0.0, 0.0),
+            (0.1, 0.2),
+            (1.0, 1.0),
+            (0.9, 0.1),
+            (f32::NAN, 0.5),
+        ]);
+        let (extent, counts) = Histogram2D::new(&data).counts((2, 2));
+        assert_eq!((extent.minimum.x, extent.maximum.x), (0.0, 1.0));
+        // Bottom row first: two points bottom-left, one bottom-right, one
+        // top-right (the maximum lands in the last cell).
+        assert_eq!(counts, vec![2, 1, 0, 1]);
+    }
+
+    #[test]
+    fn log_counts_lift_sparse_cells() {
+        let linear = Histogram2DConfig::default();
+        let log = Histogram2DConfigBuilder::default()
+            .log_counts(true)
+            .build()
+            .unwrap();
+        assert!((linear.shade(100, 100) - 1.0).abs() < 1e-6);
+        assert!(log.shade(1, 100) > 3.0 * linear.shade(1, 100));
+    }
+
+    #[test]
+    fn single_point_gets_a_cell() {
+        let data = Dataset::new(vec![(2.0, 3.0)]);
+        let (extent, counts) = Histogram2D::new(&data).counts((3, 3));
+        assert!(extent.width() > 0.0 && extent.height() > 0.0);
+        assert_eq!(counts.iter().sum::<usize>(), 1);
+    }
+}
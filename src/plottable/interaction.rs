@@ -0,0 +1,317 @@
+//! Overlays for interactive inspection of a plot.
+//!
+//! [`Crosshair`] follows the mouse cursor: it drops dashed guide lines from
+//! the cursor to the left and bottom edges of the plotting area and shows
+//! the data coordinates under the cursor in a small labelled box. It is a
+//! screen-space [`PlotElement`], so draw it last, after the graph, to keep
+//! it on top of everything else.
+//!
+//! [`BoxSelect`] tracks a mouse drag for drag-to-zoom: it exposes the
+//! selection rectangle while the drag is in progress and converts it to a
+//! [`DataBBox`] on release, ready to become the new axis limits.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use locus::prelude::*;
+//! # let (mut rl, thread) = raylib::init().build();
+//! # let view = ViewTransformer::new(
+//! #     DataBBox::new((0.0, 0.0), (10.0, 10.0)),
+//! #     Viewport::new(0.0, 0.0, 800.0, 600.0),
+//! # );
+//! let mut config = CrosshairConfig::default();
+//! config.apply_theme(&NORD);
+//! let mut d = rl.begin_drawing(&thread);
+//! let cursor = Screenpoint::from(d.get_mouse_position());
+//! Crosshair::new(cursor, view).plot(&mut d, &config);
+//! ```
+
+use derive_builder::Builder;
+use raylib::prelude::*;
+
+use crate::{
+    colorscheme::{Colorscheme, Themable},
+    plottable::{
+        line::draw_dashed_line,
+        point::Screenpoint,
+        text::{Anchor, HAlign, TextLabel, TextStyle, TextStyleBuilder, VAlign},
+        view::{DataBBox, ViewTransformer},
+    },
+    plotter::PlotElement,
+};
+
+/// Guide lines and a coordinate readout at the mouse cursor.
+///
+/// Nothing is drawn while the cursor is outside the plotting area of
+/// `view`.
+#[derive(Debug, Clone, Copy)]
+pub struct Crosshair {
+    /// Cursor position in screen pixels.
+    pub cursor: Screenpoint,
+    /// The transform of the plot being inspected, used to convert the cursor
+    /// back to data coordinates.
+    pub view: ViewTransformer,
+}
+
+impl Crosshair {
+    /// Create a crosshair at `cursor` over the plot drawn with `view`.
+    #[must_use]
+    pub fn new(cursor: impl Into<Screenpoint>, view: ViewTransformer) -> Self {
+        Self {
+            cursor: cursor.into(),
+            view,
+        }
+    }
+
+    /// The readout text, `"(x, y)"` in data coordinates.
+    fn readout(&self, precision: usize) -> String {
+        let data = self.view.to_data(&self.cursor);
+        format!("({:.precision$}, {:.precision$})", data.x, data.y)
+    }
+}
+
+/// Configuration for [`Crosshair`] rendering.
+///
+/// When `color` is `None` it is resolved from
+/// [`Colorscheme::axis`](crate::colorscheme::Colorscheme::axis) during theme
+/// application. An unset readout background is filled with the scheme's
+/// background color, so the readout gets a box once themed.
+#[derive(Debug, Clone, Builder)]
+#[builder(pattern = "owned")]
+#[builder(default)]
+pub struct CrosshairConfig {
+    /// Explicit guide line color. `None` means "use the theme axis color".
+    #[builder(setter(into, strip_option))]
+    pub color: Option<Color>,
+    /// Guide line thickness in pixels.
+    pub thickness: f32,
+    /// Guide line dash pattern as `(dash, gap)` lengths in pixels.
+    pub dash: (f32, f32),
+    /// Number of decimals shown in the readout.
+    pub precision: usize,
+    /// Style of the readout. Its `offset` places the box relative to the
+    /// cursor.
+    pub label_style: TextStyle,
+}
+
+impl Default for CrosshairConfig {
+    fn default() -> Self {
+        Self {
+            color: None,
+            thickness: 1.0,
+            dash: (4.0, 4.0),
+            precision: 2,
+            label_style: TextStyleBuilder::default()
+                .font_size(14.0)
+                .anchor(Anchor {
+                    h: HAlign::Left,
+                    v: VAlign::Bottom,
+                })
+                .offset(Vector2::new(10.0, -10.0))
+                .corner_radius(3.0)
+                .build()
+                .expect("Will never fail"),
+        }
+    }
+}
+
+impl PlotElement for Crosshair {
+    type Config = CrosshairConfig;
+
+    fn plot(&self, rl: &mut RaylibDrawHandle, configs: &Self::Config) {
+        let inner = self.view.screen_bounds.inner_bbox();
+        if !inner.contains(&self.cursor) {
+            return;
+        }
+        let color = configs.color.unwrap_or(Color::BLACK);
+        let (x, y) = (self.cursor.x, self.cursor.y);
+        // Down to the x axis, then across to the y axis.
+        draw_dashed_line(
+            rl,
+            *self.cursor,
+            Vector2::new(x, inner.maximum.y),
+            configs.thickness,
+            configs.dash,
+            color,
+        );
+        draw_dashed_line(
+            rl,
+            *self.cursor,
+            Vector2::new(inner.minimum.x, y),
+            configs.thickness,
+            configs.dash,
+            color,
+        );
+        TextLabel::new(self.readout(configs.precision), self.cursor).plot(rl, &configs.label_style);
+    }
+}
+
+impl Themable for CrosshairConfig {
+    fn apply_theme(&mut self, scheme: &Colorscheme) {
+        if self.color.is_none() {
+            self.color = Some(scheme.axis);
+        }
+        if self.label_style.background.is_none() {
+            self.label_style.background = Some(scheme.background);
+        }
+        self.label_style.apply_theme(scheme);
+    }
+}
+
+/// Selections narrower or shorter than this many pixels are treated as
+/// clicks and ignored.
+const MIN_SELECTION_PIXELS: f32 = 1.0;
+
+/// A drag-to-zoom selection in progress.
+///
+/// Feed it the cursor while a mouse button is held, draw
+/// [`rectangle`](BoxSelect::rectangle) as feedback, and call
+/// [`finish`](BoxSelect::finish) on release.
+/// [`track`](BoxSelect::track) does all three steps from raylib's mouse
+/// state.
+///
+/// ```rust,no_run
+/// use locus::prelude::*;
+/// use raylib::prelude::*;
+/// # let (mut rl, thread) = raylib::init().build();
+/// # let view = ViewTransformer::new(
+/// #     DataBBox::new((0.0, 0.0), (10.0, 10.0)),
+/// #     Viewport::new(0.0, 0.0, 800.0, 600.0),
+/// # );
+/// let mut selection = BoxSelect::default();
+/// if let Some(bounds) = selection.track(&rl, MouseButton::MOUSE_BUTTON_LEFT, &view) {
+///     // Rebuild the axis from `bounds`.
+/// }
+/// let mut d = rl.begin_drawing(&thread);
+/// if let Some(rect) = selection.rectangle() {
+///     d.draw_rectangle_lines_ex(rect, 1.0, Color::GRAY);
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BoxSelect {
+    /// Drag start and current cursor position; `None` when idle.
+    drag: Option<(Screenpoint, Screenpoint)>,
+}
+
+impl BoxSelect {
+    /// Start a selection at `at`, discarding any selection in progress.
+    pub fn begin(&mut self, at: impl Into<Screenpoint>) {
+        let at = at.into();
+        self.drag = Some((at, at));
+    }
+
+    /// Move the free corner of the selection to `to`. Does nothing when no
+    /// selection is in progress.
+    pub fn update(&mut self, to: impl Into<Screenpoint>) {
+        if let Some((_, current)) = &mut self.drag {
+            *current = to.into();
+        }
+    }
+
+    /// Whether a selection is in progress.
+    #[must_use]
+    pub fn is_active(&self) -> bool {
+        self.drag.is_some()
+    }
+
+    /// The selection in screen pixels, for drawing. `None` when idle.
+    #[must_use]
+    pub fn rectangle(&self) -> Option<Rectangle> {
+        self.drag.map(|(start, current)| {
+            Rectangle::new(
+                start.x.min(current.x),
+                start.y.min(current.y),
+                (start.x - current.x).abs(),
+                (start.y - current.y).abs(),
+            )
+        })
+    }
+
+    /// End the selection and return the data bounds it covers under
+    /// `view`.
+    ///
+    /// Returns `None` when no selection was in progress or when it has
+    /// (next to) zero width or height, so a plain click does not zoom.
+    pub fn finish(&mut self, view: &ViewTransformer) -> Option<DataBBox> {
+        let (start, current) = self.drag.take()?;
+        if (start.x - current.x).abs() < MIN_SELECTION_PIXELS
+            || (start.y - current.y).abs() < MIN_SELECTION_PIXELS
+        {
+            return None;
+        }
+        Some(DataBBox::new(view.to_data(&start), view.to_data(&current)))
+    }
+
+    /// Drive the selection from raylib's mouse state: begin when `button`
+    /// is pressed, follow the cursor while it is held, and
+    /// [`finish`](BoxSelect::finish) when it is released.
+    pub fn track(
+        &mut self,
+        rl: &RaylibHandle,
+        button: MouseButton,
+        view: &ViewTransformer,
+    ) -> Option<DataBBox> {
+        let cursor = rl.get_mouse_position();
+        if rl.is_mouse_button_pressed(button) {
+            self.begin(cursor);
+        } else if rl.is_mouse_button_down(button) {
+            self.update(cursor);
+        } else if rl.is_mouse_button_released(button) {
+            self.update(cursor);
+            return self.finish(view);
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plottable::view::Viewport;
+
+    #[test]
+    fn box_select_returns_data_bounds() {
+        let view = ViewTransformer::new(
+            DataBBox::new((0.0, 0.0), (10.0, 10.0)),
+            Viewport::new(0.0, 0.0, 100.0, 100.0),
+        );
+        let mut selection = BoxSelect::default();
+        selection.begin(Vector2::new(60.0, 20.0));
+        selection.update(Vector2::new(20.0, 80.0));
+        let rect = selection.rectangle().unwrap();
+        assert_eq!(
+            (rect.x, rect.y, rect.width, rect.height),
+            (20.0, 20.0, 40.0, 60.0)
+        );
+
+        let bounds = selection.finish(&view).unwrap();
+        assert!((bounds.minimum.x - 2.0).abs() < 1e-4);
+        assert!((bounds.maximum.x - 6.0).abs() < 1e-4);
+        assert!((bounds.minimum.y - 2.0).abs() < 1e-4);
+        assert!((bounds.maximum.y - 8.0).abs() < 1e-4);
+        assert!(!selection.is_active());
+    }
+
+    #[test]
+    fn box_select_ignores_zero_area() {
+        let view = ViewTransformer::new(
+            DataBBox::new((0.0, 0.0), (10.0, 10.0)),
+            Viewport::new(0.0, 0.0, 100.0, 100.0),
+        );
+        let mut selection = BoxSelect::default();
+        selection.begin(Vector2::new(30.0, 30.0));
+        selection.update(Vector2::new(70.0, 30.0));
+        assert!(selection.finish(&view).is_none());
+        assert!(selection.finish(&view).is_none());
+    }
+
+    #[test]
+    fn readout_shows_data_coordinates() {
+        let view = ViewTransformer::new(
+            DataBBox::new((0.0, 0.0), (10.0, 10.0)),
+            Viewport::new(0.0, 0.0, 100.0, 100.0),
+        );
+        let crosshair = Crosshair::new(Vector2::new(25.0, 25.0), view);
+        assert_eq!(crosshair.readout(1), "(2.5, 7.5)");
+    }
+}
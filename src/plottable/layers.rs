@@ -0,0 +1,344 @@
+//! Overlaying several chart elements in a single coordinate system.
+//!
+//! A [`Layers`] container holds a heterogeneous list of chart elements, each
+//! stored together with its own configuration, and implements
+//! [`ChartElement`] itself so that it can be the subject of a
+//! [`Graph`](crate::graph::Graph). All layers share one
+//! [`ViewTransformer`], and the combined
+//! [`data_bounds`](ChartElement::data_bounds) is the union of every layer's
+//! bounds.
+//!
+//! Every layer has a z-index, `0` unless set with
+//! [`with_layer_at`](Layers::with_layer_at) or [`push_at`](Layers::push_at).
+//! Layers are drawn from the lowest z to the highest, so higher layers appear
+//! on top; layers sharing a z are drawn in insertion order.
+//!
+//! Theme defaults of the layers' configurations are resolved once, while the
+//! stack is built: pass the graph's scheme to
+//! [`with_colorscheme`](Layers::with_colorscheme).
+//!
+//! # Order within a graph
+//!
+//! The whole stack is the data step of [`Graph`](crate::graph::Graph)'s
+//! fixed sequence, so z only orders layers among themselves:
+//!
+//! 1. plot background, grid lines, and shaded regions, below every layer;
+//! 2. the layers, by z;
+//! 3. reference lines and the plot border;
+//! 4. axes, ticks, titles, and axis labels;
+//! 5. the legend, then annotations, above everything.
+//!
+//! Data that must sit above the axes or below the grid therefore belongs in
+//! the graph's chrome (e.g. an annotation or a region) rather than a layer.
+//!
+//! # Example
+//!
+//! ```rust
+//! use locus::prelude::*;
+//! use raylib::color::Color;
+//! # let points = Dataset::new(vec![(0.0, 0.0), (1.0, 1.0), (2.0, 2.0)]);
+//! # let highlights = Dataset::new(vec![(1.0, 1.5)]);
+//! # let trend = Line::new((0.0, 0.2), (2.0, 1.8));
+//! let layers = Layers::new()
+//!     .with_colorscheme(&DRACULA)
+//!     .with_layer(ScatterPlot::new(&points), ScatterPlotConfig::default())
+//!     .with_layer(
+//!         ScatterPlot::new(&highlights),
+//!         ScatterPlotBuilder::default()
+//!             .fixed_color(Color::RED)
+//!             .build()
+//!             .unwrap(),
+//!     )
+//!     // Added last, but drawn under both scatter plots.
+//!     .with_layer_at(-1, trend, LineConfig::default());
+//! let graph = Graph::new(layers);
+//! let config: GraphConfig<Layers> = GraphBuilder::default()
+//!     .colorscheme(&DRACULA)
+//!     .build()
+//!     .unwrap();
+//! ```
+
+use raylib::prelude::RaylibDrawHandle;
+
+use crate::{
+    colorscheme::{Colorscheme, Themable},
+    plottable::view::{DataBBox, ViewTransformer},
+    plotter::{ChartElement, DynChartElement},
+};
+
+/// A chart element stored with its configuration.
+struct Layer<E: ChartElement> {
+    element: E,
+    configs: E::Config,
+}
+
+impl<E> DynChartElement for Layer<E>
+where
+    E: ChartElement,
+    E::Config: Themable,
+{
+    fn apply_theme(&mut self, scheme: &Colorscheme) {
+        self.configs.apply_theme(scheme);
+    }
+
+    fn draw_in_view(&self, rl: &mut RaylibDrawHandle, view: &ViewTransformer) {
+        self.element.draw_in_view(rl, &self.configs, view);
+    }
+
+    fn data_bounds(&self) -> DataBBox {
+        self.element.data_bounds()
+    }
+
+    fn min_positive(&self) -> (Option<f32>, Option<f32>) {
+        self.element.min_positive()
+    }
+}
+
+/// An ordered stack of chart elements sharing one view.
+///
+/// See the [module documentation](self) for an example.
+#[derive(Default)]
+pub struct Layers<'a> {
+    /// Layers with their z-index, kept sorted by z in insertion order.
+    layers: Vec<(i32, Box<dyn DynChartElement + 'a>)>,
+    /// Scheme every layer is themed with as it is inserted.
+    colorscheme: Option<Colorscheme>,
+}
+
+impl<'a> Layers<'a> {
+    /// Create an empty layer stack.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            layers: Vec::new(),
+            colorscheme: None,
+        }
+    }
+
+    /// Resolve the theme defaults of every layer from `scheme`: the layers
+    /// already in the stack now, and later ones as they are inserted.
+    /// Returns self for chaining.
+    ///
+    /// Use the scheme the graph is drawn with. Without one, layers keep
+    /// their configurations' own fallbacks.
+    #[must_use]
+    pub fn with_colorscheme(mut self, scheme: &Colorscheme) -> Self {
+        for (_, layer) in &mut self.layers {
+            layer.apply_theme(scheme);
+        }
+        self.colorscheme = Some(scheme.clone());
+        self
+    }
+
+    /// Append `element`, drawn with `configs`, at z-index `0`: on top of the
+    /// existing layers up to that z.
+    pub fn push<E>(&mut self, element: E, configs: E::Config)
+    where
+        E: ChartElement + 'a,
+        E::Config: Themable,
+    {
+        self.push_at(0, element, configs);
+    }
+
+    /// Insert `element`, drawn with `configs`, at z-index `z`: above every
+    /// layer with a lower or equal z and below those with a higher one.
+    pub fn push_at<E>(&mut self, z: i32, element: E, configs: E::Config)
+    where
+        E: ChartElement + 'a,
+        E::Config: Themable,
+    {
+        self.push_dyn_at(z, Box::new(Layer { element, configs }));
+    }
+
+    /// Append an already type-erased element at z-index `0`.
+    pub fn push_dyn(&mut self, element: Box<dyn DynChartElement + 'a>) {
+        self.push_dyn_at(0, element);
+    }
+
+    /// Insert an already type-erased element at z-index `z`.
+    pub fn push_dyn_at(&mut self, z: i32, mut element: Box<dyn DynChartElement + 'a>) {
+        if let Some(scheme) = &self.colorscheme {
+            element.apply_theme(scheme);
+        }
+        let index = self.layers.partition_point(|(other, _)| *other <= z);
+        self.layers.insert(index, (z, element));
+    }
+
+    /// Append `element`, drawn with `configs`, returning self for chaining.
+    #[must_use]
+    pub fn with_layer<E>(mut self, element: E, configs: E::Config) -> Self
+    where
+        E: ChartElement + 'a,
+        E::Config: Themable,
+    {
+        self.push(element, configs);
+        self
+    }
+
+    /// Insert `element`, drawn with `configs`, at z-index `z`, returning
+    /// self for chaining.
+    #[must_use]
+    pub fn with_layer_at<E>(mut self, z: i32, element: E, configs: E::Config) -> Self
+    where
+        E: ChartElement + 'a,
+        E::Config: Themable,
+    {
+        self.push_at(z, element, configs);
+        self
+    }
+
+    /// The z-index of every layer, in drawing order.
+    #[must_use]
+    pub fn z_order(&self) -> Vec<i32> {
+        self.layers.iter().map(|(z, _)| *z).collect()
+    }
+
+    /// Number of layers in the stack.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.layers.len()
+    }
+
+    /// Whether the stack has no layers.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.layers.is_empty()
+    }
+}
+
+/// Configuration for [`Layers`].
+///
+/// Each layer carries its own configuration, themed while the stack is built
+/// (see [`Layers::with_colorscheme`]), so the stack itself has no options.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LayersConfig;
+
+impl Themable for LayersConfig {
+    fn apply_theme(&mut self, _scheme: &Colorscheme) {}
+}
+
+impl ChartElement for Layers<'_> {
+    type Config = LayersConfig;
+
+    fn draw_in_view(
+        &self,
+        rl: &mut RaylibDrawHandle,
+        _configs: &Self::Config,
+        view: &ViewTransformer,
+    ) {
+        for (_, layer) in &self.layers {
+            layer.draw_in_view(rl, view);
+        }
+    }
+
+    fn data_bounds(&self) -> DataBBox {
+        self.layers
+            .iter()
+            .map(|(_, layer)| layer.data_bounds())
+            .reduce(|a, b| a.union(&b))
+            .unwrap_or_else(|| DataBBox::from_min_max((0.0, 0.0), (0.0, 0.0)))
+    }
+
+    fn min_positive(&self) -> (Option<f32>, Option<f32>) {
+        self.layers
+            .iter()
+            .map(|(_, layer)| layer.min_positive())
+            .fold((None, None), |(x, y), (lx, ly)| {
+                (min_option(x, lx), min_option(y, ly))
+            })
+    }
+}
+
+/// The smaller of two optional values, or whichever one is present.
+fn min_option(a: Option<f32>, b: Option<f32>) -> Option<f32> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(a.min(b)),
+        (a, b) => a.or(b),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, rc::Rc};
+
+    use raylib::color::Color;
+
+    use super::*;
+    use crate::plottable::{
+        line::{Line, LineConfig},
+        reference::{HLine, ReferenceLineConfig},
+    };
+
+    /// An element whose configuration reports the axis color it was themed
+    /// with, and how often.
+    struct Probe;
+
+    #[derive(Default)]
+    struct ProbeConfig {
+        themed: Rc<Cell<(Option<Color>, usize)>>,
+    }
+
+    impl Themable for ProbeConfig {
+        fn apply_theme(&mut self, scheme: &Colorscheme) {
+            let (_, count) = self.themed.get();
+            self.themed.set((Some(scheme.axis), count + 1));
+        }
+    }
+
+    impl ChartElement for Probe {
+        type Config = ProbeConfig;
+
+        fn draw_in_view(&self, _: &mut RaylibDrawHandle, _: &ProbeConfig, _: &ViewTransformer) {}
+
+        fn data_bounds(&self) -> DataBBox {
+            DataBBox::from_min_max((0.0, 0.0), (0.0, 0.0))
+        }
+    }
+
+    #[test]
+    fn every_layer_is_themed_once_while_building() {
+        let (before, after) = (ProbeConfig::default(), ProbeConfig::default());
+        let (seen_before, seen_after) = (before.themed.clone(), after.themed.clone());
+        let scheme = Colorscheme::new(
+            Color::WHITE,
+            Color::LIGHTGRAY,
+            Color::BLACK,
+            Color::MAROON,
+            vec![Color::RED],
+        );
+        let layers = Layers::new()
+            .with_layer(Probe, before)
+            .with_colorscheme(&scheme)
+            .with_layer_at(-1, Probe, after);
+        assert_eq!(seen_before.get(), (Some(Color::MAROON), 1));
+        assert_eq!(seen_after.get(), (Some(Color::MAROON), 1));
+        assert_eq!(layers.len(), 2);
+    }
+
+    #[test]
+    fn bounds_are_the_union_of_the_layers() {
+        let layers = Layers::new()
+            .with_layer(Line::new((0.0, 1.0), (2.0, 3.0)), LineConfig::default())
+            .with_layer(Line::new((-1.0, 2.0), (1.0, 2.5)), LineConfig::default())
+            // Only stretches y; the x range comes from the lines.
+            .with_layer(HLine(-4.0), ReferenceLineConfig::default());
+        let bounds = layers.data_bounds();
+        assert!((bounds.minimum.x + 1.0).abs() < 1e-6);
+        assert!((bounds.maximum.x - 2.0).abs() < 1e-6);
+        assert!((bounds.minimum.y + 4.0).abs() < 1e-6);
+        assert!((bounds.maximum.y - 3.0).abs() < 1e-6);
+        assert!(Layers::new().data_bounds().width().abs() < 1e-6);
+    }
+
+    #[test]
+    fn layers_are_kept_in_z_order() {
+        let line = || Line::new((0.0, 0.0), (1.0, 1.0));
+        let layers = Layers::new()
+            .with_layer(line(), LineConfig::default())
+            .with_layer_at(2, line(), LineConfig::default())
+            .with_layer_at(-1, line(), LineConfig::default())
+            .with_layer(line(), LineConfig::default());
+        assert_eq!(layers.z_order(), vec![-1, 0, 0, 2]);
+        assert_eq!(layers.len(), 4);
+    }
+}
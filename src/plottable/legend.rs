@@ -1,8 +1,9 @@
 //! Configurable legend box with color swatches and text labels.
 //!
 //! A [`Legend`] is a list of [`LegendEntry`] items rendered inside the graph
-//! viewport. Each entry shows a colored shape indicator next to a text label,
-//! making it easy for viewers to identify data series.
+//! viewport. Each entry shows a colored indicator (a marker shape or a short
+//! line segment) next to a text label, making it easy for viewers to identify
+//! data series. An optional title sits at the top of the box.
 //!
 //! Legends are added to a graph through
 //! [`GraphBuilder::legend`](crate::graph::GraphBuilder::legend) or
@@ -34,7 +35,7 @@ use crate::{
     Anchor, TextLabel,
     colorscheme::Themable,
     plottable::{
-        point::{Screenpoint, Shape},
+        point::{PointConfigBuilder, Screenpoint, Shape},
         text::{TextStyle, TextStyleBuilder},
     },
     plotter::{ChartElement, PlotElement},
@@ -56,15 +57,34 @@ pub enum LegendPosition {
     Custom(f32, f32),
 }
 
-/// A single entry in a legend: a color swatch, indicator shape, and label.
+/// What a legend entry draws next to its label.
+#[derive(Debug, Clone, Copy)]
+pub enum IndicatorKind {
+    /// A marker of the entry's [`shape`](LegendEntry::shape), matching
+    /// scatter-style series.
+    Marker,
+    /// A short horizontal line segment, matching line-style series.
+    Line {
+        /// Stroke thickness in pixels.
+        thickness: f32,
+    },
+}
+
+/// A single entry in a legend: a color swatch, indicator, and label.
 #[derive(Debug, Clone)]
 pub struct LegendEntry {
     /// Display text for this entry.
     pub label: String,
-    /// Color of the shape indicator.
+    /// Color of the indicator.
     pub color: Color,
     /// Shape used for the indicator swatch.
     pub shape: Shape,
+    /// Indicator drawn next to the label.
+    pub indicator: IndicatorKind,
+    /// Whether the swatch is filled or drawn as an outline only.
+    pub filled: bool,
+    /// Border color of the swatch (or stroke color when hollow).
+    pub outline_color: Option<Color>,
 }
 
 impl LegendEntry {
@@ -76,6 +96,9 @@ impl LegendEntry {
             label: label.into(),
             color,
             shape: Shape::Circle,
+            indicator: IndicatorKind::Marker,
+            filled: true,
+            outline_color: None,
         }
     }
 
@@ -83,6 +106,28 @@ impl LegendEntry {
     #[must_use]
     pub fn with_shape(mut self, shape: Shape) -> Self {
         self.shape = shape;
+        self.indicator = IndicatorKind::Marker;
+        self
+    }
+
+    /// Use a line segment of the given thickness as the indicator.
+    #[must_use]
+    pub fn with_line(mut self, thickness: f32) -> Self {
+        self.indicator = IndicatorKind::Line { thickness };
+        self
+    }
+
+    /// Draw the swatch as an outline only, matching hollow scatter markers.
+    #[must_use]
+    pub fn hollow(mut self) -> Self {
+        self.filled = false;
+        self
+    }
+
+    /// Draw a border of the given color around the swatch.
+    #[must_use]
+    pub fn with_outline(mut self, color: Color) -> Self {
+        self.outline_color = Some(color);
         self
     }
 }
@@ -125,6 +170,12 @@ pub struct LegendConfig {
     /// Optional border as `(color, thickness)`. `None` means no border.
     #[builder(default = "None")]
     pub border: Option<(Color, f32)>,
+    /// Optional title drawn at the top of the box.
+    #[builder(default = "None", setter(into, strip_option))]
+    pub title: Option<String>,
+    /// Title font size relative to `label_style.font_size`.
+    #[builder(default = "1.2")]
+    pub title_scale: f32,
 }
 
 impl Default for LegendConfig {
@@ -147,6 +198,8 @@ impl Default for LegendConfig {
             indicator_size: 8.0,
             indicator_gap: 6.0,
             border: None,
+            title: None,
+            title_scale: 1.2,
         }
     }
 }
@@ -169,9 +222,21 @@ impl ChartElement for Legend {
             None => &rl.get_font_default(),
         };
 
+        let title_style = TextStyle {
+            font_size: configs.label_style.font_size * configs.title_scale,
+            ..configs.label_style.clone()
+        };
+        let title_size = configs
+            .title
+            .as_ref()
+            .map(|title| title_style.measure_text(title, font));
+        // Height the title takes up above the first row, including its gap.
+        let title_height = title_size.map_or(0.0, |size| size.y + configs.entry_spacing);
+
         let row_height = configs.label_style.font_size;
         let n = self.entries.len();
         let total_height = configs.padding * 2.0
+            + title_height
             + (n as f32) * row_height
             + ((n.saturating_sub(1)) as f32) * configs.entry_spacing;
         let mut max_label_width: f32 = 0.0;
@@ -180,10 +245,11 @@ impl ChartElement for Legend {
             max_label_width = max_label_width.max(size.x);
         }
 
-        let total_width = configs.padding * 2.0
+        let total_width = (configs.padding * 2.0
             + configs.indicator_size
             + configs.indicator_gap
-            + max_label_width;
+            + max_label_width)
+            .max(configs.padding * 2.0 + title_size.map_or(0.0, |size| size.x));
 
         let inner_bbox = view.screen_bounds.inner_bbox();
 
@@ -219,39 +285,52 @@ impl ChartElement for Legend {
             );
         }
 
+        if let Some(title) = &configs.title {
+            let origin = Screenpoint::new(
+                legend_box.x + configs.padding,
+                legend_box.y + configs.padding,
+            );
+            TextLabel::new(title, origin).plot(rl, &title_style);
+        }
+
         for (i, entry) in self.entries.iter().enumerate() {
-            let row_y =
-                legend_box.y + configs.padding + (i as f32) * (row_height + configs.entry_spacing);
+            let row_y = legend_box.y
+                + configs.padding
+                + title_height
+                + (i as f32) * (row_height + configs.entry_spacing);
             let swatch_x = legend_box.x + configs.padding;
             let swatch_cy = row_y + row_height * 0.5;
-            // NOTE: Whilst we do have a point primitive where we could use it to draw the shapes, it doesn't
-            // fit the best because of how the icons should be placed. It would be best to unify the API, as
-            // the inclusion of more shapes could be reflected automatically in the legend, instead of having
-            // double code. As of right now, this is somewhat ok.
-            // TODO: Maybe unify to use the point primitive for icon drawing
-            match entry.shape {
-                Shape::Circle => {
-                    rl.draw_circle(
-                        swatch_x as i32 + (configs.indicator_size * 0.5) as i32,
-                        swatch_cy as i32,
-                        configs.indicator_size * 0.5,
-                        entry.color,
-                    );
-                }
-                Shape::Rectangle => {
-                    rl.draw_rectangle_v(
-                        Vector2::new(swatch_x, swatch_cy - configs.indicator_size * 0.5),
-                        Vector2::new(configs.indicator_size, configs.indicator_size),
-                        entry.color,
+            let half = configs.indicator_size * 0.5;
+            match entry.indicator {
+                IndicatorKind::Marker => {
+                    let shape = entry.shape;
+                    // Rectangles hang from their top-left corner and take the
+                    // full side length; every other shape is centred with a
+                    // half-extent.
+                    let (anchor, size) = match shape {
+                        Shape::Rectangle => (
+                            Screenpoint::new(swatch_x, swatch_cy - half),
+                            configs.indicator_size,
+                        ),
+                        _ => (Screenpoint::new(swatch_x + half, swatch_cy), half),
+                    };
+                    anchor.plot(
+                        rl,
+                        &PointConfigBuilder::default()
+                            .color(entry.color)
+                            .size(size)
+                            .shape(shape)
+                            .filled(entry.filled)
+                            .outline_color(entry.outline_color)
+                            .build()
+                            .expect("Failed to build point config"),
                     );
                 }
-                Shape::Triangle => {
-                    let cx = swatch_x + configs.indicator_size * 0.5;
-                    let half = configs.indicator_size * 0.5;
-                    rl.draw_triangle(
-                        Vector2::new(cx, swatch_cy - half),
-                        Vector2::new(cx - half, swatch_cy + half),
-                        Vector2::new(cx + half, swatch_cy + half),
+                IndicatorKind::Line { thickness } => {
+                    rl.draw_line_ex(
+                        Vector2::new(swatch_x, swatch_cy),
+                        Vector2::new(swatch_x + configs.indicator_size, swatch_cy),
+                        thickness,
                         entry.color,
                     );
                 }
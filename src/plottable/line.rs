@@ -7,10 +7,13 @@
 //!   arrowhead.
 //! * [`Axis`] : a pair of perpendicular lines representing the x and y axes,
 //!   with automatic "nice number" range fitting.
-//! * [`GridLines`] : evenly spaced reference lines aligned to the axis, drawn
-//!   behind the data.
+//! * [`GridLines`] : reference lines through the tick positions, drawn
+//!   behind the data. Share an [`AxisTicks`] with the [`TickLabels`] so
+//!   both use identical positions.
 //! * [`TickLabels`] : small marks along each axis with formatted numeric
 //!   labels.
+//! * [`PixelGrid`] : a fixed screen-space grid every few pixels, independent
+//!   of the data, for aligning screen-space elements and layout debugging.
 //!
 //! Each element has an associated `*Config` / `*Configs` type (built via
 //! `derive_builder`) and implements either [`PlotElement`] or
@@ -25,11 +28,14 @@ use crate::{
     TextLabel,
     colorscheme::Themable,
     plottable::{
-        common::{get_spacing, nice_number},
+        common::{nice_number, within_axis},
         point::{Datapoint, Screenpoint},
         text::{Anchor, TextStyle},
-        ticks::{Scale, TickSet, TickSpec},
-        view::{DataBBox, ViewTransformer},
+        ticks::{
+            CategoryAxis, LabelFormatter, Notation, Scale, Tick, TickDensity, TickSet, TickSpec,
+            group_thousands,
+        },
+        view::{DataBBox, ScreenBBox, ViewTransformer, Viewport},
     },
     plotter::{ChartElement, PlotElement},
 };
@@ -52,8 +58,8 @@ impl Line {
 }
 /// Configurations for a [`Line`].
 ///
-/// Controls thickness, color, and whether an arrowhead is rendered at the
-/// destination end. When `color` is `None` it is resolved from the active
+/// Controls thickness, color, dash pattern, and whether an arrowhead is
+/// rendered at the destination end. When `color` is `None` it is resolved from the active
 /// [`Colorscheme`](crate::colorscheme::Colorscheme) at theme-application
 /// time.
 ///
@@ -84,6 +90,10 @@ pub struct LineConfig {
     pub arrow_length: f32,
     /// Half-width of the arrowhead perpendicular to the line (pixels).
     pub arrow_width: f32,
+    /// Optional dash pattern as `(dash, gap)` lengths in pixels. `None`
+    /// draws a solid line. The arrowhead is always solid.
+    #[builder(setter(into, strip_option))]
+    pub dash: Option<(f32, f32)>,
 }
 
 impl Default for LineConfig {
@@ -95,6 +105,7 @@ impl Default for LineConfig {
             arrow: Visibility::Visible,
             arrow_length: 4.0 * thickness,
             arrow_width: 3.5 * thickness,
+            dash: None,
         }
     }
 }
@@ -102,48 +113,145 @@ impl Default for LineConfig {
 impl PlotElement for Line {
     type Config = LineConfig;
     fn plot(&self, rl: &mut RaylibDrawHandle, configs: &LineConfig) {
-        match configs.arrow {
-            Visibility::Visible => {
-                rl.draw_line_ex(
-                    *self.from,
-                    *self.to,
-                    configs.thickness,
-                    configs.color.unwrap_or(Color::BLACK),
-                );
-                let direction = Vector2 {
-                    x: self.to.x - self.from.x,
-                    y: self.to.y - self.from.y,
-                };
-                let length = direction.length();
-                if length <= 0.0 {
-                    return;
-                }
-                let direction_norm = direction.normalized();
-                let vdx = -direction_norm.y;
-                let vdy = direction_norm.x;
-                let p1 = Vector2::new(
-                    self.to.x - configs.arrow_length * direction_norm.x + configs.arrow_width * vdx,
-                    self.to.y - configs.arrow_length * direction_norm.y + configs.arrow_width * vdy,
-                );
-                let p2 = Vector2::new(
-                    self.to.x - configs.arrow_length * direction_norm.x - configs.arrow_width * vdx,
-                    self.to.y - configs.arrow_length * direction_norm.y - configs.arrow_width * vdy,
-                );
-                let tail = Vector2::new(self.to.x, self.to.y);
-                rl.draw_triangle(p2, p1, tail, configs.color.unwrap_or(Color::BLACK));
-            }
-            Visibility::Invisible => {
-                rl.draw_line_ex(
-                    *self.from,
-                    *self.to,
-                    configs.thickness,
-                    configs.color.unwrap_or(Color::BLACK),
-                );
-            }
+        let color = configs.color.unwrap_or(Color::BLACK);
+        let (shaft, head) = self.pieces(configs);
+        for (start, end) in shaft {
+            rl.draw_line_ex(start, end, configs.thickness, color);
+        }
+        if let Some([right, left, tip]) = head {
+            rl.draw_triangle(right, left, tip, color);
+        }
+    }
+}
+
+impl Line {
+    /// The runs of the shaft, dashed if configured, and the corners of the
+    /// arrowhead, if any, as [`Line::plot`] draws them.
+    fn pieces(&self, configs: &LineConfig) -> (Vec<(Vector2, Vector2)>, Option<[Vector2; 3]>) {
+        let head = match configs.arrow {
+            Visibility::Visible => arrowhead(*self.from, *self.to, configs),
+            Visibility::Invisible => None,
+        };
+        // Stop the shaft at the base of the head so it can't poke through
+        // the tip.
+        let shaft_end = head.map_or(*self.to, |(base, _)| base);
+        let shaft = match configs.dash {
+            Some(dash) => dash_runs(*self.from, shaft_end, dash),
+            None => vec![(*self.from, shaft_end)],
+        };
+        (shaft, head.map(|(_, corners)| corners))
+    }
+}
+
+/// Arrowhead at the `to` end of the line from `from`: the base point where
+/// the shaft should stop, and the head's corners `[right, left, tip]` in
+/// the order raylib fills them.
+///
+/// A line shorter than `arrow_length` gets a proportionally smaller head
+/// that ends at `from`, and the head is never narrower than twice the line
+/// thickness. `None` for a zero-length line.
+fn arrowhead(from: Vector2, to: Vector2, configs: &LineConfig) -> Option<(Vector2, [Vector2; 3])> {
+    let length = from.distance_to(to);
+    if length <= 0.0 {
+        return None;
+    }
+    let direction = (to - from) / length;
+    let normal = Vector2::new(-direction.y, direction.x);
+    let fit = if configs.arrow_length > length {
+        length / configs.arrow_length
+    } else {
+        1.0
+    };
+    let base = to - direction * (configs.arrow_length * fit).max(0.0);
+    let half_width = (configs.arrow_width * fit).max(configs.thickness);
+    Some((
+        base,
+        [base - normal * half_width, base + normal * half_width, to],
+    ))
+}
+
+/// A data-space line, e.g. a fitted trend line from
+/// [`trend_line`](crate::dataset::trend_line). Endpoints are projected
+/// through the view and drawn like a screen-space [`Line`].
+impl ChartElement for Line {
+    type Config = LineConfig;
+
+    fn draw_in_view(
+        &self,
+        rl: &mut RaylibDrawHandle,
+        configs: &Self::Config,
+        view: &ViewTransformer,
+    ) {
+        let from = view.to_screen(&self.from);
+        let to = view.to_screen(&self.to);
+        Line::new(*from, *to).plot(rl, configs);
+    }
+
+    fn data_bounds(&self) -> DataBBox {
+        DataBBox::new(self.from, self.to)
+    }
+}
+
+impl Themable for LineConfig {
+    fn apply_theme(&mut self, scheme: &crate::colorscheme::Colorscheme) {
+        if self.color.is_none() {
+            self.color = Some(scheme.cycle.first().copied().unwrap_or(Color::BLACK));
         }
     }
 }
 
+/// Draw a dashed segment from `start` to `end` as `(dash, gap)` pixel runs.
+///
+/// Falls back to a solid line when the dash length is not positive.
+pub(crate) fn draw_dashed_line(
+    rl: &mut RaylibDrawHandle,
+    start: Vector2,
+    end: Vector2,
+    thickness: f32,
+    (dash, gap): (f32, f32),
+    color: Color,
+) {
+    for (from, to) in dash_runs(start, end, (dash, gap)) {
+        rl.draw_line_ex(from, to, thickness, color);
+    }
+}
+
+/// The visible runs of a `(dash, gap)` pattern from `start` to `end`. The
+/// last run is cut short at `end`; a non-positive dash gives one solid run.
+fn dash_runs(start: Vector2, end: Vector2, (dash, gap): (f32, f32)) -> Vec<(Vector2, Vector2)> {
+    let length = start.distance_to(end);
+    if dash <= 0.0 || length <= 0.0 {
+        return vec![(start, end)];
+    }
+    let direction = (end - start) / length;
+    let mut runs = Vec::new();
+    let mut travelled = 0.0;
+    while travelled < length {
+        let run_end = (travelled + dash).min(length);
+        runs.push((start + direction * travelled, start + direction * run_end));
+        travelled = run_end + gap.max(0.0);
+    }
+    runs
+}
+
+/// Color, thickness and optional dash pattern of a grid line.
+type LineStyle = (Color, f32, Option<(f32, f32)>);
+
+/// Draw a segment solid, or dashed when `dash` is set.
+fn stroke(
+    rl: &mut RaylibDrawHandle,
+    start: Vector2,
+    end: Vector2,
+    thickness: f32,
+    dash: Option<(f32, f32)>,
+    color: Color,
+) {
+    match dash {
+        Some(dash) => draw_dashed_line(rl, start, end, thickness, dash, color),
+        None => rl.draw_line_ex(start, end, thickness, color),
+    }
+}
+
 /// Definition of an Axis
 #[derive(Clone, Copy, Debug)]
 pub struct Axis {
@@ -164,6 +272,26 @@ impl Axis {
         (self.y_axis.to.y - self.y_axis.from.y).abs()
     }
 
+    /// Creates a new Axis spanning exactly `x` and `y`, without padding or
+    /// "nice number" snapping.
+    ///
+    /// Because [`Graph`](crate::graph::Graph) prefers the axis bounds over
+    /// the subject's own, this pins the visible range; data outside the
+    /// limits is clipped by the plot area.
+    ///
+    /// ```rust
+    /// use locus::prelude::*;
+    /// let axis = Axis::with_limits(0.0..100.0, 0.0..1.0);
+    /// let bounds = axis.data_bounds();
+    /// assert_eq!(bounds.maximum.x, 100.0);
+    /// ```
+    #[must_use]
+    pub fn with_limits(x: Range<f32>, y: Range<f32>) -> Self {
+        let x = x.start.min(x.end)..x.start.max(x.end);
+        let y = y.start.min(y.end)..y.start.max(y.end);
+        Self::from((x, y))
+    }
+
     /// Creates a new Axis that fits the given data ranges, applying "nice number" algorithms
     /// to determine the determine the range.
     #[must_use]
@@ -198,6 +326,50 @@ impl Axis {
             y_axis: Line::new(Datapoint::new(min_x, min_y), Datapoint::new(min_x, max_y)),
         }
     }
+
+    /// Like [`Axis::fitting_config`], but with equal data spans on both
+    /// axes: after snapping each range, the narrower one is widened about
+    /// its centre to match the wider one.
+    ///
+    /// Drawn in a square plotting area this gives one data unit the same
+    /// length on both axes, so circles stay round without letterboxing the
+    /// viewport.
+    ///
+    /// ```rust
+    /// use locus::prelude::*;
+    /// let axis = Axis::fitting_square(0.0..10.0, 4.0..6.0, 0.01, 10);
+    /// let bounds = axis.data_bounds();
+    /// let close = |a: f32, b: f32| (a - b).abs() < 1e-4;
+    /// // x snaps to -1..11; y snaps to 3.8..6.2 and widens about 5 to match.
+    /// assert!(close(bounds.minimum.x, -1.0) && close(bounds.maximum.x, 11.0));
+    /// assert!(close(bounds.minimum.y, -1.0) && close(bounds.maximum.y, 11.0));
+    /// ```
+    #[must_use]
+    pub fn fitting_square(
+        x_range: Range<f32>,
+        y_range: Range<f32>,
+        padding_pct: f32,
+        ticks: usize,
+    ) -> Self {
+        let fitted = Self::fitting_config(x_range, y_range, padding_pct, ticks);
+        let (mut min_x, mut max_x) = (fitted.x_axis.from.x, fitted.x_axis.to.x);
+        let (mut min_y, mut max_y) = (fitted.y_axis.from.y, fitted.y_axis.to.y);
+        let span = (max_x - min_x).max(max_y - min_y);
+        let widen = |min: &mut f32, max: &mut f32| {
+            if *max - *min >= span {
+                return;
+            }
+            let center = (*min + *max) * 0.5;
+            *min = center - span * 0.5;
+            *max = center + span * 0.5;
+        };
+        widen(&mut min_x, &mut max_x);
+        widen(&mut min_y, &mut max_y);
+        Self {
+            x_axis: Line::new(Datapoint::new(min_x, min_y), Datapoint::new(max_x, min_y)),
+            y_axis: Line::new(Datapoint::new(min_x, min_y), Datapoint::new(min_x, max_y)),
+        }
+    }
 }
 
 /// Generates a "nice range" that fits `min` and `max`. This means that will snap, generally,
@@ -250,6 +422,46 @@ pub enum Visibility {
     Invisible,
 }
 
+/// Where the axis lines (and their tick marks) are drawn.
+#[derive(Debug, Clone, Copy, Default)]
+pub enum AxisPlacement {
+    /// Along the bottom/left edges of the data range (the default).
+    #[default]
+    Edge,
+    /// Through the data origin `(0, 0)`, Matplotlib "spines at zero" style.
+    /// Each axis falls back to its edge when zero is outside the range.
+    Origin,
+}
+
+/// Side of the plotting area an axis' ticks and labels are drawn on.
+///
+/// The x ticks use [`Side::Bottom`] or [`Side::Top`], the y ticks
+/// [`Side::Left`] or [`Side::Right`]; a side that does not apply to an axis
+/// (e.g. `Top` for the y ticks) falls back to that axis' default.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Side {
+    /// Left of the plot (the y default).
+    #[default]
+    Left,
+    /// Right of the plot.
+    Right,
+    /// Below the plot (the x default).
+    Bottom,
+    /// Above the plot.
+    Top,
+}
+
+impl AxisPlacement {
+    /// Data coordinate where a perpendicular axis crosses the range
+    /// `[min, max]`, or `None` when it should stay at the edge.
+    pub(crate) fn crossing(self, min: f32, max: f32) -> Option<f32> {
+        match self {
+            AxisPlacement::Origin if (min..=max).contains(&0.0) => Some(0.0),
+            _ => None,
+        }
+    }
+}
+
 /// Configuration for the pair of axis lines.
 ///
 /// Individual axes and their arrowheads can be toggled via the builder
@@ -285,6 +497,8 @@ pub struct AxisConfigs {
     pub color: Option<Color>,
     /// Line thickness in pixels.
     pub thickness: f32,
+    /// Whether the axes run along the edges or through the origin.
+    pub placement: AxisPlacement,
 }
 
 impl AxisConfigsBuilder {
@@ -357,6 +571,7 @@ impl Default for AxisConfigs {
             color: None,
             thickness,
             arrow_width: 4.0 * thickness,
+            placement: AxisPlacement::Edge,
         }
     }
 }
@@ -370,11 +585,26 @@ impl ChartElement for Axis {
         configs: &Self::Config,
         view: &ViewTransformer,
     ) {
+        let bounds = self.data_bounds();
+        let x_axis = match configs
+            .placement
+            .crossing(bounds.minimum.y, bounds.maximum.y)
+        {
+            Some(y) => Line::new((self.x_axis.from.x, y), (self.x_axis.to.x, y)),
+            None => self.x_axis,
+        };
+        let y_axis = match configs
+            .placement
+            .crossing(bounds.minimum.x, bounds.maximum.x)
+        {
+            Some(x) => Line::new((x, self.y_axis.from.y), (x, self.y_axis.to.y)),
+            None => self.y_axis,
+        };
         let (x_line, y_line) = {
-            let x_start = view.to_screen(&self.x_axis.from);
-            let x_end = view.to_screen(&self.x_axis.to);
-            let y_start = view.to_screen(&self.y_axis.from);
-            let y_end = view.to_screen(&self.y_axis.to);
+            let x_start = view.to_screen(&x_axis.from);
+            let x_end = view.to_screen(&x_axis.to);
+            let y_start = view.to_screen(&y_axis.from);
+            let y_end = view.to_screen(&y_axis.to);
             (Line::new(*x_start, *x_end), Line::new(*y_start, *y_end))
         };
 
@@ -384,6 +614,7 @@ impl ChartElement for Axis {
             arrow: configs.x_arrow,
             arrow_length: configs.arrow_length,
             arrow_width: configs.arrow_width,
+            dash: None,
         };
 
         let line_config_y = LineConfig {
@@ -392,6 +623,7 @@ impl ChartElement for Axis {
             arrow: configs.y_arrow,
             arrow_length: configs.arrow_length,
             arrow_width: configs.arrow_width,
+            dash: None,
         };
         match configs.x_axis {
             Visibility::Visible => {
@@ -421,6 +653,33 @@ impl ChartElement for Axis {
     }
 }
 
+impl Axis {
+    /// Draws only the y-axis line, on the right edge of `view`'s data
+    /// bounds. Used for secondary axes.
+    pub(crate) fn draw_right_in_view(
+        &self,
+        rl: &mut RaylibDrawHandle,
+        configs: &AxisConfigs,
+        view: &ViewTransformer,
+    ) {
+        if let Visibility::Invisible = configs.y_axis {
+            return;
+        }
+        let x = view.data_bounds.maximum.x;
+        let start = view.to_screen(&(x, self.y_axis.from.y).into());
+        let end = view.to_screen(&(x, self.y_axis.to.y).into());
+        let line_config = LineConfig {
+            thickness: configs.thickness,
+            color: configs.color,
+            arrow: configs.y_arrow,
+            arrow_length: configs.arrow_length,
+            arrow_width: configs.arrow_width,
+            dash: None,
+        };
+        Line::new(*start, *end).plot(rl, &line_config);
+    }
+}
+
 impl Themable for AxisConfigs {
     fn apply_theme(&mut self, scheme: &crate::colorscheme::Colorscheme) {
         match &self.color {
@@ -472,6 +731,16 @@ pub enum Separation {
     Value(f32),
 }
 
+/// The ticks of both axes, generated once so that every element drawn at
+/// tick positions (marks, labels, grid lines) agrees on them.
+#[derive(Debug, Clone)]
+pub struct AxisTicks {
+    /// Ticks along the x axis.
+    pub x: TickSet,
+    /// Ticks along the y axis.
+    pub y: TickSet,
+}
+
 /// Grid lines drawn behind the data to aid visual reading.
 ///
 /// Constructed from an [`Axis`] (which defines the data range) and an
@@ -508,8 +777,25 @@ pub struct GridLinesConfig {
     /// Line thickness in pixels.
     pub thickness: f32,
     /// Maximum number of grid lines per axis (used by the auto-spacing
-    /// algorithm).
+    /// algorithm). Ignored inside a [`Graph`](crate::graph::Graph) with
+    /// tick labels, where the grid follows their ticks.
     pub max_ticks: usize,
+    /// Scale of the x axis; vertical lines follow its ticks when the grid
+    /// is drawn on its own.
+    pub x_scale: Scale,
+    /// Scale of the y axis; horizontal lines follow its ticks when the grid
+    /// is drawn on its own.
+    pub y_scale: Scale,
+    /// Optional dash pattern as `(dash, gap)` lengths in pixels. `None`
+    /// draws solid lines.
+    #[builder(setter(into, strip_option))]
+    pub dash: Option<(f32, f32)>,
+    /// Optional emphasized line as `(value, color, thickness)`, drawn solid
+    /// on top of the regular grid wherever `value` is in range on a gridded
+    /// axis. Typically `(0.0, color, 2.0)` to highlight the zero line. Like
+    /// the rest of the grid it is clipped to the plotting area.
+    #[builder(setter(into, strip_option))]
+    pub emphasize: Option<(f32, Color, f32)>,
 }
 
 impl Default for GridLinesConfig {
@@ -519,6 +805,10 @@ impl Default for GridLinesConfig {
             alpha: 0.3,
             thickness: 1.0,
             max_ticks: 10,
+            x_scale: Scale::Linear,
+            y_scale: Scale::Linear,
+            dash: None,
+            emphasize: None,
         }
     }
 }
@@ -529,7 +819,7 @@ impl GridLines {
         &self,
         rl: &mut RaylibDrawHandle,
         data_x: f32,
-        config: &GridLinesConfig,
+        (color, thickness, dash): LineStyle,
         view: &ViewTransformer,
     ) {
         // The line goes from bottom of Y-axis to top of Y-axis (in Data units)
@@ -540,15 +830,14 @@ impl GridLines {
         let start = view.to_screen(&Datapoint::new(data_x, data_y_start));
         let end = view.to_screen(&Datapoint::new(data_x, data_y_end));
 
-        let color = config.color.unwrap_or(Color::BLACK).alpha(config.alpha);
-        rl.draw_line_ex(*start, *end, config.thickness, color);
+        stroke(rl, *start, *end, thickness, dash, color);
     }
 
     fn draw_h_line(
         &self,
         rl: &mut RaylibDrawHandle,
         data_y: f32,
-        config: &GridLinesConfig,
+        (color, thickness, dash): LineStyle,
         view: &ViewTransformer,
     ) {
         let data_x_start = self.axis.x_axis.from.x;
@@ -557,29 +846,119 @@ impl GridLines {
         let start = view.to_screen(&Datapoint::new(data_x_start, data_y));
         let end = view.to_screen(&Datapoint::new(data_x_end, data_y));
 
+        stroke(rl, *start, *end, thickness, dash, color);
+    }
+
+    /// Color, thickness and dash of a regular grid line.
+    fn regular_style(config: &GridLinesConfig) -> LineStyle {
         let color = config.color.unwrap_or(Color::BLACK).alpha(config.alpha);
-        rl.draw_line_ex(*start, *end, config.thickness, color);
+        (color, config.thickness, config.dash)
+    }
+
+    /// The emphasized value and its solid style, if configured.
+    fn emphasis(config: &GridLinesConfig) -> Option<(f32, LineStyle)> {
+        config
+            .emphasize
+            .map(|(value, color, thickness)| (value, (color, thickness, None)))
+    }
+
+    /// Positions of the grid lines between `from` and `to`: every tick of
+    /// `ticks` in range, with the same tolerance [`TickLabels`] uses, so
+    /// each line passes through a tick mark.
+    fn positions(ticks: &TickSet, from: f32, to: f32) -> Vec<f32> {
+        let (min, max) = (from.min(to), from.max(to));
+        ticks
+            .ticks
+            .iter()
+            .map(|tick| tick.value)
+            .filter(|value| within_axis(*value, min, max))
+            .collect()
+    }
+
+    /// Separations of the x and y lines given by the orientation. The
+    /// separation of an axis without lines is irrelevant and left `Auto`.
+    fn separations(&self) -> (Separation, Separation) {
+        match self.orientation {
+            Orientation::Vertical { separation } => (separation, Separation::Auto),
+            Orientation::Horizontal { separation } => (Separation::Auto, separation),
+            Orientation::Both {
+                separation_x,
+                separation_y,
+            } => (separation_x, separation_y),
+        }
+    }
+
+    /// The ticks the grid follows when drawn on its own, from the scales
+    /// and `max_ticks` of `configs` and the separations of the orientation.
+    ///
+    /// Inside a [`Graph`](crate::graph::Graph) the grid instead follows the
+    /// ticks of its [`TickLabels`]; see [`GridLines::draw_with_ticks`].
+    #[must_use]
+    pub fn tick_sets(&self, configs: &GridLinesConfig) -> AxisTicks {
+        let bounds = self.axis.data_bounds();
+        let (separation_x, separation_y) = self.separations();
+        let spec = |scale, separation| TickSpec {
+            scale,
+            max_ticks: configs.max_ticks,
+            separation,
+        };
+        AxisTicks {
+            x: TickSet::generate_ticks(
+                bounds.minimum.x,
+                bounds.maximum.x,
+                spec(configs.x_scale, separation_x),
+            ),
+            y: TickSet::generate_ticks(
+                bounds.minimum.y,
+                bounds.maximum.y,
+                spec(configs.y_scale, separation_y),
+            ),
+        }
+    }
+
+    /// Draw the grid through `ticks` instead of generating its own, so the
+    /// lines coincide with the tick marks drawn from the same set.
+    ///
+    /// The orientation still picks which lines are drawn, but its
+    /// separations, like the scales and `max_ticks` of `configs`, are
+    /// ignored: the ticks already fix the positions.
+    pub fn draw_with_ticks(
+        &self,
+        rl: &mut RaylibDrawHandle,
+        configs: &GridLinesConfig,
+        view: &ViewTransformer,
+        ticks: &AxisTicks,
+    ) {
+        match self.orientation {
+            Orientation::Vertical { .. } => self.plot_vertical(rl, configs, &ticks.x, view),
+            Orientation::Horizontal { .. } => self.plot_horizontal(rl, configs, &ticks.y, view),
+            Orientation::Both { .. } => {
+                self.plot_vertical(rl, configs, &ticks.x, view);
+                self.plot_horizontal(rl, configs, &ticks.y, view);
+            }
+        }
     }
 
     fn plot_vertical(
         &self,
         rl: &mut RaylibDrawHandle,
         config: &GridLinesConfig,
-        sep: Separation,
+        ticks: &TickSet,
         view: &ViewTransformer,
     ) {
-        let spacing = get_spacing(self.axis.length_x_axis(), sep, config.max_ticks);
-        let (max, min) = (
-            self.axis.x_axis.from.x.max(self.axis.x_axis.to.x),
-            self.axis.x_axis.from.x.min(self.axis.x_axis.to.x),
+        let x_axis = &self.axis.x_axis;
+        let style = Self::regular_style(config);
+        for pos in Self::positions(ticks, x_axis.from.x, x_axis.to.x) {
+            self.draw_v_line(rl, pos, style, view);
+        }
+        let (min, max) = (
+            x_axis.from.x.min(x_axis.to.x),
+            x_axis.from.x.max(x_axis.to.x),
         );
-
-        // Find the first "nice" multiple of spacing after or at start
-        let mut pos = (min / spacing).ceil() * spacing;
-
-        while pos <= max {
-            self.draw_v_line(rl, pos, config, view);
-            pos += spacing;
+        if let Some((value, style)) =
+            Self::emphasis(config).filter(|(value, _)| within_axis(*value, min, max))
+        {
+            self.draw_v_line(rl, value, style, view);
         }
     }
 
@@ -587,21 +966,22 @@ impl GridLines {
         &self,
         rl: &mut RaylibDrawHandle,
         config: &GridLinesConfig,
-        sep: Separation,
+        ticks: &TickSet,
         view: &ViewTransformer,
     ) {
-        let spacing = get_spacing(self.axis.length_y_axis(), sep, config.max_ticks);
-        let (max, min) = (
-            self.axis.y_axis.from.y.max(self.axis.y_axis.to.y),
-            self.axis.y_axis.from.y.min(self.axis.y_axis.to.y),
+        let y_axis = &self.axis.y_axis;
+        let style = Self::regular_style(config);
+        for pos in Self::positions(ticks, y_axis.from.y, y_axis.to.y) {
+            self.draw_h_line(rl, pos, style, view);
+        }
+        let (min, max) = (
+            y_axis.from.y.min(y_axis.to.y),
+            y_axis.from.y.max(y_axis.to.y),
         );
-
-        // Note: Check if your Y-axis grows up or down.
-        // This assumes 'from' is the smaller value.
-        let mut pos = (min / spacing).ceil() * spacing;
-        while pos <= max {
-            self.draw_h_line(rl, pos, config, view);
-            pos += spacing;
+        if let Some((value, style)) =
+            Self::emphasis(config).filter(|(value, _)| within_axis(*value, min, max))
+        {
+            self.draw_h_line(rl, value, style, view);
         }
     }
 }
@@ -615,21 +995,7 @@ impl ChartElement for GridLines {
         configs: &GridLinesConfig,
         view: &ViewTransformer,
     ) {
-        match &self.orientation {
-            Orientation::Vertical { separation } => {
-                self.plot_vertical(rl, configs, *separation, view);
-            }
-            Orientation::Horizontal { separation } => {
-                self.plot_horizontal(rl, configs, *separation, view);
-            }
-            Orientation::Both {
-                separation_x,
-                separation_y,
-            } => {
-                self.plot_vertical(rl, configs, *separation_x, view);
-                self.plot_horizontal(rl, configs, *separation_y, view);
-            }
-        }
+        self.draw_with_ticks(rl, configs, view, &self.tick_sets(configs));
     }
 
     fn data_bounds(&self) -> DataBBox {
@@ -646,6 +1012,107 @@ impl Themable for GridLinesConfig {
     }
 }
 
+/// A screen-space grid with a line every
+/// [`spacing_px`](PixelGridConfig::spacing_px) pixels.
+///
+/// Unlike [`GridLines`] it ignores the data entirely: lines start at the
+/// top-left corner of `area` and repeat at a fixed pixel spacing, which makes
+/// it handy for aligning [`PlotElement`]s and for debugging layouts.
+///
+/// ```rust,no_run
+/// use locus::prelude::*;
+/// # let (mut rl, thread) = raylib::init().build();
+/// let grid = PixelGrid::new(Viewport::new(0.0, 0.0, 800.0, 600.0));
+/// let config = PixelGridConfigBuilder::default().spacing_px(25.0).build().unwrap();
+/// let mut d = rl.begin_drawing(&thread);
+/// grid.plot(&mut d, &config);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct PixelGrid {
+    /// Screen area covered by the grid.
+    pub area: ScreenBBox,
+}
+
+impl PixelGrid {
+    /// Create a pixel grid covering the whole of `viewport`, margins
+    /// included.
+    #[must_use]
+    pub fn new(viewport: Viewport) -> Self {
+        Self {
+            area: viewport.outer_bbox(),
+        }
+    }
+
+    /// Line offsets from `from` to `to`, every `spacing` pixels. A spacing
+    /// below one pixel is raised to one.
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )]
+    fn offsets(from: f32, to: f32, spacing: f32) -> Vec<f32> {
+        let spacing = spacing.max(1.0);
+        let count = ((to - from) / spacing).floor().max(0.0) as usize;
+        (0..=count).map(|k| from + k as f32 * spacing).collect()
+    }
+}
+
+/// Configuration for [`PixelGrid`] rendering.
+///
+/// When `color` is `None` it is resolved from
+/// [`Colorscheme::grid`](crate::colorscheme::Colorscheme::grid) during
+/// theme application.
+#[derive(Debug, Clone, Copy, Builder)]
+#[builder(pattern = "owned")]
+#[builder(default)]
+pub struct PixelGridConfig {
+    /// Distance between neighbouring lines in pixels.
+    pub spacing_px: f32,
+    /// Explicit line color. `None` means "use theme grid color".
+    #[builder(setter(strip_option, into))]
+    pub color: Option<Color>,
+    /// Alpha multiplier applied on top of the color's own alpha.
+    pub alpha: f32,
+    /// Line thickness in pixels.
+    pub thickness: f32,
+}
+
+impl Default for PixelGridConfig {
+    fn default() -> Self {
+        Self {
+            spacing_px: 50.0,
+            color: None,
+            alpha: 0.5,
+            thickness: 1.0,
+        }
+    }
+}
+
+impl PlotElement for PixelGrid {
+    type Config = PixelGridConfig;
+
+    fn plot(&self, rl: &mut RaylibDrawHandle, configs: &Self::Config) {
+        let color = configs.color.unwrap_or(Color::BLACK).alpha(configs.alpha);
+        let (min, max) = (self.area.minimum, self.area.maximum);
+        for x in Self::offsets(min.x, max.x, configs.spacing_px) {
+            let (start, end) = (Vector2::new(x, min.y), Vector2::new(x, max.y));
+            rl.draw_line_ex(start, end, configs.thickness, color);
+        }
+        for y in Self::offsets(min.y, max.y, configs.spacing_px) {
+            let (start, end) = (Vector2::new(min.x, y), Vector2::new(max.x, y));
+            rl.draw_line_ex(start, end, configs.thickness, color);
+        }
+    }
+}
+
+impl Themable for PixelGridConfig {
+    fn apply_theme(&mut self, scheme: &crate::colorscheme::Colorscheme) {
+        if self.color.is_none() {
+            self.color = Some(scheme.grid);
+        }
+    }
+}
+
 /// Small marks along each axis with formatted numeric labels.
 ///
 /// `TickLabels` combines tick mark rendering with optional text labels
@@ -689,10 +1156,25 @@ pub struct TickLabelsConfig {
     pub major_size: f32,
     /// Length of minor tick marks in pixels (log/symlog scales).
     pub minor_size: f32,
-    /// Maximum number of ticks per axis.
-    pub max_ticks: usize,
+    /// How many ticks each axis aims for: a fixed maximum, or one per so
+    /// many pixels of axis length. Set a fixed count with
+    /// [`max_ticks`](TickLabelsBuilder::max_ticks).
+    pub density: TickDensity,
     /// Spacing strategy for tick placement.
     pub separation: Separation,
+    /// Exact x tick positions, e.g. `[1, 2, 5, 10, 50]`. When set, they
+    /// replace the generated ticks (see [`TickSet::explicit`]), so
+    /// `density`, `separation`, and minor ticks no longer apply to x.
+    #[builder(setter(into, strip_option))]
+    pub x_positions: Option<Vec<f32>>,
+    /// Exact y tick positions; like `x_positions`, for the y axis.
+    #[builder(setter(into, strip_option))]
+    pub y_positions: Option<Vec<f32>>,
+    /// Named categories on the x axis, one tick and name per integer slot.
+    /// Takes precedence over `x_positions`, and the names bypass the
+    /// notation, thousands separator, and label formatter.
+    #[builder(setter(into, strip_option))]
+    pub x_categories: Option<CategoryAxis>,
     /// Visibility of x-axis ticks.
     #[builder(private)]
     pub x_axis: Visibility,
@@ -714,9 +1196,45 @@ pub struct TickLabelsConfig {
     pub label_offset: f32,
     /// Rotation in degrees for x-axis tick labels (useful for long labels).
     pub label_rotation: f32,
+    /// Rotation in degrees for y-axis tick labels. Labels pivot on the end
+    /// nearest the axis and are pushed out far enough to clear the marks.
+    pub y_label_rotation: f32,
+    /// Where tick marks are drawn. Inside a [`Graph`](crate::graph::Graph)
+    /// with an axis this is taken from [`AxisConfigs::placement`], so set it
+    /// there; it only needs setting here for tick labels drawn on their own.
+    pub placement: AxisPlacement,
+    /// Number notation for tick labels (plain, scientific, or SI-suffixed
+    /// engineering). Ignored when a `label_formatter` is set.
+    pub notation: Notation,
+    /// Custom label formatter. When `Some`, it replaces the built-in numeric
+    /// formatting of major tick labels.
+    #[builder(setter(custom))]
+    pub label_formatter: Option<LabelFormatter>,
+    /// Separator inserted every three digits in the integer part of
+    /// built-in labels, e.g. `Some(',')` for `2,000,000`. `None` leaves
+    /// digits ungrouped. Ignored when a `label_formatter` is set.
+    #[builder(setter(strip_option))]
+    pub thousands_separator: Option<char>,
+    /// Side the x ticks are drawn on: [`Side::Bottom`] (the default), or
+    /// [`Side::Top`] to draw them along the top edge with labels above.
+    pub x_side: Side,
+    /// Side the y ticks are drawn on: [`Side::Left`] (the default), or
+    /// [`Side::Right`] to draw them along the right edge with labels to the
+    /// right.
+    pub y_side: Side,
 }
 
 impl TickLabelsBuilder {
+    /// Aim for at most `max_ticks` ticks per axis, whatever its length;
+    /// shorthand for `density(TickDensity::Fixed(max_ticks))`.
+    #[must_use]
+    pub fn max_ticks(self, max_ticks: usize) -> Self {
+        Self {
+            density: Some(TickDensity::Fixed(max_ticks)),
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn with_x_scale(self, scale: Scale) -> Self {
         Self {
@@ -760,6 +1278,67 @@ impl TickLabelsBuilder {
             ..self
         }
     }
+
+    /// Format major tick labels with `f`, which receives the tick value.
+    #[must_use]
+    pub fn label_formatter(self, f: impl Fn(f32) -> String + 'static) -> Self {
+        Self {
+            label_formatter: Some(Some(LabelFormatter::new(f))),
+            ..self
+        }
+    }
+}
+
+impl TickLabelsConfig {
+    /// Text drawn next to `tick`, honouring the custom formatter and notation.
+    fn tick_label(&self, tick: &Tick) -> String {
+        if let Some(formatter) = &self.label_formatter {
+            return formatter.format(tick.value);
+        }
+        let label = match self.notation {
+            Notation::Plain => tick.label.clone(),
+            notation => notation.format(tick.value),
+        };
+        match self.thousands_separator {
+            Some(separator) => group_thousands(&label, separator),
+            None => label,
+        }
+    }
+
+    /// Text actually drawn next to `tick`: its label for a major tick, and
+    /// nothing for minor ticks or when labels are hidden.
+    fn drawn_label(&self, tick: &Tick) -> String {
+        if self.show_labels && tick.major {
+            self.tick_label(tick)
+        } else {
+            String::new()
+        }
+    }
+
+    /// [`drawn_label`](Self::drawn_label) for an x tick, which keeps the
+    /// category name of a categorical axis as is.
+    fn drawn_x_label(&self, tick: &Tick) -> String {
+        if self.x_categories.is_some() && self.show_labels && tick.major {
+            tick.label.clone()
+        } else {
+            self.drawn_label(tick)
+        }
+    }
+
+    /// Ticks fixed by the categories or explicit positions of the x axis,
+    /// if any.
+    fn fixed_x_ticks(&self) -> Option<TickSet> {
+        match (&self.x_categories, &self.x_positions) {
+            (Some(categories), _) => Some(categories.tick_set()),
+            (None, Some(positions)) => Some(TickSet::explicit(positions)),
+            (None, None) => None,
+        }
+    }
+
+    /// Ticks fixed by the explicit positions of the y axis, if any.
+    fn fixed_y_ticks(&self) -> Option<TickSet> {
+        self.y_positions.as_deref().map(TickSet::explicit)
+    }
 }
 
 impl Default for TickLabelsConfig {
@@ -769,8 +1348,11 @@ impl Default for TickLabelsConfig {
             alpha: 1.0,
             major_size: 7.0,
             minor_size: 5.0,
-            max_ticks: 10,
+            density: TickDensity::default(),
             separation: Separation::Auto,
+            x_positions: None,
+            y_positions: None,
+            x_categories: None,
             x_axis: Visibility::Visible,
             y_axis: Visibility::Visible,
             x_axis_scale: Scale::Linear,
@@ -785,13 +1367,38 @@ impl Default for TickLabelsConfig {
                 anchor: Anchor::TOP_CENTER,
                 rotation: 0.0,
                 offset: Vector2::new(0.0, 0.0),
+                line_spacing: 1.2,
+                background: None,
+                background_padding: 4.0,
+                corner_radius: 0.0,
+                fit: false,
+                min_font_size: 10.0,
             },
             label_offset: 4.0,
             label_rotation: 0.0,
+            y_label_rotation: 0.0,
+            placement: AxisPlacement::Edge,
+            notation: Notation::Plain,
+            label_formatter: None,
+            thousands_separator: None,
+            x_side: Side::Bottom,
+            y_side: Side::Left,
         }
     }
 }
 
+impl TickLabelsConfig {
+    /// Whether the x ticks go along the top edge.
+    pub(crate) fn x_on_top(&self) -> bool {
+        self.x_side == Side::Top
+    }
+
+    /// Whether the y ticks go along the right edge.
+    pub(crate) fn y_on_right(&self) -> bool {
+        self.y_side == Side::Right
+    }
+}
+
 impl ChartElement for TickLabels {
     type Config = TickLabelsConfig;
 
@@ -800,24 +1407,167 @@ impl ChartElement for TickLabels {
         rl: &mut RaylibDrawHandle,
         configs: &Self::Config,
         view: &ViewTransformer,
+    ) {
+        self.draw_with_ticks(rl, configs, view, &self.tick_sets(configs, view));
+    }
+
+    fn data_bounds(&self) -> DataBBox {
+        self.axis.data_bounds()
+    }
+}
+
+/// Width and height in pixels of the plotting area of `view`.
+fn plot_size(view: &ViewTransformer) -> Vector2 {
+    let inner = view.screen_bounds.inner_bbox();
+    Vector2::new(inner.width(), inner.height())
+}
+
+impl TickLabels {
+    /// The ticks of both axes as drawn for `configs` in `view`, whose
+    /// plotting area sets the tick count of a
+    /// [`TickDensity::PerPixels`] density.
+    ///
+    /// Generate them once per frame and hand the same set to
+    /// [`TickLabels::draw_with_ticks`] and [`GridLines::draw_with_ticks`]
+    /// so every grid line passes through a tick mark.
+    #[must_use]
+    pub fn tick_sets(&self, configs: &TickLabelsConfig, view: &ViewTransformer) -> AxisTicks {
+        self.sized_tick_sets(configs, plot_size(view))
+    }
+
+    /// The ticks exactly as drawn for `configs` in `view`: only those lying
+    /// on the axes, each carrying the text written next to it (empty for
+    /// minor ticks and when labels are hidden).
+    ///
+    /// No window is needed, so custom overlays, exporters, and tests can
+    /// query the marks a [`Graph`](crate::graph::Graph) would draw.
+    ///
+    /// ```rust
+    /// use locus::prelude::*;
+    /// let axis = Axis::with_limits(0.0..10.0, 0.0..1.0);
+    /// let configs = TickLabelsBuilder::default()
+    ///     .separation(Separation::Value(2.5))
+    ///     .build()
+    ///     .unwrap();
+    /// let view = ViewTransformer::new(axis.data_bounds(), Viewport::new(0.0, 0.0, 400.0, 300.0));
+    /// let ticks = TickLabels::new(axis).labelled_ticks(&configs, &view);
+    /// let labels: Vec<_> = ticks.x.ticks.iter().map(|t| t.label.as_str()).collect();
+    /// assert_eq!(labels, ["0", "2.5", "5", "7.5", "10"]);
+    /// ```
+    #[must_use]
+    pub fn labelled_ticks(&self, configs: &TickLabelsConfig, view: &ViewTransformer) -> AxisTicks {
+        let bounds = self.data_bounds();
+        let AxisTicks { x, y } = self.tick_sets(configs, view);
+        let drawn = |set: TickSet,
+                     (min, max): (f32, f32),
+                     label: fn(&TickLabelsConfig, &Tick) -> String| TickSet {
+            step: set.step,
+            ticks: set
+                .ticks
+                .into_iter()
+                .filter(|tick| within_axis(tick.value, min, max))
+                .map(|tick| Tick {
+                    label: label(configs, &tick),
+                    ..tick
+                })
+                .collect(),
+        };
+        AxisTicks {
+            x: drawn(
+                x,
+                (bounds.minimum.x, bounds.maximum.x),
+                TickLabelsConfig::drawn_x_label,
+            ),
+            y: drawn(
+                y,
+                (bounds.minimum.y, bounds.maximum.y),
+                TickLabelsConfig::drawn_label,
+            ),
+        }
+    }
+
+    /// [`TickLabels::tick_sets`] for a plotting area of `size` pixels.
+    fn sized_tick_sets(&self, configs: &TickLabelsConfig, size: Vector2) -> AxisTicks {
+        let bounds = self.data_bounds();
+        AxisTicks {
+            x: Self::tick_set(
+                configs,
+                configs.fixed_x_ticks(),
+                bounds.minimum.x,
+                bounds.maximum.x,
+                configs.x_axis_scale,
+                size.x,
+            ),
+            y: Self::tick_set(
+                configs,
+                configs.fixed_y_ticks(),
+                bounds.minimum.y,
+                bounds.maximum.y,
+                configs.y_axis_scale,
+                size.y,
+            ),
+        }
+    }
+
+    /// Ticks of one axis spanning `min..max` on `scale`, drawn
+    /// `length_px` pixels long, or the `fixed` ticks when given.
+    fn tick_set(
+        configs: &TickLabelsConfig,
+        fixed: Option<TickSet>,
+        min: f32,
+        max: f32,
+        scale: Scale,
+        length_px: f32,
+    ) -> TickSet {
+        if let Some(fixed) = fixed {
+            return fixed;
+        }
+        TickSet::generate_ticks(
+            min,
+            max,
+            TickSpec {
+                scale,
+                max_ticks: configs.density.max_ticks(length_px),
+                separation: configs.separation,
+            },
+        )
+    }
+
+    /// Draw the tick marks and labels at `ticks`, typically obtained from
+    /// [`TickLabels::tick_sets`].
+    pub fn draw_with_ticks(
+        &self,
+        rl: &mut RaylibDrawHandle,
+        configs: &TickLabelsConfig,
+        view: &ViewTransformer,
+        ticks: &AxisTicks,
     ) {
         let data_bounds = self.data_bounds();
+        let (x_edge, x_outward, x_anchor) = if configs.x_on_top() {
+            (data_bounds.maximum.y, -1.0, Anchor::BOTTOM_CENTER)
+        } else {
+            (data_bounds.minimum.y, 1.0, Anchor::TOP_CENTER)
+        };
+        let (y_edge, y_outward) = if configs.y_on_right() {
+            (data_bounds.maximum.x, 1.0)
+        } else {
+            (data_bounds.minimum.x, -1.0)
+        };
+        let x_axis_y = configs
+            .placement
+            .crossing(data_bounds.minimum.y, data_bounds.maximum.y)
+            .unwrap_or(x_edge);
+        let y_axis_x = configs
+            .placement
+            .crossing(data_bounds.minimum.x, data_bounds.maximum.x)
+            .unwrap_or(y_edge);
         match configs.x_axis {
             Visibility::Visible => {
-                let tickset = TickSet::generate_ticks(
-                    data_bounds.minimum.x,
-                    data_bounds.maximum.x,
-                    TickSpec {
-                        scale: configs.x_axis_scale,
-                        max_ticks: configs.max_ticks,
-                        separation: configs.separation,
-                    },
-                );
-                for tick in &tickset.ticks {
-                    if !(data_bounds.minimum.x..data_bounds.maximum.x).contains(&tick.value) {
+                for tick in &ticks.x.ticks {
+                    if !within_axis(tick.value, data_bounds.minimum.x, data_bounds.maximum.x) {
                         continue;
                     }
-                    let screen_point = view.to_screen(&(tick.value, data_bounds.minimum.y).into());
+                    let screen_point = view.to_screen(&(tick.value, x_axis_y).into());
                     let mark_len = if tick.major {
                         configs.major_size
                     } else {
@@ -825,20 +1575,21 @@ impl ChartElement for TickLabels {
                     };
                     rl.draw_line_v(
                         Vector2::new(screen_point.x, screen_point.y),
-                        Vector2::new(screen_point.x, screen_point.y + mark_len),
+                        Vector2::new(screen_point.x, screen_point.y + x_outward * mark_len),
                         configs.color.unwrap_or(Color::BLACK),
                     );
 
                     // Draw tick label text (major ticks only, unless label is non-empty)
-                    if configs.show_labels && tick.major && !tick.label.is_empty() {
+                    let label = configs.drawn_x_label(tick);
+                    if !label.is_empty() {
                         let mut style = configs.label_style.clone();
-                        style.anchor = Anchor::TOP_CENTER;
+                        style.anchor = x_anchor;
                         style.rotation = configs.label_rotation;
                         let origin = Screenpoint::new(
                             screen_point.x,
-                            screen_point.y + mark_len + configs.label_offset,
+                            screen_point.y + x_outward * (mark_len + configs.label_offset),
                         );
-                        let text = TextLabel::new(&tick.label, origin);
+                        let text = TextLabel::new(label, origin);
                         text.plot(rl, &style);
                     }
                 }
@@ -848,53 +1599,185 @@ impl ChartElement for TickLabels {
 
         match configs.y_axis {
             Visibility::Visible => {
-                let tickset = TickSet::generate_ticks(
-                    data_bounds.minimum.y,
-                    data_bounds.maximum.y,
-                    TickSpec {
-                        scale: configs.y_axis_scale,
-                        max_ticks: configs.max_ticks,
-                        separation: configs.separation,
-                    },
-                );
-                for tick in &tickset.ticks {
-                    if !(data_bounds.minimum.y..data_bounds.maximum.y).contains(&tick.value) {
-                        continue;
-                    }
-                    let screen_point = view.to_screen(&(data_bounds.minimum.x, tick.value).into());
-                    let mark_len = if tick.major {
-                        configs.major_size
-                    } else {
-                        configs.minor_size
-                    };
-                    rl.draw_line_v(
-                        Vector2::new(screen_point.x - mark_len, screen_point.y),
-                        Vector2::new(screen_point.x, screen_point.y),
-                        configs.color.unwrap_or(Color::BLACK),
-                    );
-
-                    // Draw tick label text
-                    if configs.show_labels && tick.major && !tick.label.is_empty() {
-                        let mut style = configs.label_style.clone();
-                        style.anchor = Anchor::RIGHT_MIDDLE;
-                        let origin = Screenpoint::new(
-                            screen_point.x - mark_len - configs.label_offset,
-                            screen_point.y,
-                        );
-                        let text = TextLabel::new(&tick.label, origin);
-                        text.plot(rl, &style);
-                    }
-                }
+                self.draw_y_ticks(rl, configs, view, &ticks.y, y_axis_x, y_outward);
             }
             Visibility::Invisible => {}
         }
     }
 
-    fn data_bounds(&self) -> DataBBox {
-        self.axis.data_bounds()
+    /// Draws the y-axis `ticks` along the vertical line at data-x `x`.
+    ///
+    /// `outward` is `-1.0` to extend marks and labels to the left of the
+    /// line, or `1.0` to extend them to the right.
+    fn draw_y_ticks(
+        &self,
+        rl: &mut RaylibDrawHandle,
+        configs: &TickLabelsConfig,
+        view: &ViewTransformer,
+        ticks: &TickSet,
+        x: f32,
+        outward: f32,
+    ) {
+        let data_bounds = self.data_bounds();
+        for tick in &ticks.ticks {
+            if !within_axis(tick.value, data_bounds.minimum.y, data_bounds.maximum.y) {
+                continue;
+            }
+            let screen_point = view.to_screen(&(x, tick.value).into());
+            let mark_len = if tick.major {
+                configs.major_size
+            } else {
+                configs.minor_size
+            };
+            rl.draw_line_v(
+                Vector2::new(screen_point.x + outward * mark_len, screen_point.y),
+                Vector2::new(screen_point.x, screen_point.y),
+                configs.color.unwrap_or(Color::BLACK),
+            );
+
+            // Draw tick label text
+            let label = configs.drawn_label(tick);
+            if !label.is_empty() {
+                let mut style = configs.label_style.clone();
+                style.anchor = if outward < 0.0 {
+                    Anchor::RIGHT_MIDDLE
+                } else {
+                    Anchor::LEFT_MIDDLE
+                };
+                style.rotation = configs.y_label_rotation;
+                let origin = y_label_origin(configs, screen_point, mark_len, outward);
+                let text = TextLabel::new(label, origin);
+                text.plot(rl, &style);
+            }
+        }
+    }
+
+    /// Labels of the major ticks drawn between `min` and `max` on the x
+    /// axis, or with `x_axis` false the y axis, `length_px` pixels long.
+    fn axis_labels(
+        &self,
+        configs: &TickLabelsConfig,
+        x_axis: bool,
+        (min, max): (f32, f32),
+        scale: Scale,
+        length_px: f32,
+    ) -> Vec<String> {
+        if !configs.show_labels {
+            return Vec::new();
+        }
+        let (fixed, label): (_, fn(&TickLabelsConfig, &Tick) -> String) = if x_axis {
+            (configs.fixed_x_ticks(), TickLabelsConfig::drawn_x_label)
+        } else {
+            (configs.fixed_y_ticks(), TickLabelsConfig::drawn_label)
+        };
+        Self::tick_set(configs, fixed, min, max, scale, length_px)
+            .ticks
+            .iter()
+            .filter(|tick| tick.major && within_axis(tick.value, min, max))
+            .map(|tick| label(configs, tick))
+            .collect()
+    }
+
+    /// Pixels the ticks need outside the plotting area: the height below
+    /// (or above) the x axis and the width beside the y axis, marks and
+    /// labels included. A hidden axis needs no room. `plot_size` is the
+    /// expected size of the plotting area, which a
+    /// [`TickDensity::PerPixels`] density needs to count the ticks.
+    pub(crate) fn extents(
+        &self,
+        configs: &TickLabelsConfig,
+        default_font: &WeakFont,
+        plot_size: Vector2,
+    ) -> Vector2 {
+        let bounds = self.data_bounds();
+        let widest = |labels: Vec<String>, rotation: f32| {
+            let (sin, cos) = rotation.to_radians().sin_cos();
+            labels
+                .iter()
+                .map(|label| configs.label_style.measure_text(label, default_font))
+                // Bounding box of the label after rotation.
+                .map(|size| {
+                    Vector2::new(
+                        size.x * cos.abs() + size.y * sin.abs(),
+                        size.x * sin.abs() + size.y * cos.abs(),
+                    )
+                })
+                .fold(Vector2::zero(), |acc, size| {
+                    Vector2::new(acc.x.max(size.x), acc.y.max(size.y))
+                })
+        };
+        let mut extents = Vector2::zero();
+        if let Visibility::Visible = configs.x_axis {
+            let labels = self.axis_labels(
+                configs,
+                true,
+                (bounds.minimum.x, bounds.maximum.x),
+                configs.x_axis_scale,
+                plot_size.x,
+            );
+            extents.y = configs.major_size
+                + configs.label_offset
+                + widest(labels, configs.label_rotation).y;
+        }
+        if let Visibility::Visible = configs.y_axis {
+            let labels = self.axis_labels(
+                configs,
+                false,
+                (bounds.minimum.y, bounds.maximum.y),
+                configs.y_axis_scale,
+                plot_size.y,
+            );
+            extents.x = configs.major_size
+                + configs.label_offset
+                + widest(labels, configs.y_label_rotation).x;
+        }
+        extents
+    }
+
+    /// Draws only the y-axis ticks, on the right edge of `view`'s data
+    /// bounds, with labels outside the plot area. Used for secondary axes.
+    pub(crate) fn draw_right_in_view(
+        &self,
+        rl: &mut RaylibDrawHandle,
+        configs: &TickLabelsConfig,
+        view: &ViewTransformer,
+    ) {
+        if let Visibility::Visible = configs.y_axis {
+            let bounds = self.data_bounds();
+            let ticks = Self::tick_set(
+                configs,
+                configs.fixed_y_ticks(),
+                bounds.minimum.y,
+                bounds.maximum.y,
+                configs.y_axis_scale,
+                plot_size(view).y,
+            );
+            self.draw_y_ticks(rl, configs, view, &ticks, view.data_bounds.maximum.x, 1.0);
+        }
     }
 }
 
+/// Anchor point of the label of a y tick at `tick`, whose mark is
+/// `mark_len` long and points `outward` (`-1.0` left, `1.0` right).
+///
+/// A rotated label pivots on its middle edge nearest the axis, which swings
+/// one of its corners back toward the axis by half the text height times the
+/// sine of the angle; the anchor moves out by that much so the label stays
+/// clear of the mark.
+fn y_label_origin(
+    configs: &TickLabelsConfig,
+    tick: Screenpoint,
+    mark_len: f32,
+    outward: f32,
+) -> Screenpoint {
+    let swing =
+        configs.label_style.font_size * 0.5 * configs.y_label_rotation.to_radians().sin().abs();
+    Screenpoint::new(
+        tick.x + outward * (mark_len + configs.label_offset + swing),
+        tick.y,
+    )
+}
+
 /// Follows the color of the axis for tick marks; themes label text via `colorscheme.text`.
 impl Themable for TickLabelsConfig {
     fn apply_theme(&mut self, scheme: &crate::colorscheme::Colorscheme) {
@@ -904,3 +1787,232 @@ impl Themable for TickLabelsConfig {
         self.label_style.apply_theme(scheme);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plottable::view::Viewport;
+
+    #[test]
+    fn dashed_line_keeps_its_arrowhead() {
+        let configs = LineConfigBuilder::default()
+            .thickness(1.0)
+            .arrow_length(4.0)
+            .dash((3.0, 2.0))
+            .build()
+            .unwrap();
+        let line = Line::new((0.0, 0.0), (20.0, 0.0));
+        let (shaft, head) = line.pieces(&configs);
+        let [_, _, tip] = head.unwrap();
+        assert!(tip.distance_to(Vector2::new(20.0, 0.0)) < 1e-5);
+        // 16 px of shaft up to the base of the head: runs at 0, 5, 10, 15.
+        assert_eq!(shaft.len(), 4);
+        assert!(shaft.iter().all(|(_, end)| end.x <= 16.0 + 1e-5));
+    }
+
+    #[test]
+    fn dash_runs_alternate_and_stop_at_the_end() {
+        let runs = dash_runs(Vector2::zero(), Vector2::new(10.0, 0.0), (3.0, 2.0));
+        let xs: Vec<(f32, f32)> = runs.iter().map(|(a, b)| (a.x, b.x)).collect();
+        assert_eq!(xs, vec![(0.0, 3.0), (5.0, 8.0)]);
+        let solid = dash_runs(Vector2::zero(), Vector2::new(4.0, 0.0), (0.0, 2.0));
+        assert_eq!(solid.len(), 1);
+    }
+
+    #[test]
+    fn grid_dash_applies_to_regular_lines_but_not_the_emphasis() {
+        let config = GridLinesConfigBuilder::default()
+            .color(Color::GRAY)
+            .dash((4.0, 2.0))
+            .emphasize((0.0, Color::RED, 2.0))
+            .build()
+            .unwrap();
+        let (_, thickness, dash) = GridLines::regular_style(&config);
+        assert_eq!((thickness, dash), (1.0, Some((4.0, 2.0))));
+        let (value, (color, thickness, dash)) = GridLines::emphasis(&config).unwrap();
+        assert_eq!((value, thickness, dash), (0.0, 2.0, None));
+        assert_eq!(color, Color::RED);
+        assert!(GridLines::emphasis(&GridLinesConfig::default()).is_none());
+    }
+
+    #[test]
+    fn arrowhead_fits_a_line_shorter_than_the_head() {
+        let configs = LineConfigBuilder::default()
+            .thickness(1.0)
+            .arrow_length(6.0)
+            .arrow_width(5.0)
+            .build()
+            .unwrap();
+        let from = Vector2::new(10.0, 10.0);
+        let to = Vector2::new(13.0, 10.0);
+        let (base, [right, left, tip]) = arrowhead(from, to, &configs).unwrap();
+        assert!(base.distance_to(from) < 1e-5);
+        assert!(tip.distance_to(to) < 1e-5);
+        assert!((left.distance_to(right) - 5.0).abs() < 1e-5);
+
+        let long = Vector2::new(110.0, 10.0);
+        let (base, _) = arrowhead(from, long, &configs).unwrap();
+        assert!((base.distance_to(long) - 6.0).abs() < 1e-5);
+        assert!(arrowhead(from, from, &configs).is_none());
+    }
+
+    #[test]
+    fn rotated_y_label_clears_the_mark() {
+        let mut configs = TickLabelsConfig::default();
+        configs.label_style.font_size = 20.0;
+        configs.label_offset = 4.0;
+        let tick = Screenpoint::new(100.0, 50.0);
+
+        let straight = y_label_origin(&configs, tick, 5.0, -1.0);
+        assert!((straight.x - 91.0).abs() < 1e-4);
+
+        configs.y_label_rotation = 45.0;
+        let rotated = y_label_origin(&configs, tick, 5.0, -1.0);
+        let swing = 10.0 * std::f32::consts::FRAC_1_SQRT_2;
+        assert!((rotated.x - (91.0 - swing)).abs() < 1e-4);
+        assert!((rotated.y - 50.0).abs() < 1e-4);
+        // The corner swung back toward the axis still sits past the gap.
+        assert!(rotated.x + swing <= tick.x - 5.0 - 4.0 + 1e-4);
+
+        let right = y_label_origin(&configs, tick, 5.0, 1.0);
+        assert!((right.x - (109.0 + swing)).abs() < 1e-4);
+    }
+
+    #[test]
+    fn with_limits_keeps_exact_bounds() {
+        let axis = Axis::with_limits(0.0..100.0, 10.0..-10.0);
+        let bounds = axis.data_bounds();
+        assert!((bounds.minimum.x - 0.0).abs() < f32::EPSILON);
+        assert!((bounds.maximum.x - 100.0).abs() < f32::EPSILON);
+        assert!((bounds.minimum.y + 10.0).abs() < f32::EPSILON);
+        assert!((bounds.maximum.y - 10.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn point_beyond_limits_projects_outside_inner_bbox() {
+        let axis = Axis::with_limits(0.0..100.0, 0.0..100.0);
+        let viewport = Viewport::new(0.0, 0.0, 200.0, 200.0);
+        let view = ViewTransformer::new(axis.data_bounds(), viewport);
+        let inner = viewport.inner_bbox();
+
+        let p = view.to_screen(&Datapoint::new(150.0, 50.0));
+        assert!(p.x > inner.maximum.x);
+        let p = view.to_screen(&Datapoint::new(50.0, -20.0));
+        assert!(p.y > inner.maximum.y);
+    }
+
+    #[test]
+    fn origin_placement_falls_back_to_edge() {
+        assert_eq!(AxisPlacement::Origin.crossing(-5.0, 5.0), Some(0.0));
+        assert_eq!(AxisPlacement::Origin.crossing(1.0, 5.0), None);
+        assert_eq!(AxisPlacement::Edge.crossing(-5.0, 5.0), None);
+    }
+
+    #[test]
+    fn log_grid_lines_follow_log_ticks() {
+        let grid = GridLines::new(
+            Axis::with_limits(1.0..500.0, 1.0..500.0),
+            Orientation::default(),
+        );
+        let configs = GridLinesConfigBuilder::default()
+            .x_scale(Scale::Log {
+                base: 10.0,
+                include_minor: false,
+            })
+            .build()
+            .unwrap();
+        let ticks = grid.tick_sets(&configs);
+        let positions = GridLines::positions(&ticks.x, 1.0, 500.0);
+        assert_eq!(positions, vec![1.0, 10.0, 100.0]);
+    }
+
+    #[test]
+    fn per_pixel_density_thins_short_axes() {
+        let ticks = TickLabels::new(Axis::with_limits(0.0..100.0, 0.0..100.0));
+        let configs = TickLabelsBuilder::default()
+            .density(TickDensity::PerPixels(50.0))
+            .build()
+            .unwrap();
+        let sets = ticks.sized_tick_sets(&configs, Vector2::new(1000.0, 100.0));
+        assert!(sets.x.ticks.len() > 2 * sets.y.ticks.len());
+    }
+
+    #[test]
+    fn tick_sides_ignore_the_other_axis_sides() {
+        let configs = TickLabelsBuilder::default()
+            .x_side(Side::Top)
+            .y_side(Side::Right)
+            .build()
+            .unwrap();
+        assert!(configs.x_on_top() && configs.y_on_right());
+        let swapped = TickLabelsBuilder::default()
+            .x_side(Side::Right)
+            .y_side(Side::Top)
+            .build()
+            .unwrap();
+        assert!(!swapped.x_on_top() && !swapped.y_on_right());
+    }
+
+    #[test]
+    fn pixel_grid_repeats_from_the_corner() {
+        assert_eq!(
+            PixelGrid::offsets(10.0, 130.0, 50.0),
+            vec![10.0, 60.0, 110.0]
+        );
+        assert_eq!(PixelGrid::offsets(0.0, 3.0, 0.0).len(), 4);
+        assert_eq!(PixelGrid::offsets(5.0, 5.0, 50.0), vec![5.0]);
+    }
+
+    #[test]
+    fn shared_ticks_put_grid_lines_on_tick_marks() {
+        let axis = Axis::with_limits(0.0..7.0, -3.0..3.0);
+        let configs = TickLabelsBuilder::default()
+            .separation(Separation::Value(1.5))
+            .build()
+            .unwrap();
+        let ticks = TickLabels::new(axis).sized_tick_sets(&configs, Vector2::new(400.0, 300.0));
+        let marks: Vec<f32> = ticks
+            .x
+            .ticks
+            .iter()
+            .map(|tick| tick.value)
+            .filter(|value| within_axis(*value, 0.0, 7.0))
+            .collect();
+        assert_eq!(GridLines::positions(&ticks.x, 0.0, 7.0), marks);
+        assert_eq!(marks, vec![0.0, 1.5, 3.0, 4.5, 6.0]);
+    }
+
+    #[test]
+    fn tick_at_axis_maximum_is_labelled() {
+        let ticks = TickLabels::new(Axis::with_limits(0.0..10.0, 0.0..10.0));
+        let configs = TickLabelsBuilder::default()
+            .separation(Separation::Value(2.0))
+            .build()
+            .unwrap();
+        let labels = ticks.axis_labels(&configs, true, (0.0, 10.0), Scale::Linear, 400.0);
+        assert_eq!(labels, vec!["0", "2", "4", "6", "8", "10"]);
+    }
+
+    #[test]
+    fn explicit_positions_replace_generated_ticks() {
+        let ticks = TickLabels::new(Axis::with_limits(0.0..40.0, 0.0..10.0));
+        let configs = TickLabelsBuilder::default()
+            .x_positions(vec![1.0, 2.0, 5.0, 10.0, 50.0])
+            .build()
+            .unwrap();
+        let labels = ticks.axis_labels(
+            &configs,
+            true,
+            (0.0, 40.0),
+            Scale::Log {
+                base: 10.0,
+                include_minor: true,
+            },
+            400.0,
+        );
+        assert_eq!(labels, vec!["1", "2", "5", "10"]);
+        let sets = ticks.sized_tick_sets(&configs, Vector2::new(400.0, 300.0));
+        assert!(sets.x.step.is_none() && sets.x.ticks.iter().all(|tick| tick.major));
+        assert!(sets.y.step.is_some());
+    }
+}
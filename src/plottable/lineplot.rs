@@ -0,0 +1,295 @@
+//! Line charts of a single dataset.
+//!
+//! A [`LinePlot`] connects the points of a [`Dataset`] in storage order.
+//! Points with a non-finite coordinate (`NaN` or ±∞) mark missing data:
+//! no segment is drawn to or from them, so the polyline splits into
+//! contiguous runs with a visible gap where the data is missing.
+//!
+//! Thick lines are filled in at each bend according to a [`LineJoin`], so
+//! consecutive segments read as one continuous stroke.
+//!
+//! # Example
+//!
+//! ```rust
+//! use locus::prelude::*;
+//! let data = Dataset::new(vec![(0.0, 1.0), (1.0, f32::NAN), (2.0, 1.5), (3.0, 2.0)]);
+//! let config = LinePlotConfigBuilder::default().thickness(3.0).build().unwrap();
+//! let graph = Graph::new(LinePlot::new(&data));
+//! ```
+
+use derive_builder::Builder;
+use raylib::prelude::*;
+
+use crate::{
+    colorscheme::{Colorscheme, Themable},
+    dataset::Dataset,
+    plottable::{
+        legend::LegendEntry,
+        point::{Datapoint, fill_triangle},
+        view::{DataBBox, ViewTransformer},
+    },
+    plotter::ChartElement,
+};
+
+/// Pairs of consecutive points joined by a segment, skipping every pair
+/// where either end has a non-finite coordinate.
+///
+/// ```rust
+/// use locus::prelude::*;
+/// let points = [
+///     Datapoint::new(0.0, 0.0),
+///     Datapoint::new(1.0, f32::NAN),
+///     Datapoint::new(2.0, 2.0),
+///     Datapoint::new(3.0, 1.0),
+/// ];
+/// assert_eq!(segments(&points).count(), 1);
+/// ```
+pub fn segments(points: &[Datapoint]) -> impl Iterator<Item = (Datapoint, Datapoint)> + '_ {
+    points
+        .windows(2)
+        .filter(|pair| pair.iter().all(|p| p.x.is_finite() && p.y.is_finite()))
+        .map(|pair| (pair[0], pair[1]))
+}
+
+/// How a thick line is filled in where two of its segments meet.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LineJoin {
+    /// Segments are drawn independently, leaving a notch on the outside of
+    /// every bend.
+    None,
+    /// A disc as wide as the line at every vertex (the default).
+    #[default]
+    Round,
+    /// The outer edges are extended until they meet in a sharp corner.
+    /// Bends so tight that the corner would stick out further than
+    /// [`MITER_LIMIT`] half-thicknesses are cut flat instead.
+    Miter,
+}
+
+/// Longest miter corner, in half line thicknesses from the vertex, before
+/// a [`LineJoin::Miter`] join is cut flat.
+pub const MITER_LIMIT: f32 = 4.0;
+
+/// Draw the segments of `points` through `view`, filling in the bends with
+/// `join`. Like [`segments`], the line breaks at non-finite points.
+pub(crate) fn draw_polyline(
+    rl: &mut RaylibDrawHandle,
+    points: &[Datapoint],
+    view: &ViewTransformer,
+    (color, thickness, join): (Color, f32, LineJoin),
+) {
+    for (a, b) in segments(points) {
+        let (a, b) = (view.to_screen(&a), view.to_screen(&b));
+        rl.draw_line_ex(*a, *b, thickness, color);
+    }
+    let half = thickness * 0.5;
+    for triple in points.windows(3) {
+        if !triple.iter().all(|p| p.x.is_finite() && p.y.is_finite()) {
+            continue;
+        }
+        let [a, p, b] = [triple[0], triple[1], triple[2]].map(|q| *view.to_screen(&q));
+        match join {
+            LineJoin::None => {}
+            LineJoin::Round => rl.draw_circle_v(p, half, color),
+            LineJoin::Miter => {
+                if let Some((start, end, corner)) = miter_join(a, p, b, half) {
+                    fill_triangle(rl, p, start, end, color);
+                    if let Some(corner) = corner {
+                        fill_triangle(rl, start, corner, end, color);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Outer corners of the segment ends meeting at `p` on a line from `a` to
+/// `b` with half-thickness `half`, and the miter corner where the outer
+/// edges meet, unless it lies beyond [`MITER_LIMIT`].
+///
+/// `None` when the line does not bend at `p`, or a segment has no length.
+fn miter_join(
+    a: Vector2,
+    p: Vector2,
+    b: Vector2,
+    half: f32,
+) -> Option<(Vector2, Vector2, Option<Vector2>)> {
+    let (d1, d2) = (p - a, b - p);
+    if d1.length() < f32::EPSILON || d2.length() < f32::EPSILON {
+        return None;
+    }
+    let (d1, d2) = (d1.normalized(), d2.normalized());
+    let turn = d1.x * d2.y - d1.y * d2.x;
+    if turn.abs() < 1e-6 {
+        return None;
+    }
+    // The outer side of the bend is opposite the direction of the turn.
+    let side = -turn.signum();
+    let n1 = Vector2::new(-d1.y, d1.x) * side;
+    let n2 = Vector2::new(-d2.y, d2.x) * side;
+    let (start, end) = (p + n1 * half, p + n2 * half);
+    let bisector = n1 + n2;
+    if bisector.length() < f32::EPSILON {
+        return Some((start, end, None));
+    }
+    let bisector = bisector.normalized();
+    let reach = half / bisector.dot(n1);
+    let corner = (reach <= MITER_LIMIT * half).then(|| p + bisector * reach);
+    Some((start, end, corner))
+}
+
+/// A polyline through a dataset, broken at missing (non-finite) points.
+///
+/// See the [module documentation](self) for an example.
+#[derive(Debug, Clone, Copy)]
+pub struct LinePlot<'a> {
+    /// The dataset being visualized.
+    pub data: &'a Dataset,
+}
+
+impl<'a> LinePlot<'a> {
+    /// Create a line plot of `data`.
+    #[must_use]
+    pub fn new(data: &'a Dataset) -> Self {
+        Self { data }
+    }
+
+    /// A legend entry named `name`, drawn as a line swatch matching the
+    /// plot.
+    #[must_use]
+    pub fn legend_entry(&self, name: impl Into<String>, configs: &LinePlotConfig) -> LegendEntry {
+        LegendEntry::new(name, configs.color.unwrap_or(Color::BLACK)).with_line(configs.thickness)
+    }
+}
+
+/// Configuration for [`LinePlot`] rendering.
+///
+/// When `color` is `None` it is resolved from the first color of the
+/// [`Colorscheme`](crate::colorscheme::Colorscheme) cycle during theme
+/// application.
+#[derive(Debug, Clone, Copy, Builder)]
+#[builder(pattern = "owned")]
+#[builder(default)]
+pub struct LinePlotConfig {
+    /// Explicit line color. `None` means "use the first theme cycle color".
+    #[builder(setter(into, strip_option))]
+    pub color: Option<Color>,
+    /// Line thickness in pixels.
+    pub thickness: f32,
+    /// How bends are filled in.
+    pub join: LineJoin,
+}
+
+impl Default for LinePlotConfig {
+    fn default() -> Self {
+        Self {
+            color: None,
+            thickness: 2.0,
+            join: LineJoin::Round,
+        }
+    }
+}
+
+impl LinePlotConfig {
+    /// Color, thickness and join of the line, for [`draw_polyline`].
+    pub(crate) fn stroke(&self) -> (Color, f32, LineJoin) {
+        (
+            self.color.unwrap_or(Color::BLACK),
+            self.thickness,
+            self.join,
+        )
+    }
+}
+
+impl ChartElement for LinePlot<'_> {
+    type Config = LinePlotConfig;
+
+    fn draw_in_view(
+        &self,
+        rl: &mut RaylibDrawHandle,
+        configs: &Self::Config,
+        view: &ViewTransformer,
+    ) {
+        draw_polyline(rl, &self.data.data, view, configs.stroke());
+    }
+
+    /// Bounds of the finite points only, so a gap marked with ±∞ does not
+    /// stretch the view.
+    fn data_bounds(&self) -> DataBBox {
+        self.data
+            .data
+            .iter()
+            .filter(|p| p.x.is_finite() && p.y.is_finite())
+            .map(|p| DataBBox::from_min_max(*p, *p))
+            .reduce(|a, b| a.union(&b))
+            .unwrap_or_else(|| DataBBox::from_min_max((0.0, 0.0), (0.0, 0.0)))
+    }
+
+    fn min_positive(&self) -> (Option<f32>, Option<f32>) {
+        self.data.min_positive()
+    }
+}
+
+impl Themable for LinePlotConfig {
+    fn apply_theme(&mut self, scheme: &Colorscheme) {
+        if self.color.is_none() {
+            self.color = Some(scheme.cycle.first().copied().unwrap_or(Color::BLACK));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nan_breaks_the_line() {
+        let data = Dataset::new(vec![(0.0, 0.0), (1.0, f32::NAN), (2.0, 2.0)]);
+        assert_eq!(segments(&data.data).count(), 0);
+
+        let data = Dataset::new(vec![
+            (0.0, 0.0),
+            (1.0, f32::NAN),
+            (2.0, 2.0),
+            (3.0, 1.0),
+            (4.0, f32::INFINITY),
+            (5.0, 3.0),
+            (6.0, 4.0),
+        ]);
+        let drawn: Vec<(f32, f32)> = segments(&data.data).map(|(a, b)| (a.x, b.x)).collect();
+        assert_eq!(drawn, vec![(2.0, 3.0), (5.0, 6.0)]);
+    }
+
+    #[test]
+    fn miter_fills_the_outside_of_the_bend() {
+        // Right, then down the screen: the outer corner is up and right.
+        let (start, end, corner) = miter_join(
+            Vector2::new(0.0, 0.0),
+            Vector2::new(10.0, 0.0),
+            Vector2::new(10.0, 10.0),
+            2.0,
+        )
+        .unwrap();
+        assert!((start.y + 2.0).abs() < 1e-5 && (end.x - 12.0).abs() < 1e-5);
+        let corner = corner.unwrap();
+        assert!((corner.x - 12.0).abs() < 1e-4 && (corner.y + 2.0).abs() < 1e-4);
+
+        // Nearly doubling back exceeds the limit; a straight line has no join.
+        let hairpin = miter_join(
+            Vector2::new(0.0, 0.0),
+            Vector2::new(10.0, 0.0),
+            Vector2::new(0.0, 0.5),
+            2.0,
+        );
+        assert!(hairpin.is_some_and(|(_, _, corner)| corner.is_none()));
+        assert!(
+            miter_join(
+                Vector2::new(0.0, 0.0),
+                Vector2::new(1.0, 0.0),
+                Vector2::new(2.0, 0.0),
+                2.0
+            )
+            .is_none()
+        );
+    }
+}
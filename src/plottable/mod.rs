@@ -6,12 +6,25 @@
 //! | Sub-module | Contents |
 //! |---|---|
 //! | [`annotation`] | Data-space text annotations with optional leader arrows |
+//! | [`band`] | [`Band`](band::Band) shaded confidence bands around a center line |
+//! | [`bar`] | [`GroupedBarChart`](bar::GroupedBarChart) and [`StackedBarChart`](bar::StackedBarChart) over categorical slots |
+//! | [`density`] | [`DensityPlot`](density::DensityPlot) Gaussian kernel density curves |
+//! | [`histogram`] | [`Histogram2D`](histogram::Histogram2D) binned point densities colored through a colormap |
+//! | [`interaction`] | [`Crosshair`](interaction::Crosshair) overlay and [`BoxSelect`](interaction::BoxSelect) drag-to-zoom |
+//! | [`layers`] | [`Layers`](layers::Layers) container overlaying several chart elements in one view |
 //! | [`legend`] | Configurable legend box with color swatches and labels |
 //! | [`mod@line`] | Lines, axes, grid lines, tick labels, and related configs |
+//! | [`lineplot`] | [`LinePlot`](lineplot::LinePlot) polylines that break at missing (`NaN`) points |
+//! | [`pie`] | [`PieChart`](pie::PieChart) pie and donut charts in screen space |
 //! | [`point`] | [`Datapoint`](point::Datapoint), [`Screenpoint`](point::Screenpoint), and shape primitives |
+//! | [`polar`] | [`PolarTransformer`](polar::PolarTransformer), polar grid, and polar scatter plots |
+//! | [`radar`] | [`RadarChart`](radar::RadarChart) spider charts over equiangular spokes |
+//! | [`reference`] | [`HLine`](reference::HLine) / [`VLine`](reference::VLine) reference lines and shaded spans/regions |
 //! | [`scatter`] | [`ScatterPlot`](scatter::ScatterPlot) with per-point dynamic attributes |
+//! | [`series`] | [`MultiScatter`](series::MultiScatter) and [`MultiLine`](series::MultiLine) over named series |
 //! | [`text`] | Text rendering primitives, font handles, and anchor/alignment types |
 //! | [`ticks`] | Tick generation for linear, logarithmic, and symmetric-log scales |
+//! | [`timeline`] | [`Timeline`](timeline::Timeline) Gantt-style task bars in rows over time |
 //! | [`view`] | Bounding boxes, viewports, margins, and the [`ViewTransformer`](view::ViewTransformer) |
 //!
 //! Most users will interact with these types indirectly through the
@@ -19,12 +32,25 @@
 //! for advanced use cases such as custom chart elements.
 
 pub mod annotation;
+pub mod band;
+pub mod bar;
+pub mod density;
+pub mod histogram;
+pub mod interaction;
+pub mod layers;
 pub mod legend;
 pub mod line;
+pub mod lineplot;
+pub mod pie;
 pub mod point;
+pub mod polar;
+pub mod radar;
+pub mod reference;
 pub mod scatter;
+pub mod series;
 pub mod text;
 pub mod ticks;
+pub mod timeline;
 pub mod view;
 
 /// Internal helpers for "nice number" rounding and tick spacing algorithms.
@@ -32,19 +58,6 @@ pub mod view;
 /// These utilities are used by the tick and grid line generators to produce
 /// human-friendly axis ranges and spacing values.
 pub(crate) mod common {
-    use crate::plottable::line::Separation;
-
-    #[allow(clippy::cast_precision_loss)]
-    pub(crate) fn get_spacing(length: f32, separation: Separation, max_ticks: usize) -> f32 {
-        match separation {
-            Separation::Value(v) => v,
-            Separation::Auto => {
-                let rough_spacing = length / (max_ticks as f32).max(1.0);
-                nice_number(rough_spacing, true)
-            }
-        }
-    }
-
     pub(crate) fn nice_number(value: f32, round: bool) -> f32 {
         let exponent = value.log10().floor();
         let fraction = value / 10.0_f32.powf(exponent);
@@ -87,6 +100,15 @@ pub(crate) mod common {
         let val_max = (max / step).ceil() * step;
         (val_min, val_max, step)
     }
+    /// Whether a tick at `value` lies on the axis spanning `min..=max`.
+    ///
+    /// Both ends are inclusive, with a small tolerance relative to the span so
+    /// that a tick landing on the maximum through float rounding is still kept.
+    pub(crate) fn within_axis(value: f32, min: f32, max: f32) -> bool {
+        let tolerance = (max - min).abs() * 1e-5;
+        (min - tolerance..=max + tolerance).contains(&value)
+    }
+
     /// Tick data returned by [`log_spacing`].
     pub(crate) type LogSpacingResult = (f32, f32, Vec<f32>, Option<Vec<f32>>);
 
@@ -118,7 +140,7 @@ pub(crate) mod common {
                 {
                     for exponent in e0..=e1 {
                         let tick = base.powi(exponent);
-                        if (low..high).contains(&tick) {
+                        if within_axis(tick, low, high) {
                             ticks.push(tick);
                         }
 
@@ -128,7 +150,7 @@ pub(crate) mod common {
                             if minor_max >= 3 {
                                 for m in 2..minor_max {
                                     let minor_val = (m as f32) * base.powi(exponent);
-                                    if (low..high).contains(&minor_val)
+                                    if within_axis(minor_val, low, high)
                                         && let Some(ref mut minor_ticks) = minor_ticks
                                     {
                                         minor_ticks.push(minor_val);
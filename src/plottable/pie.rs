@@ -0,0 +1,319 @@
+//! Pie and donut charts.
+//!
+//! A [`PieChart`] splits a circle into one sector per labelled value, each
+//! spanning its share of the total. It is a screen-space [`PlotElement`]:
+//! place it with a center point and size it with
+//! [`PieConfig::radius`]. A non-zero
+//! [`donut_hole_fraction`](PieConfig::donut_hole_fraction) cuts out the
+//! middle to draw a donut instead.
+//!
+//! Slice colors come from the theme cycle unless set explicitly, and
+//! [`legend_entries`](PieChart::legend_entries) produces matching swatches.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use locus::prelude::*;
+//! # let (mut rl, thread) = raylib::init().build();
+//! let pie = PieChart::new((400.0, 300.0), [("rent", 1200.0), ("food", 450.0), ("fun", 200.0)]);
+//! let mut config = PieConfigBuilder::default()
+//!     .donut_hole_fraction(0.5)
+//!     .show_percentages(true)
+//!     .build()
+//!     .unwrap();
+//! config.apply_theme(&NORD);
+//! let legend = pie.legend_entries(&config, &NORD);
+//! let mut d = rl.begin_drawing(&thread);
+//! pie.plot(&mut d, &config);
+//! ```
+
+use std::f32::consts::TAU;
+
+use derive_builder::Builder;
+use raylib::prelude::*;
+
+use crate::{
+    colorscheme::{Colorscheme, Themable, indexed_color},
+    plottable::{
+        legend::LegendEntry,
+        point::{Datapoint, Screenpoint, Shape, fill_triangle},
+        polar::PolarTransformer,
+        text::{Anchor, TextLabel, TextStyle, TextStyleBuilder},
+    },
+    plotter::PlotElement,
+};
+
+/// One labelled value of a [`PieChart`].
+#[derive(Debug, Clone)]
+pub struct PieSlice {
+    /// Slice name, used for legend entries.
+    pub label: String,
+    /// Slice value. Only positive, finite values get a sector.
+    pub value: f32,
+}
+
+/// A pie (or donut) chart of labelled values.
+///
+/// See the [module documentation](self) for an example.
+#[derive(Debug, Clone)]
+pub struct PieChart {
+    /// Screen position of the center.
+    pub center: Screenpoint,
+    /// The slices, laid out in order from the start angle.
+    pub slices: Vec<PieSlice>,
+}
+
+impl PieChart {
+    /// Create a pie chart centred on `center` from `(label, value)` pairs.
+    #[must_use]
+    pub fn new<L: Into<String>>(
+        center: impl Into<Screenpoint>,
+        slices: impl IntoIterator<Item = (L, f32)>,
+    ) -> Self {
+        Self {
+            center: center.into(),
+            slices: slices
+                .into_iter()
+                .map(|(label, value)| PieSlice {
+                    label: label.into(),
+                    value,
+                })
+                .collect(),
+        }
+    }
+
+    /// One legend entry per slice, colored like its sector.
+    #[must_use]
+    pub fn legend_entries(&self, configs: &PieConfig, scheme: &Colorscheme) -> Vec<LegendEntry> {
+        self.slices
+            .iter()
+            .enumerate()
+            .map(|(i, s)| {
+                LegendEntry::new(s.label.clone(), configs.slice_color(i, Some(scheme)))
+                    .with_shape(Shape::Rectangle)
+            })
+            .collect()
+    }
+
+    /// `(index, start, sweep)` of every drawn slice, in radians
+    /// counter-clockwise from the positive x direction. Sweeps are negative
+    /// when the chart runs clockwise.
+    fn sectors(&self, configs: &PieConfig) -> Vec<(usize, f32, f32)> {
+        let drawn = |v: f32| v.is_finite() && v > 0.0;
+        let total: f32 = self
+            .slices
+            .iter()
+            .map(|s| s.value)
+            .filter(|v| drawn(*v))
+            .sum();
+        if total <= 0.0 {
+            return Vec::new();
+        }
+        let direction = if configs.clockwise { -1.0 } else { 1.0 };
+        let mut start = configs.start_angle.to_radians();
+        self.slices
+            .iter()
+            .enumerate()
+            .filter(|(_, s)| drawn(s.value))
+            .map(|(i, s)| {
+                let sweep = direction * TAU * s.value / total;
+                let sector = (i, start, sweep);
+                start += sweep;
+                sector
+            })
+            .collect()
+    }
+}
+
+/// Configuration for [`PieChart`] rendering.
+///
+/// When `colors` is `None` it is resolved from the
+/// [`Colorscheme`](crate::colorscheme::Colorscheme) cycle during theme
+/// application; slice `i` uses `colors[i % colors.len()]`.
+#[derive(Debug, Clone, Builder)]
+#[builder(pattern = "owned")]
+#[builder(default)]
+pub struct PieConfig {
+    /// Outer radius in pixels.
+    pub radius: f32,
+    /// Angle in degrees where the first slice starts, counter-clockwise from
+    /// the 3 o'clock position. The default of `90.0` starts at the top.
+    pub start_angle: f32,
+    /// Whether slices follow each other clockwise.
+    pub clockwise: bool,
+    /// Radius of the hole as a fraction of `radius`, in `0.0..1.0`. `0.0`
+    /// draws a pie, anything larger a donut.
+    pub donut_hole_fraction: f32,
+    /// Explicit per-slice colors. `None` means "use the theme cycle".
+    #[builder(setter(into, strip_option))]
+    pub colors: Option<Vec<Color>>,
+    /// Whether to write each slice's share (e.g. `"25%"`) on it.
+    pub show_percentages: bool,
+    /// Number of decimals in the percentage labels.
+    pub percent_decimals: usize,
+    /// Style of the percentage labels, centred on their slice.
+    pub label_style: TextStyle,
+    /// Segments a full circle is split into; each slice gets its share,
+    /// and at least one. `0`, the default, picks a count from the radius.
+    /// 24 to 64 suits most charts: fewer looks faceted on large pies, more
+    /// only adds triangles to small ones.
+    pub circle_segments: u32,
+}
+
+impl Default for PieConfig {
+    fn default() -> Self {
+        Self {
+            radius: 150.0,
+            start_angle: 90.0,
+            clockwise: true,
+            donut_hole_fraction: 0.0,
+            colors: None,
+            show_percentages: false,
+            percent_decimals: 0,
+            label_style: TextStyleBuilder::default()
+                .font_size(14.0)
+                .anchor(Anchor::CENTER)
+                .build()
+                .expect("Will never fail"),
+            circle_segments: 0,
+        }
+    }
+}
+
+impl PieConfig {
+    /// Fill color of slice `index`, falling back to `scheme`'s cycle when no
+    /// colors are set.
+    fn slice_color(&self, index: usize, scheme: Option<&Colorscheme>) -> Color {
+        indexed_color(self.colors.as_deref(), scheme, index)
+    }
+
+    /// Number of straight edges approximating the arc of a slice spanning
+    /// `sweep` radians.
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )]
+    fn arc_steps(&self, sweep: f32) -> usize {
+        let steps = if self.circle_segments == 0 {
+            sweep.abs() * self.radius / ARC_STEP_PIXELS
+        } else {
+            sweep.abs() / TAU * self.circle_segments as f32
+        };
+        (steps.ceil() as usize).max(1)
+    }
+}
+
+/// Pixels of arc covered by one edge of a sector's outline when
+/// [`PieConfig::circle_segments`] is `0`.
+const ARC_STEP_PIXELS: f32 = 4.0;
+
+impl PlotElement for PieChart {
+    type Config = PieConfig;
+
+    #[allow(clippy::cast_precision_loss)]
+    fn plot(&self, rl: &mut RaylibDrawHandle, configs: &Self::Config) {
+        let polar = PolarTransformer::new(self.center, configs.radius);
+        let hole = configs.donut_hole_fraction.clamp(0.0, 0.99);
+        let sectors = self.sectors(configs);
+        for &(i, start, sweep) in &sectors {
+            let color = configs.slice_color(i, None);
+            let steps = configs.arc_steps(sweep);
+            let at = |k: usize, r: f32| {
+                // The last step lands exactly on `start + sweep`, so a lone
+                // full-circle slice closes without a gap.
+                let theta = start + sweep * k as f32 / steps as f32;
+                *polar.to_screen(&Datapoint::new(theta, r))
+            };
+            for k in 0..steps {
+                let (a, b) = (at(k, 1.0), at(k + 1, 1.0));
+                if hole > 0.0 {
+                    let (c, d) = (at(k + 1, hole), at(k, hole));
+                    fill_triangle(rl, a, b, c, color);
+                    fill_triangle(rl, a, c, d, color);
+                } else {
+                    fill_triangle(rl, *self.center, a, b, color);
+                }
+            }
+        }
+        if !configs.show_percentages {
+            return;
+        }
+        let total: f32 = sectors.iter().map(|(_, _, sweep)| sweep.abs()).sum();
+        let label_radius = if hole > 0.0 { (1.0 + hole) * 0.5 } else { 0.65 };
+        for &(_, start, sweep) in &sectors {
+            let share = 100.0 * sweep.abs() / total;
+            let origin = polar.to_screen(&Datapoint::new(start + sweep * 0.5, label_radius));
+            let text = format!("{share:.prec$}%", prec = configs.percent_decimals);
+            TextLabel::new(text, origin).plot(rl, &configs.label_style);
+        }
+    }
+}
+
+impl Themable for PieConfig {
+    fn apply_theme(&mut self, scheme: &Colorscheme) {
+        if self.colors.is_none() && !scheme.cycle.is_empty() {
+            self.colors = Some(scheme.cycle.to_vec());
+        }
+        self.label_style.apply_theme(scheme);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_approx(a: f32, b: f32) {
+        assert!((a - b).abs() < 1e-5, "expected {b}, got {a}");
+    }
+
+    #[test]
+    fn sectors_split_the_circle_by_share() {
+        let pie = PieChart::new(
+            (0.0, 0.0),
+            [("a", 1.0), ("skip", -3.0), ("b", 1.0), ("c", 2.0)],
+        );
+        let sectors = pie.sectors(&PieConfig::default());
+        assert_eq!(
+            sectors.iter().map(|s| s.0).collect::<Vec<_>>(),
+            vec![0, 2, 3]
+        );
+        // Clockwise from the top.
+        assert_approx(sectors[0].1, TAU / 4.0);
+        assert_approx(sectors[0].2, -TAU / 4.0);
+        assert_approx(sectors[1].1, 0.0);
+        assert_approx(sectors[2].2, -TAU / 2.0);
+    }
+
+    #[test]
+    fn single_slice_is_a_full_circle() {
+        let pie = PieChart::new((0.0, 0.0), [("all", 5.0), ("none", 0.0)]);
+        let config = PieConfigBuilder::default()
+            .start_angle(0.0)
+            .clockwise(false)
+            .build()
+            .unwrap();
+        let sectors = pie.sectors(&config);
+        assert_eq!(sectors.len(), 1);
+        assert_approx(sectors[0].1, 0.0);
+        assert_approx(sectors[0].2, TAU);
+        assert!(
+            PieChart::new((0.0, 0.0), [("x", 0.0)])
+                .sectors(&config)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn circle_segments_are_shared_between_slices() {
+        let config = PieConfigBuilder::default()
+            .circle_segments(32)
+            .build()
+            .unwrap();
+        assert_eq!(config.arc_steps(TAU), 32);
+        assert_eq!(config.arc_steps(-TAU / 4.0), 8);
+        assert_eq!(config.arc_steps(0.01), 1);
+        // 150 px radius at 4 px per edge.
+        assert_eq!(PieConfig::default().arc_steps(TAU), 236);
+    }
+}
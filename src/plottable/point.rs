@@ -82,8 +82,8 @@ impl std::ops::Deref for Datapoint {
 /// [`Datapoint`] but representing a position on the rendered window rather
 /// than in the user's data space.
 ///
-/// Implements [`PlotElement`] so that a single point can be drawn as a
-/// circle, triangle, or rectangle via [`PointConfig`].
+/// Implements [`PlotElement`] so that a single point can be drawn as any
+/// [`Shape`] via [`PointConfig`].
 #[derive(Clone, Copy, Debug)]
 pub struct Screenpoint(pub Vector2);
 
@@ -135,6 +135,173 @@ pub enum Shape {
     Triangle,
     /// Filled axis-aligned rectangle.
     Rectangle,
+    /// Filled square rotated by 45°, centred on the point.
+    Diamond,
+    /// Upright `+` made of two thick strokes.
+    Plus,
+    /// Diagonal `×` made of two thick strokes.
+    Cross,
+    /// Filled five-pointed star.
+    Star,
+}
+
+/// Ratio between the inner and outer radius of [`Shape::Star`].
+const STAR_INNER_RATIO: f32 = 0.382;
+/// Stroke thickness of [`Shape::Plus`] and [`Shape::Cross`] relative to `size`.
+const STROKE_RATIO: f32 = 0.35;
+
+/// Draw a filled triangle regardless of vertex winding.
+///
+/// raylib only rasterises triangles wound counter-clockwise, so clockwise
+/// input is flipped before drawing.
+pub(crate) fn fill_triangle(
+    rl: &mut RaylibDrawHandle,
+    a: Vector2,
+    b: Vector2,
+    c: Vector2,
+    color: Color,
+) {
+    let cross = (b.x - a.x) * (c.y - a.y) - (b.y - a.y) * (c.x - a.x);
+    if cross > 0.0 {
+        rl.draw_triangle(a, c, b, color);
+    } else {
+        rl.draw_triangle(a, b, c, color);
+    }
+}
+
+/// Outline of a star centred on `center`: alternating outer and inner
+/// vertices, starting from the top point.
+#[allow(clippy::cast_precision_loss)]
+fn star_vertices(center: Vector2, size: f32) -> Vec<Vector2> {
+    (0..10)
+        .map(|k| {
+            let radius = if k % 2 == 0 {
+                size
+            } else {
+                size * STAR_INNER_RATIO
+            };
+            let angle = (-90.0 + 36.0 * k as f32).to_radians();
+            center + Vector2::new(radius * angle.cos(), radius * angle.sin())
+        })
+        .collect()
+}
+
+/// Rotate `p` about `center` by `degrees` (clockwise on screen).
+fn rotate_about(p: Vector2, center: Vector2, degrees: f32) -> Vector2 {
+    let (sin, cos) = degrees.to_radians().sin_cos();
+    let d = p - center;
+    center + Vector2::new(d.x * cos - d.y * sin, d.x * sin + d.y * cos)
+}
+
+/// The polygon outlining a shape anchored at `(x, y)`, as `(centre, vertices)`,
+/// rotated by `rotation` degrees about its centre.
+///
+/// Returns `None` for shapes that are not polygons (circles and the stroked
+/// `Plus` / `Cross`).
+fn polygon(
+    shape: Shape,
+    x: f32,
+    y: f32,
+    size: f32,
+    rotation: f32,
+) -> Option<(Vector2, Vec<Vector2>)> {
+    let (center, vertices) = unrotated_polygon(shape, x, y, size)?;
+    let vertices = vertices
+        .into_iter()
+        .map(|v| rotate_about(v, center, rotation))
+        .collect();
+    Some((center, vertices))
+}
+
+fn unrotated_polygon(shape: Shape, x: f32, y: f32, size: f32) -> Option<(Vector2, Vec<Vector2>)> {
+    let center = Vector2::new(x, y);
+    let vertices = match shape {
+        Shape::Triangle => vec![
+            Vector2::new(
+                x + size * f32::cos(330.0_f32.to_radians()),
+                y + size * f32::sin(330.0_f32.to_radians()),
+            ),
+            Vector2::new(
+                x + size * f32::cos(210.0_f32.to_radians()),
+                y + size * f32::sin(210.0_f32.to_radians()),
+            ),
+            Vector2::new(x, y + size),
+        ],
+        // Rectangles hang from their anchor point (top-left corner).
+        Shape::Rectangle => {
+            return Some((
+                Vector2::new(x + size * 0.5, y + size * 0.5),
+                vec![
+                    Vector2::new(x, y),
+                    Vector2::new(x + size, y),
+                    Vector2::new(x + size, y + size),
+                    Vector2::new(x, y + size),
+                ],
+            ));
+        }
+        Shape::Diamond => vec![
+            Vector2::new(x, y - size),
+            Vector2::new(x - size, y),
+            Vector2::new(x, y + size),
+            Vector2::new(x + size, y),
+        ],
+        Shape::Star => star_vertices(center, size),
+        Shape::Circle | Shape::Plus | Shape::Cross => return None,
+    };
+    Some((center, vertices))
+}
+
+/// The two strokes making up a `Plus` or `Cross` centred on `(x, y)`,
+/// rotated by `rotation` degrees.
+fn strokes(shape: Shape, x: f32, y: f32, size: f32, rotation: f32) -> [(Vector2, Vector2); 2] {
+    let center = Vector2::new(x, y);
+    unrotated_strokes(shape, x, y, size).map(|(start, end)| {
+        (
+            rotate_about(start, center, rotation),
+            rotate_about(end, center, rotation),
+        )
+    })
+}
+
+fn unrotated_strokes(shape: Shape, x: f32, y: f32, size: f32) -> [(Vector2, Vector2); 2] {
+    let d = match shape {
+        Shape::Cross => size * std::f32::consts::FRAC_1_SQRT_2,
+        _ => size,
+    };
+    match shape {
+        Shape::Cross => [
+            (Vector2::new(x - d, y - d), Vector2::new(x + d, y + d)),
+            (Vector2::new(x - d, y + d), Vector2::new(x + d, y - d)),
+        ],
+        _ => [
+            (Vector2::new(x - d, y), Vector2::new(x + d, y)),
+            (Vector2::new(x, y - d), Vector2::new(x, y + d)),
+        ],
+    }
+}
+
+/// Pixels of circumference covered by one segment of a smooth circle.
+const SMOOTH_ARC_PIXELS: f32 = 2.0;
+/// Fewest and most segments of a smooth circle.
+const SMOOTH_SEGMENTS: (i32, i32) = (16, 128);
+
+/// Segment count of a smooth circle of `radius` pixels: one per
+/// [`SMOOTH_ARC_PIXELS`] of circumference, within [`SMOOTH_SEGMENTS`].
+#[allow(clippy::cast_possible_truncation)]
+fn smooth_segments(radius: f32) -> i32 {
+    let (fewest, most) = SMOOTH_SEGMENTS;
+    let wanted = (std::f32::consts::TAU * radius.max(0.0) / SMOOTH_ARC_PIXELS).ceil();
+    (wanted.min(most as f32) as i32).max(fewest)
+}
+
+/// Segment count of a circle of `radius` pixels: `requested` when non-zero,
+/// at least three, otherwise the adaptive [`smooth_segments`] count.
+pub(crate) fn circle_segments(radius: f32, requested: u32) -> i32 {
+    if requested == 0 {
+        smooth_segments(radius)
+    } else {
+        i32::try_from(requested.max(3)).unwrap_or(i32::MAX)
+    }
 }
 
 /// Visual configuration for drawing a single [`Screenpoint`].
@@ -161,6 +328,34 @@ pub struct PointConfig {
     size: f32,
     /// Geometric shape used to render the point.
     shape: Shape,
+    /// Whether the shape is filled. When `false` only its outline is drawn,
+    /// in `outline_color` if set and `color` otherwise. `Plus` and `Cross`
+    /// are strokes and look the same either way.
+    filled: bool,
+    /// Border drawn on top of a filled shape. `None` draws no border.
+    outline_color: Option<Color>,
+    /// Thickness in pixels of the outline of hollow or bordered shapes.
+    outline_thickness: f32,
+    /// Clockwise rotation of the shape about its centre, in degrees.
+    /// Circles ignore it.
+    rotation_deg: f32,
+    /// Pixel offset added to the point's position when drawing. The point
+    /// itself keeps its projected position.
+    offsets: Vector2,
+    /// Draw circles at their exact sub-pixel position with a segment count
+    /// that grows with the radius, instead of raylib's fixed 36-segment
+    /// circle snapped to whole pixels. Small markers look rounder and dense
+    /// clouds shimmer less, but every circle costs more triangles; leave
+    /// it off when drawing tens of thousands of points. Other shapes
+    /// ignore it.
+    smooth: bool,
+    /// Segments used for circles. `0`, the default, picks a count from the
+    /// radius when `smooth` is set and keeps raylib's circle otherwise. Any
+    /// other value draws circles like `smooth` does with exactly that many
+    /// segments: 12 to 16 is plenty for small markers, 48 to 64 keeps
+    /// circles of a hundred pixels and more round. Fewer segments are
+    /// cheaper to draw.
+    circle_segments: u32,
 }
 
 impl Default for PointConfig {
@@ -169,41 +364,176 @@ impl Default for PointConfig {
             color: Color::RED,
             size: 10.0,
             shape: Shape::Circle,
+            filled: true,
+            outline_color: None,
+            outline_thickness: 1.0,
+            rotation_deg: 0.0,
+            offsets: Vector2::zero(),
+            smooth: false,
+            circle_segments: 0,
         }
     }
 }
 
-impl PlotElement for Screenpoint {
-    type Config = PointConfig;
+impl Screenpoint {
+    /// This point shifted by `offset` pixels.
+    #[must_use]
+    pub fn offset_by(&self, offset: Vector2) -> Self {
+        Self(self.0 + offset)
+    }
+
     #[allow(clippy::cast_possible_truncation)]
-    fn plot(&self, rl: &mut raylib::prelude::RaylibDrawHandle, configs: &PointConfig) {
-        let x = self.x;
-        let y = self.y;
+    fn fill(&self, rl: &mut RaylibDrawHandle, configs: &PointConfig) {
+        let (x, y) = (self.x, self.y);
         match configs.shape {
+            Shape::Circle if configs.smooth || configs.circle_segments > 0 => {
+                let segments = circle_segments(configs.size, configs.circle_segments);
+                rl.draw_circle_sector(
+                    Vector2::new(x, y),
+                    configs.size,
+                    0.0,
+                    360.0,
+                    segments,
+                    configs.color,
+                );
+            }
             Shape::Circle => {
                 rl.draw_circle(x as i32, y as i32, configs.size, configs.color);
             }
-            Shape::Triangle => {
-                rl.draw_triangle(
-                    Vector2::new(
-                        x + configs.size * f32::cos(330.0_f32.to_radians()),
-                        y + configs.size * f32::sin(330.0_f32.to_radians()),
-                    ),
-                    Vector2::new(
-                        x + configs.size * f32::cos(210.0_f32.to_radians()),
-                        y + configs.size * f32::sin(210.0_f32.to_radians()),
-                    ),
-                    Vector2::new(x, y + configs.size),
-                    configs.color,
-                );
+            Shape::Plus | Shape::Cross => {
+                let thick = (configs.size * STROKE_RATIO).max(1.0);
+                for (start, end) in strokes(configs.shape, x, y, configs.size, configs.rotation_deg)
+                {
+                    rl.draw_line_ex(start, end, thick, configs.color);
+                }
+            }
+            Shape::Triangle | Shape::Rectangle | Shape::Diamond | Shape::Star => {
+                if let Some((center, vertices)) =
+                    polygon(configs.shape, x, y, configs.size, configs.rotation_deg)
+                {
+                    for (k, v) in vertices.iter().enumerate() {
+                        let next = vertices[(k + 1) % vertices.len()];
+                        fill_triangle(rl, center, *v, next, configs.color);
+                    }
+                }
             }
-            Shape::Rectangle => {
-                rl.draw_rectangle_v(
+        }
+    }
+
+    fn outline(&self, rl: &mut RaylibDrawHandle, configs: &PointConfig, color: Color) {
+        let (x, y) = (self.x, self.y);
+        let thick = configs.outline_thickness;
+        match configs.shape {
+            Shape::Circle => {
+                rl.draw_ring(
                     Vector2::new(x, y),
-                    Vector2::new(configs.size, configs.size),
-                    configs.color,
+                    (configs.size - thick).max(0.0),
+                    configs.size,
+                    0.0,
+                    360.0,
+                    if configs.smooth || configs.circle_segments > 0 {
+                        circle_segments(configs.size, configs.circle_segments)
+                    } else {
+                        0
+                    },
+                    color,
                 );
             }
+            Shape::Plus | Shape::Cross => {
+                let thick = (configs.size * STROKE_RATIO).max(1.0);
+                for (start, end) in strokes(configs.shape, x, y, configs.size, configs.rotation_deg)
+                {
+                    rl.draw_line_ex(start, end, thick, color);
+                }
+            }
+            Shape::Triangle | Shape::Rectangle | Shape::Diamond | Shape::Star => {
+                if let Some((_, vertices)) =
+                    polygon(configs.shape, x, y, configs.size, configs.rotation_deg)
+                {
+                    for (k, v) in vertices.iter().enumerate() {
+                        let next = vertices[(k + 1) % vertices.len()];
+                        rl.draw_line_ex(*v, next, thick, color);
+                    }
+                }
+            }
         }
     }
 }
+
+impl PlotElement for Screenpoint {
+    type Config = PointConfig;
+    fn plot(&self, rl: &mut raylib::prelude::RaylibDrawHandle, configs: &PointConfig) {
+        let at = self.offset_by(configs.offsets);
+        if configs.filled {
+            at.fill(rl, configs);
+            // Strokes have no interior to border.
+            let border = configs
+                .outline_color
+                .filter(|_| !matches!(configs.shape, Shape::Plus | Shape::Cross));
+            if let Some(border) = border {
+                at.outline(rl, configs, border);
+            }
+        } else {
+            at.outline(rl, configs, configs.outline_color.unwrap_or(configs.color));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_approx(a: Vector2, b: (f32, f32)) {
+        assert!(
+            (a.x - b.0).abs() < 1e-4 && (a.y - b.1).abs() < 1e-4,
+            "expected {b:?}, got ({}, {})",
+            a.x,
+            a.y
+        );
+    }
+
+    #[test]
+    fn rotation_is_clockwise_on_screen() {
+        let center = Vector2::new(10.0, 10.0);
+        // Right of the centre moves to below it (y grows downwards).
+        assert_approx(
+            rotate_about(Vector2::new(11.0, 10.0), center, 90.0),
+            (10.0, 11.0),
+        );
+        assert_approx(
+            rotate_about(Vector2::new(11.0, 10.0), center, 0.0),
+            (11.0, 10.0),
+        );
+    }
+
+    #[test]
+    fn rectangle_rotates_about_its_centre() {
+        let (center, vertices) = polygon(Shape::Rectangle, 0.0, 0.0, 2.0, 90.0).unwrap();
+        assert_approx(center, (1.0, 1.0));
+        // Top-left corner ends up at the top-right after a quarter turn.
+        assert_approx(vertices[0], (2.0, 0.0));
+        assert!(polygon(Shape::Circle, 0.0, 0.0, 2.0, 45.0).is_none());
+    }
+
+    #[test]
+    fn smooth_segments_follow_the_circumference() {
+        assert_eq!(smooth_segments(1.0), 16);
+        assert_eq!(smooth_segments(10.0), 32);
+        assert_eq!(smooth_segments(500.0), 128);
+        assert_eq!(smooth_segments(f32::NAN), 16);
+    }
+
+    #[test]
+    fn explicit_circle_segments_override_the_radius() {
+        assert_eq!(circle_segments(10.0, 0), 32);
+        assert_eq!(circle_segments(10.0, 48), 48);
+        assert_eq!(circle_segments(10.0, 1), 3);
+    }
+
+    #[test]
+    fn offset_leaves_the_point_in_place() {
+        let point = Screenpoint::new(5.0, 5.0);
+        assert_approx(*point.offset_by(Vector2::new(3.0, -2.0)), (8.0, 3.0));
+        assert_approx(*point, (5.0, 5.0));
+    }
+}
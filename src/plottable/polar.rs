@@ -0,0 +1,378 @@
+//! Polar coordinates: radial transforms, a polar grid, and polar scatter plots.
+//!
+//! Polar data uses the [`Datapoint`] `x` component as the angle `theta` (in
+//! radians, counter-clockwise from the positive x direction) and the `y`
+//! component as the radius `r`.
+//!
+//! * [`PolarTransformer`] : maps `(theta, r)` to screen space around a
+//!   center, and back.
+//! * [`PolarAxis`] : concentric rings at "nice" radii plus radial spokes,
+//!   with radius and angle labels.
+//! * [`PolarScatter`] : a [`ScatterPlot`] whose points are placed with a
+//!   polar transform. It accepts the regular [`ScatterPlotConfig`].
+//!
+//! Polar elements report a square data box `[-r, r] x [-r, r]` centred on
+//! the origin, so they compose with the rest of the library: inside a
+//! [`Graph`](crate::graph::Graph) the pole lands at the data origin and the
+//! radius scale follows the view. Pair them with
+//! [`GraphBuilder::equal_aspect`](crate::graph::GraphBuilder::equal_aspect)
+//! to keep the plotting area square.
+//!
+//! # Example
+//!
+//! ```rust
+//! use locus::prelude::*;
+//! # let data = Dataset::new(vec![(0.0, 1.0), (1.5, 2.0), (3.0, 0.5)]);
+//!
+//! let layers = Layers::new()
+//!     .with_layer(PolarAxis::fitting(2.0), PolarAxisConfig::default())
+//!     .with_layer(PolarScatter::new(&data), ScatterPlotConfig::default());
+//! let graph = Graph::new(layers);
+//! let config: GraphConfig<Layers> = GraphBuilder::default()
+//!     .equal_aspect(true)
+//!     .build()
+//!     .unwrap();
+//! ```
+
+use std::f32::consts::TAU;
+
+use derive_builder::Builder;
+use raylib::prelude::*;
+
+use crate::{
+    TextLabel,
+    colorscheme::Themable,
+    dataset::Dataset,
+    plottable::{
+        common::linear_spacing,
+        line::Separation,
+        point::{Datapoint, Screenpoint, circle_segments},
+        scatter::{ScatterPlot, ScatterPlotConfig},
+        text::{Anchor, TextStyle},
+        ticks::{Scale, TickSet, TickSpec},
+        view::{DataBBox, ViewTransformer, Viewport},
+    },
+    plotter::{ChartElement, PlotElement},
+};
+
+/// Maps polar `(theta, r)` data to screen coordinates around a center.
+///
+/// Angles grow counter-clockwise on screen, starting from the positive x
+/// direction.
+#[derive(Debug, Clone, Copy)]
+pub struct PolarTransformer {
+    /// Screen position of the pole (`r = 0`).
+    pub center: Screenpoint,
+    /// Pixels per radial data unit.
+    pub scale: f32,
+}
+
+impl PolarTransformer {
+    /// Create a transformer with an explicit pole and radial scale.
+    #[must_use]
+    pub fn new(center: impl Into<Screenpoint>, scale: f32) -> Self {
+        Self {
+            center: center.into(),
+            scale,
+        }
+    }
+
+    /// Center the pole in `viewport`'s inner area and scale so that radius
+    /// `r_max` touches its nearest edge.
+    #[must_use]
+    pub fn fitting(viewport: &Viewport, r_max: f32) -> Self {
+        let inner = viewport.inner_bbox();
+        let center = Screenpoint::new(
+            (inner.minimum.x + inner.maximum.x) * 0.5,
+            (inner.minimum.y + inner.maximum.y) * 0.5,
+        );
+        let radius = inner.width().min(inner.height()) * 0.5;
+        let scale = if r_max > 0.0 { radius / r_max } else { 0.0 };
+        Self { center, scale }
+    }
+
+    /// Place the pole at the view's data origin, using the smaller of the
+    /// two axis scales so circles stay round inside the plotting area.
+    #[must_use]
+    pub fn from_view(view: &ViewTransformer) -> Self {
+        let inner = view.screen_bounds.inner_bbox();
+        let data = view.data_bounds;
+        let axis_scale = |pixels: f32, units: f32| {
+            if units > 0.0 {
+                pixels / units
+            } else {
+                f32::INFINITY
+            }
+        };
+        let scale =
+            axis_scale(inner.width(), data.width()).min(axis_scale(inner.height(), data.height()));
+        let scale = if scale.is_finite() { scale } else { 0.0 };
+        Self {
+            center: view.to_screen(&Datapoint::new(0.0, 0.0)),
+            scale,
+        }
+    }
+
+    /// Project a polar point (`x` = theta in radians, `y` = r) to screen
+    /// space.
+    #[must_use]
+    pub fn to_screen(&self, point: &Datapoint) -> Screenpoint {
+        let (theta, r) = (point.x, point.y);
+        let radius = r * self.scale;
+        // Screen y grows downward, so counter-clockwise means subtracting.
+        Screenpoint::new(
+            self.center.x + radius * theta.cos(),
+            self.center.y - radius * theta.sin(),
+        )
+    }
+
+    /// Project a screen point back to polar `(theta, r)`, with theta in
+    /// `[0, 2π)`.
+    ///
+    /// This is the inverse of [`to_screen`](PolarTransformer::to_screen) for
+    /// non-negative radii.
+    #[must_use]
+    pub fn to_data(&self, point: &Screenpoint) -> Datapoint {
+        let dx = point.x - self.center.x;
+        let dy = self.center.y - point.y;
+        let r = if self.scale > 0.0 {
+            dx.hypot(dy) / self.scale
+        } else {
+            0.0
+        };
+        Datapoint::new(dy.atan2(dx).rem_euclid(TAU), r)
+    }
+}
+
+/// Square data box `[-r, r] x [-r, r]` covering a polar plot of radius `r`.
+fn polar_bounds(r: f32) -> DataBBox {
+    let r = r.abs();
+    DataBBox::from_min_max((-r, -r), (r, r))
+}
+
+/// Concentric rings and radial spokes forming a polar grid.
+///
+/// Ring radii come from the linear tick generator, so they land on the same
+/// "nice" values a Cartesian axis would use.
+#[derive(Debug, Clone, Copy)]
+pub struct PolarAxis {
+    /// Outermost radius, in data units.
+    pub r_max: f32,
+}
+
+impl PolarAxis {
+    /// A polar axis whose outer ring sits exactly at `r_max`.
+    #[must_use]
+    pub fn new(r_max: f32) -> Self {
+        Self { r_max: r_max.abs() }
+    }
+
+    /// A polar axis whose outer radius is `r_max` rounded up to a "nice"
+    /// value.
+    ///
+    /// ```rust
+    /// use locus::prelude::*;
+    /// let axis = PolarAxis::fitting(7.3);
+    /// assert!(axis.r_max >= 7.3);
+    /// ```
+    #[must_use]
+    pub fn fitting(r_max: f32) -> Self {
+        let (_, max, _) = linear_spacing(0.0, r_max.abs(), PolarAxisConfig::default().max_rings);
+        Self { r_max: max }
+    }
+}
+
+/// Configuration for [`PolarAxis`] rendering.
+///
+/// When `color` is `None` it is resolved from
+/// [`Colorscheme::grid`](crate::colorscheme::Colorscheme::grid), and the
+/// label style from
+/// [`Colorscheme::text`](crate::colorscheme::Colorscheme::text).
+#[derive(Debug, Clone, Builder)]
+#[builder(pattern = "owned")]
+#[builder(default)]
+pub struct PolarAxisConfig {
+    /// Explicit grid color. `None` means "use theme grid color".
+    #[builder(setter(strip_option, into))]
+    pub color: Option<Color>,
+    /// Alpha multiplier applied on top of the color's own alpha.
+    pub alpha: f32,
+    /// Ring and spoke thickness in pixels.
+    pub thickness: f32,
+    /// Maximum number of rings (used by the auto-spacing algorithm).
+    pub max_rings: usize,
+    /// Number of evenly spaced radial spokes. `0` draws none.
+    pub spokes: usize,
+    /// Whether to draw radius and angle labels.
+    pub show_labels: bool,
+    /// Text style applied to labels.
+    pub label_style: TextStyle,
+    /// Gap in pixels between the outer ring and the angle labels.
+    pub label_offset: f32,
+    /// Segments of each ring. `0`, the default, picks a count from the
+    /// ring's radius; 32 to 128 keeps large rings round.
+    pub circle_segments: u32,
+}
+
+impl Default for PolarAxisConfig {
+    fn default() -> Self {
+        Self {
+            color: None,
+            alpha: 0.5,
+            thickness: 1.0,
+            max_rings: 5,
+            spokes: 12,
+            show_labels: true,
+            label_style: TextStyle {
+                font_size: 14.0,
+                ..Default::default()
+            },
+            label_offset: 12.0,
+            circle_segments: 0,
+        }
+    }
+}
+
+impl ChartElement for PolarAxis {
+    type Config = PolarAxisConfig;
+
+    #[allow(clippy::cast_precision_loss)]
+    fn draw_in_view(
+        &self,
+        rl: &mut RaylibDrawHandle,
+        configs: &Self::Config,
+        view: &ViewTransformer,
+    ) {
+        let polar = PolarTransformer::from_view(view);
+        let color = configs.color.unwrap_or(Color::BLACK).alpha(configs.alpha);
+        let half = configs.thickness * 0.5;
+
+        let rings = TickSet::generate_ticks(
+            0.0,
+            self.r_max,
+            TickSpec {
+                scale: Scale::Linear,
+                max_ticks: configs.max_rings,
+                separation: Separation::Auto,
+            },
+        );
+        for tick in &rings.ticks {
+            if tick.value <= 0.0 || tick.value > self.r_max + f32::EPSILON {
+                continue;
+            }
+            let radius = tick.value * polar.scale;
+            rl.draw_ring(
+                *polar.center,
+                (radius - half).max(0.0),
+                radius + half,
+                0.0,
+                360.0,
+                circle_segments(radius, configs.circle_segments),
+                color,
+            );
+            if configs.show_labels {
+                // Radius labels sit just below the theta = 0 spoke.
+                let origin = polar.to_screen(&Datapoint::new(0.0, tick.value));
+                let style = TextStyle {
+                    anchor: Anchor::TOP_CENTER,
+                    ..configs.label_style.clone()
+                };
+                TextLabel::new(tick.label.clone(), origin).plot(rl, &style);
+            }
+        }
+
+        for i in 0..configs.spokes {
+            let theta = TAU * i as f32 / configs.spokes as f32;
+            let end = polar.to_screen(&Datapoint::new(theta, self.r_max));
+            rl.draw_line_ex(*polar.center, *end, configs.thickness, color);
+            if configs.show_labels {
+                let reach = self.r_max + configs.label_offset / polar.scale.max(f32::EPSILON);
+                let origin = polar.to_screen(&Datapoint::new(theta, reach));
+                let degrees = (theta.to_degrees()).round();
+                TextLabel::new(format!("{degrees}°"), origin).plot(rl, &configs.label_style);
+            }
+        }
+    }
+
+    fn data_bounds(&self) -> DataBBox {
+        polar_bounds(self.r_max)
+    }
+}
+
+impl Themable for PolarAxisConfig {
+    fn apply_theme(&mut self, scheme: &crate::colorscheme::Colorscheme) {
+        if self.color.is_none() {
+            self.color = Some(scheme.grid);
+        }
+        self.label_style.apply_theme(scheme);
+    }
+}
+
+/// A scatter plot of polar `(theta, r)` data.
+///
+/// Reuses [`ScatterPlot`]'s rendering, so every [`ScatterPlotConfig`]
+/// option (dynamic size, color, shape, labels, ...) applies. Dynamic
+/// strategies receive the original polar datapoint.
+#[derive(Debug, Clone, Copy)]
+pub struct PolarScatter<'a> {
+    /// Reference to the polar dataset being visualized.
+    pub data: &'a Dataset,
+}
+
+impl<'a> PolarScatter<'a> {
+    /// Create a polar scatter plot over the given dataset.
+    #[must_use]
+    pub fn new(data: &'a Dataset) -> Self {
+        Self { data }
+    }
+}
+
+impl ChartElement for PolarScatter<'_> {
+    type Config = ScatterPlotConfig;
+
+    fn draw_in_view(
+        &self,
+        rl: &mut RaylibDrawHandle,
+        configs: &Self::Config,
+        view: &ViewTransformer,
+    ) {
+        let polar = PolarTransformer::from_view(view);
+        ScatterPlot::new(self.data).draw_projected(rl, configs, view, |p| polar.to_screen(p));
+    }
+
+    /// The square box enclosing the largest absolute radius.
+    fn data_bounds(&self) -> DataBBox {
+        polar_bounds(self.data.range_min.y.abs().max(self.data.range_max.y.abs()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::FRAC_PI_2;
+
+    use super::*;
+
+    fn close(a: f32, b: f32) -> bool {
+        (a - b).abs() < 1e-3
+    }
+
+    #[test]
+    fn polar_round_trip() {
+        let polar = PolarTransformer::new(Screenpoint::new(100.0, 100.0), 10.0);
+        let up = polar.to_screen(&Datapoint::new(FRAC_PI_2, 2.0));
+        assert!(close(up.x, 100.0) && close(up.y, 80.0));
+
+        let back = polar.to_data(&Screenpoint::new(70.0, 100.0));
+        assert!(close(back.x, std::f32::consts::PI));
+        assert!(close(back.y, 3.0));
+    }
+
+    #[test]
+    fn from_view_centers_on_origin() {
+        let view = ViewTransformer::new(polar_bounds(5.0), Viewport::new(0.0, 0.0, 200.0, 100.0));
+        let polar = PolarTransformer::from_view(&view);
+        assert!(close(polar.center.x, 100.0) && close(polar.center.y, 50.0));
+        // Height is the limiting side: 100 px over 10 units.
+        assert!(close(polar.scale, 10.0));
+    }
+}
@@ -0,0 +1,391 @@
+//! Radar (spider) charts for comparing several variables at once.
+//!
+//! A [`RadarChart`] places one spoke per variable at equal angles around a
+//! center, starting at the top and going clockwise. Each [`RadarSeries`]
+//! becomes a closed polygon whose vertex on spoke `k` sits at the series'
+//! `k`-th value, scaled so that the spoke's maximum (see [`RadarMax`])
+//! touches the outer ring.
+//!
+//! Like [`PieChart`](crate::plottable::pie::PieChart) it is a screen-space
+//! [`PlotElement`] placed with a center point. The grid (rings and spokes)
+//! and the spoke labels are drawn first, then the series in order.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use locus::prelude::*;
+//! # let (mut rl, thread) = raylib::init().build();
+//! let radar = RadarChart::new(
+//!     (400.0, 300.0),
+//!     vec![
+//!         RadarSeries::new("model a", [0.9, 0.7, 0.4, 0.8, 0.6]),
+//!         RadarSeries::new("model b", [0.6, 0.8, 0.9, 0.5, 0.7]),
+//!     ],
+//! );
+//! let mut config = RadarConfigBuilder::default()
+//!     .labels(vec!["speed".into(), "accuracy".into(), "memory".into(), "cost".into(), "recall".into()])
+//!     .max(RadarMax::Shared(1.0))
+//!     .build()
+//!     .unwrap();
+//! config.apply_theme(&NORD);
+//! let mut d = rl.begin_drawing(&thread);
+//! radar.plot(&mut d, &config);
+//! ```
+
+use std::f32::consts::{FRAC_PI_2, TAU};
+
+use derive_builder::Builder;
+use raylib::prelude::*;
+
+use crate::{
+    colorscheme::{Colorscheme, Themable, indexed_color},
+    plottable::{
+        legend::LegendEntry,
+        point::{Datapoint, Screenpoint, Shape, fill_triangle},
+        polar::PolarTransformer,
+        text::{Anchor, HAlign, TextLabel, TextStyle, TextStyleBuilder, VAlign},
+    },
+    plotter::PlotElement,
+};
+
+/// One named polygon of a [`RadarChart`], with a value per spoke.
+#[derive(Debug, Clone)]
+pub struct RadarSeries {
+    /// Series name, used for legend entries.
+    pub name: String,
+    /// One value per spoke. Missing trailing values are drawn at the center.
+    pub values: Vec<f32>,
+    /// Explicit color. `None` picks the theme cycle color by index.
+    pub color: Option<Color>,
+    /// Explicit fill alpha. `None` uses [`RadarConfig::fill_alpha`].
+    pub fill_alpha: Option<f32>,
+}
+
+impl RadarSeries {
+    /// Create a series named `name` from its per-spoke values.
+    #[must_use]
+    pub fn new(name: impl Into<String>, values: impl IntoIterator<Item = f32>) -> Self {
+        Self {
+            name: name.into(),
+            values: values.into_iter().collect(),
+            color: None,
+            fill_alpha: None,
+        }
+    }
+
+    /// Give the series a fixed color instead of a theme cycle color.
+    #[must_use]
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Give the series its own fill alpha.
+    #[must_use]
+    pub fn with_fill_alpha(mut self, alpha: f32) -> Self {
+        self.fill_alpha = Some(alpha);
+        self
+    }
+}
+
+/// The value that reaches the outer ring on each spoke.
+#[derive(Debug, Clone, Default)]
+pub enum RadarMax {
+    /// The largest value of any series on any spoke, shared by all spokes.
+    #[default]
+    Auto,
+    /// The same maximum on every spoke.
+    Shared(f32),
+    /// One maximum per spoke, for variables with different units. Spokes
+    /// past the end of the list fall back to [`Auto`](RadarMax::Auto).
+    PerAxis(Vec<f32>),
+}
+
+/// Several series drawn as polygons over equiangular spokes.
+///
+/// See the [module documentation](self) for an example.
+#[derive(Debug, Clone)]
+pub struct RadarChart {
+    /// Screen position of the center.
+    pub center: Screenpoint,
+    /// The series, drawn in order.
+    pub series: Vec<RadarSeries>,
+}
+
+impl RadarChart {
+    /// Create a radar chart centred on `center`.
+    #[must_use]
+    pub fn new(center: impl Into<Screenpoint>, series: Vec<RadarSeries>) -> Self {
+        Self {
+            center: center.into(),
+            series,
+        }
+    }
+
+    /// One legend entry per series, colored like its polygon.
+    #[must_use]
+    pub fn legend_entries(&self, configs: &RadarConfig, scheme: &Colorscheme) -> Vec<LegendEntry> {
+        self.series
+            .iter()
+            .enumerate()
+            .map(|(i, s)| {
+                LegendEntry::new(s.name.clone(), configs.series_color(i, s, Some(scheme)))
+                    .with_shape(Shape::Rectangle)
+            })
+            .collect()
+    }
+
+    /// Number of spokes: one per label or per value, whichever is more.
+    fn spoke_count(&self, configs: &RadarConfig) -> usize {
+        self.series
+            .iter()
+            .map(|s| s.values.len())
+            .chain(std::iter::once(configs.labels.len()))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Value reaching the outer ring on each of `spokes` spokes.
+    fn maxima(&self, configs: &RadarConfig, spokes: usize) -> Vec<f32> {
+        let auto = self
+            .series
+            .iter()
+            .flat_map(|s| s.values.iter().copied())
+            .filter(|v| v.is_finite())
+            .fold(0.0_f32, f32::max);
+        (0..spokes)
+            .map(|k| match &configs.max {
+                RadarMax::Shared(max) => *max,
+                RadarMax::PerAxis(maxima) => maxima.get(k).copied().unwrap_or(auto),
+                RadarMax::Auto => auto,
+            })
+            .collect()
+    }
+}
+
+/// Angle of spoke `k` out of `spokes`, in radians counter-clockwise from
+/// the positive x direction: the first spoke points up, the rest follow
+/// clockwise.
+#[allow(clippy::cast_precision_loss)]
+fn spoke_angle(k: usize, spokes: usize) -> f32 {
+    FRAC_PI_2 - TAU * k as f32 / spokes as f32
+}
+
+/// Radius of each vertex as a fraction of the outer ring, clamped to
+/// `0.0..=1.0`. Missing, non-finite, and negative values sit at the center,
+/// as do all values on a spoke with a non-positive maximum.
+fn vertex_fractions(values: &[f32], maxima: &[f32]) -> Vec<f32> {
+    maxima
+        .iter()
+        .enumerate()
+        .map(|(k, max)| match values.get(k) {
+            Some(v) if v.is_finite() && *max > 0.0 => (v / max).clamp(0.0, 1.0),
+            _ => 0.0,
+        })
+        .collect()
+}
+
+/// Configuration for [`RadarChart`] rendering.
+///
+/// When `colors` is `None` it is resolved from the
+/// [`Colorscheme`](crate::colorscheme::Colorscheme) cycle during theme
+/// application; a series' own color always takes precedence. An unset
+/// `grid_color` resolves to the scheme's grid color.
+#[derive(Debug, Clone, Builder)]
+#[builder(pattern = "owned")]
+#[builder(default)]
+pub struct RadarConfig {
+    /// Spoke labels, in spoke order starting at the top.
+    pub labels: Vec<String>,
+    /// The value reaching the outer ring on each spoke.
+    pub max: RadarMax,
+    /// Radius of the outer ring in pixels.
+    pub radius: f32,
+    /// Number of concentric grid rings, including the outer one.
+    pub rings: usize,
+    /// Explicit grid (ring and spoke) color. `None` means "use the theme
+    /// grid color".
+    #[builder(setter(into, strip_option))]
+    pub grid_color: Option<Color>,
+    /// Per-series colors, by index. `None` means "use the theme cycle".
+    #[builder(setter(into, strip_option))]
+    pub colors: Option<Vec<Color>>,
+    /// Polygon outline thickness in pixels.
+    pub thickness: f32,
+    /// Whether to fill the series polygons.
+    pub fill: bool,
+    /// Alpha of the polygon fill, unless the series sets its own.
+    pub fill_alpha: f32,
+    /// Gap in pixels between the outer ring and the spoke labels.
+    pub label_offset: f32,
+    /// Style of the spoke labels. The anchor is chosen per spoke so labels
+    /// grow away from the chart.
+    pub label_style: TextStyle,
+}
+
+impl Default for RadarConfig {
+    fn default() -> Self {
+        Self {
+            labels: Vec::new(),
+            max: RadarMax::Auto,
+            radius: 150.0,
+            rings: 4,
+            grid_color: None,
+            colors: None,
+            thickness: 2.0,
+            fill: true,
+            fill_alpha: 0.25,
+            label_offset: 8.0,
+            label_style: TextStyleBuilder::default()
+                .font_size(14.0)
+                .build()
+                .expect("Will never fail"),
+        }
+    }
+}
+
+impl RadarConfig {
+    /// Color of the series at `index`: its own color, else the configured
+    /// colors, else `scheme`'s cycle.
+    fn series_color(
+        &self,
+        index: usize,
+        series: &RadarSeries,
+        scheme: Option<&Colorscheme>,
+    ) -> Color {
+        if let Some(color) = series.color {
+            return color;
+        }
+        indexed_color(self.colors.as_deref(), scheme, index)
+    }
+}
+
+/// Anchor that keeps a label at `theta` on the outside of the chart.
+fn outward_anchor(theta: f32) -> Anchor {
+    let (sin, cos) = theta.sin_cos();
+    let h = if cos > 0.1 {
+        HAlign::Left
+    } else if cos < -0.1 {
+        HAlign::Right
+    } else {
+        HAlign::Center
+    };
+    let v = if sin > 0.1 {
+        VAlign::Bottom
+    } else if sin < -0.1 {
+        VAlign::Top
+    } else {
+        VAlign::Middle
+    };
+    Anchor { h, v }
+}
+
+impl PlotElement for RadarChart {
+    type Config = RadarConfig;
+
+    #[allow(clippy::cast_precision_loss)]
+    fn plot(&self, rl: &mut RaylibDrawHandle, configs: &Self::Config) {
+        let spokes = self.spoke_count(configs);
+        if spokes < 3 {
+            return;
+        }
+        let polar = PolarTransformer::new(self.center, configs.radius);
+        let vertex =
+            |k: usize, r: f32| *polar.to_screen(&Datapoint::new(spoke_angle(k, spokes), r));
+
+        // Grid: rings as polygons through the spokes, then the spokes.
+        let grid = configs.grid_color.unwrap_or(Color::LIGHTGRAY);
+        for ring in 1..=configs.rings {
+            let r = ring as f32 / configs.rings as f32;
+            for k in 0..spokes {
+                rl.draw_line_ex(vertex(k, r), vertex((k + 1) % spokes, r), 1.0, grid);
+            }
+        }
+        for k in 0..spokes {
+            rl.draw_line_ex(*self.center, vertex(k, 1.0), 1.0, grid);
+        }
+        for (k, label) in configs.labels.iter().enumerate().take(spokes) {
+            let theta = spoke_angle(k, spokes);
+            let r = 1.0 + configs.label_offset / configs.radius.max(f32::EPSILON);
+            let mut style = configs.label_style.clone();
+            style.anchor = outward_anchor(theta);
+            TextLabel::new(label.clone(), polar.to_screen(&Datapoint::new(theta, r)))
+                .plot(rl, &style);
+        }
+
+        let maxima = self.maxima(configs, spokes);
+        for (i, series) in self.series.iter().enumerate() {
+            let color = configs.series_color(i, series, None);
+            let points: Vec<Vector2> = vertex_fractions(&series.values, &maxima)
+                .into_iter()
+                .enumerate()
+                .map(|(k, r)| vertex(k, r))
+                .collect();
+            if configs.fill {
+                // Every vertex lies on its own spoke, so the polygon is
+                // star-shaped around the center and a fan fills it exactly.
+                let fill = color.alpha(series.fill_alpha.unwrap_or(configs.fill_alpha));
+                for (a, b) in points.iter().zip(points.iter().cycle().skip(1)) {
+                    fill_triangle(rl, *self.center, *a, *b, fill);
+                }
+            }
+            for (a, b) in points.iter().zip(points.iter().cycle().skip(1)) {
+                rl.draw_line_ex(*a, *b, configs.thickness, color);
+            }
+        }
+    }
+}
+
+impl Themable for RadarConfig {
+    fn apply_theme(&mut self, scheme: &Colorscheme) {
+        if self.colors.is_none() && !scheme.cycle.is_empty() {
+            self.colors = Some(scheme.cycle.to_vec());
+        }
+        if self.grid_color.is_none() {
+            self.grid_color = Some(scheme.grid);
+        }
+        self.label_style.apply_theme(scheme);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vertices_scale_by_spoke_maximum() {
+        let chart = RadarChart::new(
+            (0.0, 0.0),
+            vec![
+                RadarSeries::new("a", [5.0, 20.0, -1.0]),
+                RadarSeries::new("b", [10.0, f32::NAN]),
+            ],
+        );
+        let config = RadarConfigBuilder::default()
+            .labels(vec!["x".into(), "y".into(), "z".into(), "w".into()])
+            .max(RadarMax::PerAxis(vec![10.0, 40.0]))
+            .build()
+            .unwrap();
+        let spokes = chart.spoke_count(&config);
+        assert_eq!(spokes, 4);
+        let maxima = chart.maxima(&config, spokes);
+        assert_eq!(maxima, vec![10.0, 40.0, 20.0, 20.0]);
+        assert_eq!(
+            vertex_fractions(&chart.series[0].values, &maxima),
+            vec![0.5, 0.5, 0.0, 0.0]
+        );
+        assert_eq!(
+            vertex_fractions(&chart.series[1].values, &maxima),
+            vec![1.0, 0.0, 0.0, 0.0]
+        );
+    }
+
+    #[test]
+    fn spokes_start_at_the_top_and_run_clockwise() {
+        let polar = PolarTransformer::new((0.0, 0.0), 1.0);
+        let top = polar.to_screen(&Datapoint::new(spoke_angle(0, 4), 1.0));
+        let right = polar.to_screen(&Datapoint::new(spoke_angle(1, 4), 1.0));
+        assert!(top.x.abs() < 1e-5 && (top.y + 1.0).abs() < 1e-5);
+        assert!((right.x - 1.0).abs() < 1e-5 && right.y.abs() < 1e-5);
+    }
+}
@@ -0,0 +1,467 @@
+//! Reference lines and shaded regions spanning the plotting area.
+//!
+//! [`HLine`] and [`VLine`] mark a single data coordinate with a line across
+//! the full width or height of the view — typical uses are a zero line or a
+//! threshold marker. Both are styled with a [`ReferenceLineConfig`].
+//!
+//! [`VSpan`], [`HSpan`], and [`RectRegion`] highlight an interval or a
+//! rectangle with a translucent fill, styled with a [`RegionConfig`]. The
+//! [`Region`] enum lets a graph hold any of the three.
+//!
+//! They are usually added through
+//! [`GraphBuilder::hline`](crate::graph::GraphBuilder::hline),
+//! [`GraphBuilder::vline`](crate::graph::GraphBuilder::vline), and
+//! [`GraphBuilder::shade`](crate::graph::GraphBuilder::shade). Lines are
+//! drawn over the data and regions behind it, both clipped to the plotting
+//! area.
+//!
+//! # Example
+//!
+//! ```rust
+//! use locus::prelude::*;
+//! # let mut builder: GraphBuilder<ScatterPlot> = GraphBuilder::default();
+//!
+//! builder
+//!     .hline(0.0)
+//!     .vline_styled(2.5, |c| c.dash = Some((6.0, 4.0)))
+//!     .shade(VSpan { x0: 1.0, x1: 2.0 });
+//! ```
+
+use derive_builder::Builder;
+use raylib::{
+    color::Color,
+    math::Rectangle,
+    prelude::{RaylibDraw, RaylibDrawHandle},
+};
+
+use crate::{
+    colorscheme::Themable,
+    plottable::{
+        line::draw_dashed_line,
+        point::Datapoint,
+        view::{DataBBox, ViewTransformer},
+    },
+    plotter::ChartElement,
+};
+
+/// A horizontal line across the plot at data coordinate `y`.
+#[derive(Debug, Clone, Copy)]
+pub struct HLine(pub f32);
+
+/// A vertical line across the plot at data coordinate `x`.
+#[derive(Debug, Clone, Copy)]
+pub struct VLine(pub f32);
+
+/// Configuration shared by [`HLine`] and [`VLine`].
+///
+/// When `color` is `None` it is resolved from
+/// [`Colorscheme::axis`](crate::colorscheme::Colorscheme::axis) during theme
+/// application.
+#[derive(Debug, Clone, Copy, Builder)]
+#[builder(pattern = "owned")]
+#[builder(default)]
+pub struct ReferenceLineConfig {
+    /// Line width in pixels.
+    pub thickness: f32,
+    /// Explicit color. `None` means "use the theme axis color".
+    #[builder(setter(into, strip_option))]
+    pub color: Option<Color>,
+    /// Optional dash pattern as `(dash, gap)` lengths in pixels. `None`
+    /// draws a solid line.
+    #[builder(setter(into, strip_option))]
+    pub dash: Option<(f32, f32)>,
+}
+
+impl Default for ReferenceLineConfig {
+    fn default() -> Self {
+        Self {
+            thickness: 1.5,
+            color: None,
+            dash: None,
+        }
+    }
+}
+
+impl ReferenceLineConfig {
+    fn draw(
+        &self,
+        rl: &mut RaylibDrawHandle,
+        from: Datapoint,
+        to: Datapoint,
+        view: &ViewTransformer,
+    ) {
+        let (start, end) = (view.to_screen(&from), view.to_screen(&to));
+        let color = self.color.unwrap_or(Color::BLACK);
+        match self.dash {
+            Some(dash) => draw_dashed_line(rl, *start, *end, self.thickness, dash, color),
+            None => rl.draw_line_ex(*start, *end, self.thickness, color),
+        }
+    }
+}
+
+impl ChartElement for HLine {
+    type Config = ReferenceLineConfig;
+
+    fn draw_in_view(
+        &self,
+        rl: &mut RaylibDrawHandle,
+        configs: &Self::Config,
+        view: &ViewTransformer,
+    ) {
+        let bounds = view.data_bounds;
+        configs.draw(
+            rl,
+            Datapoint::new(bounds.minimum.x, self.0),
+            Datapoint::new(bounds.maximum.x, self.0),
+            view,
+        );
+    }
+
+    /// The line's `y`, with an empty x axis: it adapts to whatever x range
+    /// is shown, so in a union it leaves the x range to the other elements.
+    fn data_bounds(&self) -> DataBBox {
+        DataBBox::spanning_y(self.0, self.0)
+    }
+}
+
+impl ChartElement for VLine {
+    type Config = ReferenceLineConfig;
+
+    fn draw_in_view(
+        &self,
+        rl: &mut RaylibDrawHandle,
+        configs: &Self::Config,
+        view: &ViewTransformer,
+    ) {
+        let bounds = view.data_bounds;
+        configs.draw(
+            rl,
+            Datapoint::new(self.0, bounds.minimum.y),
+            Datapoint::new(self.0, bounds.maximum.y),
+            view,
+        );
+    }
+
+    /// The line's `x`, with an empty y axis: it adapts to whatever y range
+    /// is shown, so in a union it leaves the y range to the other elements.
+    fn data_bounds(&self) -> DataBBox {
+        DataBBox::spanning_x(self.0, self.0)
+    }
+}
+
+impl Themable for ReferenceLineConfig {
+    fn apply_theme(&mut self, scheme: &crate::colorscheme::Colorscheme) {
+        if self.color.is_none() {
+            self.color = Some(scheme.axis);
+        }
+    }
+}
+
+/// A vertical band between data x coordinates `x0` and `x1`, spanning the
+/// full height of the plot.
+#[derive(Debug, Clone, Copy)]
+pub struct VSpan {
+    pub x0: f32,
+    pub x1: f32,
+}
+
+/// A horizontal band between data y coordinates `y0` and `y1`, spanning the
+/// full width of the plot.
+#[derive(Debug, Clone, Copy)]
+pub struct HSpan {
+    pub y0: f32,
+    pub y1: f32,
+}
+
+/// A rectangle in data space.
+#[derive(Debug, Clone, Copy)]
+pub struct RectRegion {
+    pub data_bbox: DataBBox,
+}
+
+/// Any shaded region, so graphs can store spans and rectangles together.
+#[derive(Debug, Clone, Copy)]
+pub enum Region {
+    Vertical(VSpan),
+    Horizontal(HSpan),
+    Rect(RectRegion),
+}
+
+impl From<VSpan> for Region {
+    fn from(value: VSpan) -> Self {
+        Self::Vertical(value)
+    }
+}
+
+impl From<HSpan> for Region {
+    fn from(value: HSpan) -> Self {
+        Self::Horizontal(value)
+    }
+}
+
+impl From<RectRegion> for Region {
+    fn from(value: RectRegion) -> Self {
+        Self::Rect(value)
+    }
+}
+
+/// Configuration for shaded regions.
+///
+/// When `fill_color` is `None` it is resolved from the first color of the
+/// [`Colorscheme`](crate::colorscheme::Colorscheme) cycle during theme
+/// application.
+#[derive(Debug, Clone, Copy, Builder)]
+#[builder(pattern = "owned")]
+#[builder(default)]
+pub struct RegionConfig {
+    /// Explicit fill color. `None` means "use the first theme cycle color".
+    #[builder(setter(into, strip_option))]
+    pub fill_color: Option<Color>,
+    /// Alpha applied to the fill color.
+    pub fill_alpha: f32,
+    /// Optional border as `(color, thickness)`. `None` means no border.
+    #[builder(setter(into, strip_option))]
+    pub border: Option<(Color, f32)>,
+}
+
+impl Default for RegionConfig {
+    fn default() -> Self {
+        Self {
+            fill_color: None,
+            fill_alpha: 0.2,
+            border: None,
+        }
+    }
+}
+
+impl RegionConfig {
+    /// Fill (and outline) the screen rectangle spanned by two data corners.
+    fn draw(&self, rl: &mut RaylibDrawHandle, a: Datapoint, b: Datapoint, view: &ViewTransformer) {
+        let (a, b) = (view.to_screen(&a), view.to_screen(&b));
+        let rect = Rectangle::new(
+            a.x.min(b.x),
+            a.y.min(b.y),
+            (a.x - b.x).abs(),
+            (a.y - b.y).abs(),
+        );
+        let fill = self
+            .fill_color
+            .unwrap_or(Color::BLACK)
+            .alpha(self.fill_alpha);
+        rl.draw_rectangle_rec(rect, fill);
+        if let Some((color, thickness)) = self.border {
+            rl.draw_rectangle_lines_ex(rect, thickness, color);
+        }
+    }
+}
+
+impl ChartElement for VSpan {
+    type Config = RegionConfig;
+
+    fn draw_in_view(
+        &self,
+        rl: &mut RaylibDrawHandle,
+        configs: &Self::Config,
+        view: &ViewTransformer,
+    ) {
+        let bounds = view.data_bounds;
+        configs.draw(
+            rl,
+            Datapoint::new(self.x0, bounds.minimum.y),
+            Datapoint::new(self.x1, bounds.maximum.y),
+            view,
+        );
+    }
+
+    /// `x0..x1`, with an empty y axis: the band spans whatever y range is
+    /// shown, so in a union it leaves the y range to the other elements.
+    fn data_bounds(&self) -> DataBBox {
+        DataBBox::spanning_x(self.x0, self.x1)
+    }
+}
+
+impl ChartElement for HSpan {
+    type Config = RegionConfig;
+
+    fn draw_in_view(
+        &self,
+        rl: &mut RaylibDrawHandle,
+        configs: &Self::Config,
+        view: &ViewTransformer,
+    ) {
+        let bounds = view.data_bounds;
+        configs.draw(
+            rl,
+            Datapoint::new(bounds.minimum.x, self.y0),
+            Datapoint::new(bounds.maximum.x, self.y1),
+            view,
+        );
+    }
+
+    /// `y0..y1`, with an empty x axis: the band spans whatever x range is
+    /// shown, so in a union it leaves the x range to the other elements.
+    fn data_bounds(&self) -> DataBBox {
+        DataBBox::spanning_y(self.y0, self.y1)
+    }
+}
+
+impl ChartElement for RectRegion {
+    type Config = RegionConfig;
+
+    fn draw_in_view(
+        &self,
+        rl: &mut RaylibDrawHandle,
+        configs: &Self::Config,
+        view: &ViewTransformer,
+    ) {
+        configs.draw(rl, self.data_bbox.minimum, self.data_bbox.maximum, view);
+    }
+
+    fn data_bounds(&self) -> DataBBox {
+        self.data_bbox
+    }
+}
+
+impl ChartElement for Region {
+    type Config = RegionConfig;
+
+    fn draw_in_view(
+        &self,
+        rl: &mut RaylibDrawHandle,
+        configs: &Self::Config,
+        view: &ViewTransformer,
+    ) {
+        match self {
+            Self::Vertical(span) => span.draw_in_view(rl, configs, view),
+            Self::Horizontal(span) => span.draw_in_view(rl, configs, view),
+            Self::Rect(rect) => rect.draw_in_view(rl, configs, view),
+        }
+    }
+
+    fn data_bounds(&self) -> DataBBox {
+        match self {
+            Self::Vertical(span) => span.data_bounds(),
+            Self::Horizontal(span) => span.data_bounds(),
+            Self::Rect(rect) => rect.data_bounds(),
+        }
+    }
+}
+
+impl Themable for RegionConfig {
+    fn apply_theme(&mut self, scheme: &crate::colorscheme::Colorscheme) {
+        if self.fill_color.is_none() {
+            self.fill_color = Some(scheme.cycle.first().copied().unwrap_or(scheme.grid));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::colorscheme::Colorscheme;
+
+    fn scheme() -> Colorscheme {
+        Colorscheme::new(
+            Color::WHITE,
+            Color::LIGHTGRAY,
+            Color::BLACK,
+            Color::DARKGRAY,
+            vec![Color::RED, Color::BLUE],
+        )
+    }
+
+    #[test]
+    fn reference_lines_only_bound_their_own_axis() {
+        let data = DataBBox::from_min_max((2.0, 3.0), (4.0, 5.0));
+        let with_hline = data.union(&HLine(10.0).data_bounds());
+        assert!((with_hline.minimum.x - 2.0).abs() < 1e-6);
+        assert!((with_hline.maximum.x - 4.0).abs() < 1e-6);
+        assert!((with_hline.minimum.y - 3.0).abs() < 1e-6);
+        assert!((with_hline.maximum.y - 10.0).abs() < 1e-6);
+
+        let with_vline = VLine(-1.0).data_bounds().union(&data);
+        assert!((with_vline.minimum.x + 1.0).abs() < 1e-6);
+        assert!((with_vline.maximum.x - 4.0).abs() < 1e-6);
+        assert!((with_vline.minimum.y - 3.0).abs() < 1e-6);
+        assert!((with_vline.maximum.y - 5.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn reference_line_config_defaults_to_a_solid_axis_colored_line() {
+        let mut config = ReferenceLineConfig::default();
+        assert!((config.thickness - 1.5).abs() < 1e-6);
+        assert!(config.dash.is_none());
+        assert!(config.color.is_none());
+        config.apply_theme(&scheme());
+        assert_eq!(config.color, Some(Color::DARKGRAY));
+
+        let mut explicit = ReferenceLineConfigBuilder::default()
+            .color(Color::GREEN)
+            .dash((4.0, 2.0))
+            .build()
+            .unwrap();
+        explicit.apply_theme(&scheme());
+        assert_eq!(explicit.color, Some(Color::GREEN));
+        assert_eq!(explicit.dash, Some((4.0, 2.0)));
+    }
+
+    #[test]
+    fn spans_only_bound_their_own_axis() {
+        let data = DataBBox::from_min_max((2.0, 3.0), (4.0, 5.0));
+        // Reversed ends are re-ordered.
+        let with_vspan = data.union(&Region::from(VSpan { x0: 6.0, x1: 1.0 }).data_bounds());
+        assert!((with_vspan.minimum.x - 1.0).abs() < 1e-6);
+        assert!((with_vspan.maximum.x - 6.0).abs() < 1e-6);
+        assert!((with_vspan.minimum.y - 3.0).abs() < 1e-6);
+        assert!((with_vspan.maximum.y - 5.0).abs() < 1e-6);
+
+        let with_hspan = Region::from(HSpan { y0: -2.0, y1: 4.5 })
+            .data_bounds()
+            .union(&data);
+        assert!((with_hspan.minimum.x - 2.0).abs() < 1e-6);
+        assert!((with_hspan.maximum.x - 4.0).abs() < 1e-6);
+        assert!((with_hspan.minimum.y + 2.0).abs() < 1e-6);
+        assert!((with_hspan.maximum.y - 5.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn rect_region_bounds_are_its_box() {
+        let rect = RectRegion {
+            data_bbox: DataBBox::new((3.0, 1.0), (-1.0, 2.0)),
+        };
+        let bounds = Region::from(rect).data_bounds();
+        assert!((bounds.minimum.x + 1.0).abs() < 1e-6);
+        assert!((bounds.minimum.y - 1.0).abs() < 1e-6);
+        assert!((bounds.maximum.x - 3.0).abs() < 1e-6);
+        assert!((bounds.maximum.y - 2.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn region_config_fills_with_the_first_cycle_color() {
+        let mut config = RegionConfig::default();
+        assert!((config.fill_alpha - 0.2).abs() < 1e-6);
+        assert!(config.border.is_none());
+        config.apply_theme(&scheme());
+        assert_eq!(config.fill_color, Some(Color::RED));
+
+        let empty_cycle = Colorscheme::new(
+            Color::WHITE,
+            Color::LIGHTGRAY,
+            Color::BLACK,
+            Color::DARKGRAY,
+            Vec::new(),
+        );
+        let mut config = RegionConfig::default();
+        config.apply_theme(&empty_cycle);
+        assert_eq!(config.fill_color, Some(Color::LIGHTGRAY));
+
+        let mut explicit = RegionConfigBuilder::default()
+            .fill_color(Color::BLUE)
+            .border((Color::BLACK, 2.0))
+            .build()
+            .unwrap();
+        explicit.apply_theme(&scheme());
+        assert_eq!(explicit.fill_color, Some(Color::BLUE));
+        assert!(explicit.border.is_some());
+    }
+}
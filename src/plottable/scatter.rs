@@ -3,7 +3,10 @@
 //! [`ScatterPlot`] renders a [`Dataset`] as individual points inside a
 //! [`ViewTransformer`]. Each visual attribute (size, color, shape) can be
 //! set to a fixed value or mapped dynamically per-point via a closure,
-//! enabling techniques like cluster coloring or bubble charts.
+//! enabling techniques like cluster coloring or bubble charts. Markers can
+//! also be drawn hollow or with an outline to keep overlapping points legible,
+//! with the outline color and thickness mapped per point like the fill, and
+//! annotated with per-point text labels.
 //!
 //! # Example
 //!
@@ -20,16 +23,19 @@
 //! ```
 
 use crate::{
-    colorscheme::Themable,
+    colorscheme::{Colorscheme, Themable, scale_alpha},
     dataset::Dataset,
     plottable::{
-        point::{Datapoint, PointConfigBuilder, Shape},
+        legend::LegendEntry,
+        point::{Datapoint, PointConfigBuilder, Screenpoint, Shape},
+        text::{Anchor, TextLabel, TextStyle, TextStyleBuilder},
         view::{DataBBox, ViewTransformer},
     },
     plotter::{ChartElement, PlotElement},
 };
 use derive_builder::Builder;
-use raylib::prelude::Color;
+use raylib::prelude::{Color, Vector2};
+use std::collections::HashSet;
 
 /// A closure that computes point size from the data point and its index.
 pub type DynamicSize = Box<dyn Fn(&Datapoint, usize) -> f32>;
@@ -37,6 +43,18 @@ pub type DynamicSize = Box<dyn Fn(&Datapoint, usize) -> f32>;
 pub type DynamicColor = Box<dyn Fn(&Datapoint, usize) -> Color>;
 /// A closure that computes point shape from the data point and its index.
 pub type DynamicShape = Box<dyn Fn(&Datapoint, usize) -> Shape>;
+/// A closure that computes point opacity (`0.0..=1.0`) from the data point
+/// and its index.
+pub type DynamicAlpha = Box<dyn Fn(&Datapoint, usize) -> f32>;
+/// A closure that computes marker rotation in degrees from the data point
+/// and its index.
+pub type DynamicRotation = Box<dyn Fn(&Datapoint, usize) -> f32>;
+/// A closure that computes a marker's pixel offset from the data point and
+/// its index.
+pub type DynamicOffset = Box<dyn Fn(&Datapoint, usize) -> Vector2>;
+/// A closure that computes a marker's outline thickness in pixels from the
+/// data point and its index.
+pub type DynamicThickness = Box<dyn Fn(&Datapoint, usize) -> f32>;
 /// Generic per-point attribute mapping closure.
 pub type Dynamic<T> = Box<dyn Fn(&Datapoint, usize) -> T>;
 
@@ -49,12 +67,32 @@ pub enum Strategy<T> {
     Dynamic(Dynamic<T>),
 }
 
+/// A plain value is a [`Strategy::Fixed`] one, so builder setters accept
+/// either.
+impl<T> From<T> for Strategy<T> {
+    fn from(value: T) -> Self {
+        Strategy::Fixed(value)
+    }
+}
+
+/// How a [`ScatterPlot`] thins out its points before drawing.
+#[derive(Debug, Clone, Copy)]
+pub enum DecimateStrategy {
+    /// Draw at most this many points, sampled with a uniform stride.
+    MaxPoints(usize),
+    /// Draw at most one point per screen pixel; later points landing on an
+    /// already occupied pixel are skipped.
+    PixelBucket,
+}
+
 /// Configuration for a [`ScatterPlot`].
 ///
-/// Each visual property (size, color, shape) is optional. When `None`,
-/// sensible defaults are used (size = 5, shape = circle, color resolved
-/// from the theme cycle). Properties can be set to a [`Strategy::Fixed`]
-/// constant or a [`Strategy::Dynamic`] closure for per-point variation.
+/// Each visual property (size, color, shape, alpha, rotation, offset, outline
+/// color) is optional. When `None`, sensible defaults are used (size = 5,
+/// shape = circle, color resolved from the theme cycle, fully opaque,
+/// unrotated, unshifted, no outline). Properties, including the outline
+/// thickness, can be set to a [`Strategy::Fixed`] constant or a
+/// [`Strategy::Dynamic`] closure for per-point variation.
 ///
 /// Construct via [`ScatterPlotBuilder`]:
 ///
@@ -79,6 +117,57 @@ pub struct ScatterPlotConfig {
     /// Point shape strategy. `None` falls back to [`Shape::Circle`].
     #[builder(setter(into, strip_option), default = "None")]
     shape: Option<Strategy<Shape>>,
+    /// Point opacity strategy, multiplied into the resolved color's alpha.
+    /// `None` leaves points fully opaque.
+    #[builder(setter(into, strip_option), default = "None")]
+    alpha: Option<Strategy<f32>>,
+    /// Marker rotation strategy in degrees (clockwise). `None` leaves markers
+    /// unrotated; circles ignore it.
+    #[builder(setter(into, strip_option), default = "None")]
+    rotation: Option<Strategy<f32>>,
+    /// Marker offset strategy in pixels, added to the projected position
+    /// when drawing. Labels follow the marker; the projected position used
+    /// for culling, bucketing and hit-testing does not move. `None` draws
+    /// markers on their data point.
+    #[builder(setter(into, strip_option), default = "None")]
+    offset: Option<Strategy<Vector2>>,
+    /// Per-point label text. An empty string skips that point's label.
+    /// `None` draws no labels.
+    #[builder(setter(into, strip_option), default = "None")]
+    labels: Option<Dynamic<String>>,
+    /// Text style for per-point labels. Labels sit above their marker; the
+    /// style's `offset` is applied on top of that.
+    #[builder(default = "default_label_style()")]
+    label_style: TextStyle,
+    /// Whether markers are filled. `false` draws rings and outlined shapes.
+    #[builder(default = "true")]
+    filled: bool,
+    /// Border color strategy for filled markers, or the stroke color of
+    /// hollow ones. `None` draws no border.
+    #[builder(setter(into, strip_option), default = "None")]
+    outline_color: Option<Strategy<Color>>,
+    /// Thickness strategy of marker outlines in pixels. Defaults to 1.
+    #[builder(setter(into), default = "Strategy::Fixed(1.0)")]
+    outline_thickness: Strategy<f32>,
+    /// Optional decimation pass that thins out large datasets before
+    /// drawing. `None` draws every point.
+    #[builder(setter(into, strip_option), default = "None")]
+    decimate: Option<DecimateStrategy>,
+    /// Skip points whose marker falls entirely outside the inner plotting
+    /// area. Defaults to `true`.
+    #[builder(default = "true")]
+    cull: bool,
+    /// Draw circular markers smoothly, as
+    /// [`PointConfig`](crate::plottable::point::PointConfig) does with `smooth`.
+    /// Better looking but slower, so it is off by default and best left off
+    /// for 10k+ points.
+    #[builder(default = "false")]
+    smooth: bool,
+    /// Segments of circular markers, as
+    /// [`PointConfig`](crate::plottable::point::PointConfig) takes them.
+    /// `0`, the default, keeps the count chosen from the marker size.
+    #[builder(default = "0")]
+    circle_segments: u32,
 }
 
 impl Default for ScatterPlotConfig {
@@ -89,6 +178,53 @@ impl Default for ScatterPlotConfig {
     }
 }
 
+fn default_label_style() -> TextStyle {
+    TextStyleBuilder::default()
+        .font_size(12.0)
+        .anchor(Anchor::CENTER_BOTTOM)
+        .build()
+        .expect("Will never fail")
+}
+
+/// Gap in pixels between a marker's edge and its label.
+const LABEL_GAP: f32 = 2.0;
+
+impl ScatterPlotConfig {
+    /// A legend entry matching how this configuration draws its markers.
+    ///
+    /// An unset color resolves from `scheme` the same way theming does.
+    /// Returns `None` when the color is mapped per point, since no single
+    /// swatch represents the series; a mapped shape falls back to a circle.
+    #[must_use]
+    pub fn legend_entry(
+        &self,
+        label: impl Into<String>,
+        scheme: &Colorscheme,
+    ) -> Option<LegendEntry> {
+        let color = match &self.color {
+            Some(Strategy::Fixed(c)) => *c,
+            Some(Strategy::Dynamic(_)) => return None,
+            None => scheme.cycle.first().copied().unwrap_or(Color::BLACK),
+        };
+        let color = match &self.alpha {
+            Some(Strategy::Fixed(a)) => scale_alpha(color, *a),
+            _ => color,
+        };
+        let shape = match &self.shape {
+            Some(Strategy::Fixed(s)) => *s,
+            _ => Shape::Circle,
+        };
+        let mut entry = LegendEntry::new(label, color).with_shape(shape);
+        if !self.filled {
+            entry = entry.hollow();
+        }
+        if let Some(Strategy::Fixed(outline)) = self.outline_color {
+            entry = entry.with_outline(outline);
+        }
+        Some(entry)
+    }
+}
+
 impl ScatterPlotBuilder {
     /// Use a constant point size for every data point.
     #[must_use]
@@ -142,6 +278,177 @@ impl ScatterPlotBuilder {
             ..self
         }
     }
+
+    /// Use a constant opacity (`0.0..=1.0`) for every data point.
+    #[must_use]
+    pub fn fixed_alpha(self, alpha: f32) -> Self {
+        Self {
+            alpha: Some(Some(Strategy::Fixed(alpha))),
+            ..self
+        }
+    }
+
+    /// Compute point opacity dynamically from each data point and its index.
+    #[must_use]
+    pub fn mapped_alpha(self, alpha_func: DynamicAlpha) -> Self {
+        Self {
+            alpha: Some(Some(Strategy::Dynamic(alpha_func))),
+            ..self
+        }
+    }
+
+    /// Rotate every marker by the same angle, in degrees.
+    #[must_use]
+    pub fn fixed_rotation(self, degrees: f32) -> Self {
+        Self {
+            rotation: Some(Some(Strategy::Fixed(degrees))),
+            ..self
+        }
+    }
+
+    /// Compute marker rotation in degrees from each data point and its index.
+    #[must_use]
+    pub fn mapped_rotation(self, rotation_func: DynamicRotation) -> Self {
+        Self {
+            rotation: Some(Some(Strategy::Dynamic(rotation_func))),
+            ..self
+        }
+    }
+
+    /// Shift every marker by the same pixel offset, e.g. to dodge two
+    /// series sharing x values.
+    #[must_use]
+    pub fn fixed_offset(self, offset: Vector2) -> Self {
+        Self {
+            offset: Some(Some(Strategy::Fixed(offset))),
+            ..self
+        }
+    }
+
+    /// Compute each marker's pixel offset from its data point and index.
+    #[must_use]
+    pub fn mapped_offset(self, offset_func: DynamicOffset) -> Self {
+        Self {
+            offset: Some(Some(Strategy::Dynamic(offset_func))),
+            ..self
+        }
+    }
+
+    /// Outline every marker in the same color.
+    #[must_use]
+    pub fn fixed_outline_color(self, color: Color) -> Self {
+        Self {
+            outline_color: Some(Some(Strategy::Fixed(color))),
+            ..self
+        }
+    }
+
+    /// Compute each marker's outline color from its data point and index.
+    #[must_use]
+    pub fn mapped_outline_color(self, color_func: DynamicColor) -> Self {
+        Self {
+            outline_color: Some(Some(Strategy::Dynamic(color_func))),
+            ..self
+        }
+    }
+
+    /// Use the same outline thickness, in pixels, for every marker.
+    #[must_use]
+    pub fn fixed_outline_thickness(self, thickness: f32) -> Self {
+        Self {
+            outline_thickness: Some(Strategy::Fixed(thickness)),
+            ..self
+        }
+    }
+
+    /// Compute each marker's outline thickness from its data point and
+    /// index, e.g. to emphasise selected points.
+    ///
+    /// ```rust
+    /// use locus::prelude::*;
+    /// use raylib::color::Color;
+    /// let selected = [3, 7];
+    /// let config = ScatterPlotBuilder::default()
+    ///     .fixed_outline_color(Color::WHITE)
+    ///     .mapped_outline_thickness(Box::new(move |_, i| {
+    ///         if selected.contains(&i) { 3.0 } else { 0.5 }
+    ///     }))
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    #[must_use]
+    pub fn mapped_outline_thickness(self, thickness_func: DynamicThickness) -> Self {
+        Self {
+            outline_thickness: Some(Strategy::Dynamic(thickness_func)),
+            ..self
+        }
+    }
+
+    /// Size markers as bubbles: point `i` gets a size between `min_px` and
+    /// `max_px`, scaled linearly with `values[i]` between the smallest and
+    /// largest value.
+    ///
+    /// Points without a (finite) value get `min_px`; when every value is the
+    /// same, all bubbles sit halfway between the bounds.
+    ///
+    /// ```rust
+    /// use locus::prelude::*;
+    /// let population = [120.0, 40.0, 300.0];
+    /// let config = ScatterPlotBuilder::default()
+    ///     .bubble(&population, 4.0, 30.0)
+    ///     .fixed_alpha(0.6)
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    #[must_use]
+    pub fn bubble(self, values: &[f32], min_px: f32, max_px: f32) -> Self {
+        let sizes = bubble_sizes(values, min_px, max_px, false);
+        self.mapped_size(Box::new(move |_, i| {
+            sizes.get(i).copied().unwrap_or(min_px)
+        }))
+    }
+
+    /// Like [`bubble`](ScatterPlotBuilder::bubble), but scales the marker
+    /// *area* linearly with the value, so a value twice as large looks twice
+    /// as big rather than four times.
+    #[must_use]
+    pub fn bubble_by_area(self, values: &[f32], min_px: f32, max_px: f32) -> Self {
+        let sizes = bubble_sizes(values, min_px, max_px, true);
+        self.mapped_size(Box::new(move |_, i| {
+            sizes.get(i).copied().unwrap_or(min_px)
+        }))
+    }
+}
+
+/// Marker sizes in `min_px..=max_px` for each of `values`, interpolating
+/// the size itself or, with `by_area`, its square.
+fn bubble_sizes(values: &[f32], min_px: f32, max_px: f32, by_area: bool) -> Vec<f32> {
+    let (low, high) = values
+        .iter()
+        .filter(|v| v.is_finite())
+        .fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), &v| {
+            (lo.min(v), hi.max(v))
+        });
+    let (from, to) = if by_area {
+        (min_px * min_px, max_px * max_px)
+    } else {
+        (min_px, max_px)
+    };
+    values
+        .iter()
+        .map(|&v| {
+            if !v.is_finite() {
+                return min_px;
+            }
+            let t = if high - low > f32::EPSILON {
+                (v - low) / (high - low)
+            } else {
+                0.5
+            };
+            let size = from + (to - from) * t;
+            if by_area { size.sqrt() } else { size }
+        })
+        .collect()
 }
 
 /// A scatter plot that renders every point in a [`Dataset`] as an
@@ -172,8 +479,53 @@ impl ChartElement for ScatterPlot<'_> {
         configs: &ScatterPlotConfig,
         view: &ViewTransformer,
     ) {
-        self.data.data.iter().enumerate().for_each(|(i, p)| {
-            let screen_point = view.to_screen(p);
+        self.draw_projected(rl, configs, view, |p| view.to_screen(p));
+    }
+
+    fn data_bounds(&self) -> DataBBox {
+        DataBBox {
+            minimum: Datapoint((self.data.range_min.x, self.data.range_min.y).into()),
+            maximum: Datapoint((self.data.range_max.x, self.data.range_max.y).into()),
+        }
+    }
+
+    fn min_positive(&self) -> (Option<f32>, Option<f32>) {
+        self.data.min_positive()
+    }
+}
+
+impl ScatterPlot<'_> {
+    /// Draw the markers, placing each point on screen with `project`
+    /// instead of the view's linear mapping. Culling still uses the view's
+    /// plotting area.
+    pub(crate) fn draw_projected(
+        &self,
+        rl: &mut raylib::prelude::RaylibDrawHandle,
+        configs: &ScatterPlotConfig,
+        view: &ViewTransformer,
+        project: impl Fn(&Datapoint) -> Screenpoint,
+    ) {
+        let (stride, limit) = match configs.decimate {
+            Some(DecimateStrategy::MaxPoints(max)) => {
+                (self.data.data.len().div_ceil(max.max(1)).max(1), max)
+            }
+            _ => (1, usize::MAX),
+        };
+        let bucket = matches!(configs.decimate, Some(DecimateStrategy::PixelBucket));
+        let mut occupied: HashSet<(i32, i32)> = HashSet::new();
+        let inner = view.screen_bounds.inner_bbox();
+        let points = self
+            .data
+            .data
+            .iter()
+            .enumerate()
+            .step_by(stride)
+            .take(limit);
+        points.for_each(|(i, p)| {
+            let screen_point = project(p);
+            if bucket && !occupied.insert(pixel_cell(screen_point)) {
+                return;
+            }
             let size = match &configs.size {
                 Some(strat) => match strat {
                     Strategy::Fixed(c) => *c,
@@ -181,6 +533,16 @@ impl ChartElement for ScatterPlot<'_> {
                 },
                 None => 5.0,
             };
+            // Markers whose extent lies fully outside the plotting area would
+            // be clipped anyway; skip them before paying for the draw call.
+            if configs.cull
+                && (screen_point.x + size < inner.minimum.x
+                    || screen_point.x - size > inner.maximum.x
+                    || screen_point.y + size < inner.minimum.y
+                    || screen_point.y - size > inner.maximum.y)
+            {
+                return;
+            }
 
             let shape = match &configs.shape {
                 Some(strat) => match strat {
@@ -196,24 +558,67 @@ impl ChartElement for ScatterPlot<'_> {
                 },
                 None => Color::BLACK,
             };
+            let color = match &configs.alpha {
+                Some(strat) => match strat {
+                    Strategy::Fixed(a) => scale_alpha(color, *a),
+                    Strategy::Dynamic(func) => scale_alpha(color, func(p, i)),
+                },
+                None => color,
+            };
+            let rotation = match &configs.rotation {
+                Some(strat) => match strat {
+                    Strategy::Fixed(r) => *r,
+                    Strategy::Dynamic(func) => func(p, i),
+                },
+                None => 0.0,
+            };
+            let offset = match &configs.offset {
+                Some(strat) => match strat {
+                    Strategy::Fixed(o) => *o,
+                    Strategy::Dynamic(func) => func(p, i),
+                },
+                None => Vector2::zero(),
+            };
+            let outline_color = configs.outline_color.as_ref().map(|strat| match strat {
+                Strategy::Fixed(c) => *c,
+                Strategy::Dynamic(func) => func(p, i),
+            });
+            let outline_thickness = match &configs.outline_thickness {
+                Strategy::Fixed(t) => *t,
+                Strategy::Dynamic(func) => func(p, i),
+            };
             screen_point.plot(
                 rl,
                 &PointConfigBuilder::default()
                     .size(size)
                     .shape(shape)
                     .color(color)
+                    .filled(configs.filled)
+                    .outline_color(outline_color)
+                    .outline_thickness(outline_thickness)
+                    .rotation_deg(rotation)
+                    .offsets(offset)
+                    .smooth(configs.smooth)
+                    .circle_segments(configs.circle_segments)
                     .build()
                     .expect("Failed to build point config"),
             );
+            if let Some(labels) = &configs.labels {
+                let text = labels(p, i);
+                if !text.is_empty() {
+                    let marker = screen_point.offset_by(offset);
+                    let origin = Screenpoint::new(marker.x, marker.y - size - LABEL_GAP);
+                    TextLabel::new(text, origin).plot(rl, &configs.label_style);
+                }
+            }
         });
     }
+}
 
-    fn data_bounds(&self) -> DataBBox {
-        DataBBox {
-            minimum: Datapoint((self.data.range_min.x, self.data.range_min.y).into()),
-            maximum: Datapoint((self.data.range_max.x, self.data.range_max.y).into()),
-        }
-    }
+/// The integer pixel cell a projected point falls into.
+#[allow(clippy::cast_possible_truncation)]
+fn pixel_cell(point: Screenpoint) -> (i32, i32) {
+    (point.x.floor() as i32, point.y.floor() as i32)
 }
 
 impl Themable for ScatterPlotConfig {
@@ -226,5 +631,57 @@ impl Themable for ScatterPlotConfig {
                 ));
             }
         }
+        self.label_style.apply_theme(scheme);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scale_alpha_multiplies_existing_alpha() {
+        let c = Color::new(10, 20, 30, 200);
+        assert_eq!(scale_alpha(c, 0.5).a, 100);
+        assert_eq!(scale_alpha(c, 1.0).a, 200);
+        assert_eq!(scale_alpha(c, 2.0).a, 200);
+        assert_eq!(scale_alpha(c, -1.0).a, 0);
+        assert_eq!(scale_alpha(c, 0.5).r, 10);
+    }
+
+    #[test]
+    fn bubble_sizes_span_the_pixel_bounds() {
+        let sizes = bubble_sizes(&[10.0, 20.0, 30.0, f32::NAN], 2.0, 12.0, false);
+        assert_eq!(sizes, vec![2.0, 7.0, 12.0, 2.0]);
+
+        let sizes = bubble_sizes(&[0.0, 1.0, 2.0], 0.0, 10.0, true);
+        assert!((sizes[1] * sizes[1] - 50.0).abs() < 1e-4);
+        assert!((sizes[2] - 10.0).abs() < 1e-4);
+
+        assert_eq!(bubble_sizes(&[5.0, 5.0], 2.0, 4.0, false), vec![3.0, 3.0]);
+    }
+
+    #[test]
+    fn legend_keeps_only_a_fixed_outline() {
+        let scheme = Colorscheme::default();
+        let fixed = ScatterPlotBuilder::default()
+            .outline_color(Color::WHITE)
+            .outline_thickness(2.0)
+            .build()
+            .unwrap();
+        let entry = fixed.legend_entry("fixed", &scheme).unwrap();
+        assert_eq!(entry.outline_color, Some(Color::WHITE));
+
+        let mapped = ScatterPlotBuilder::default()
+            .mapped_outline_color(Box::new(
+                |_, i| {
+                    if i == 0 { Color::RED } else { Color::BLANK }
+                },
+            ))
+            .mapped_outline_thickness(Box::new(|_, i| if i == 0 { 3.0 } else { 0.0 }))
+            .build()
+            .unwrap();
+        let entry = mapped.legend_entry("mapped", &scheme).unwrap();
+        assert_eq!(entry.outline_color, None);
     }
 }
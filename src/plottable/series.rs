@@ -0,0 +1,226 @@
+//! Scatter and line charts over several named series.
+//!
+//! [`MultiScatter`] and [`MultiLine`] draw every [`Series`] of a
+//! [`MultiSeries`] in one view, each in its own color: the series' explicit
+//! color when set, otherwise the theme cycle color at the series' index.
+//! Both are styled with a [`MultiSeriesConfig`] and can emit matching
+//! legend entries for
+//! [`GraphBuilder::legend_from`](crate::graph::GraphBuilder::legend_from).
+//!
+//! # Example
+//!
+//! ```rust
+//! use locus::prelude::*;
+//! let all = MultiSeries::new(vec![
+//!     Series::new("north", Dataset::new(vec![(0.0, 1.0), (1.0, 2.0), (2.0, 1.5)])),
+//!     Series::new("south", Dataset::new(vec![(0.0, 0.5), (1.0, 0.8), (2.0, 2.2)])),
+//! ]);
+//! let config = MultiSeriesConfig::default();
+//! let legend = MultiLine::new(&all).legend_entries(&config, &NORD);
+//! let graph = Graph::new(MultiLine::new(&all));
+//! ```
+
+use derive_builder::Builder;
+use raylib::prelude::*;
+
+use crate::{
+    colorscheme::{Colorscheme, Themable, indexed_color},
+    dataset::{MultiSeries, Series},
+    plottable::{
+        legend::LegendEntry,
+        lineplot::segments,
+        point::Shape,
+        scatter::{ScatterPlot, ScatterPlotBuilder},
+        view::{DataBBox, ViewTransformer},
+    },
+    plotter::ChartElement,
+};
+
+/// Configuration shared by [`MultiScatter`] and [`MultiLine`].
+///
+/// When `colors` is `None` it is resolved from the
+/// [`Colorscheme`](crate::colorscheme::Colorscheme) cycle during theme
+/// application. A series' own color always takes precedence.
+#[derive(Debug, Clone, Builder)]
+#[builder(pattern = "owned")]
+#[builder(default)]
+pub struct MultiSeriesConfig {
+    /// Per-series colors, by index. `None` means "use the theme cycle".
+    #[builder(setter(into, strip_option))]
+    pub colors: Option<Vec<Color>>,
+    /// Marker size in pixels ([`MultiScatter`]).
+    pub marker_size: f32,
+    /// Marker shape ([`MultiScatter`]).
+    pub shape: Shape,
+    /// Line thickness in pixels ([`MultiLine`]).
+    pub thickness: f32,
+}
+
+impl Default for MultiSeriesConfig {
+    fn default() -> Self {
+        Self {
+            colors: None,
+            marker_size: 5.0,
+            shape: Shape::Circle,
+            thickness: 2.0,
+        }
+    }
+}
+
+impl MultiSeriesConfig {
+    /// Color of the series at `index`: its own color, else the configured
+    /// colors, else `scheme`'s cycle.
+    fn series_color(&self, index: usize, series: &Series, scheme: Option<&Colorscheme>) -> Color {
+        if let Some(color) = series.color {
+            return color;
+        }
+        indexed_color(self.colors.as_deref(), scheme, index)
+    }
+
+    /// One entry per series, with the indicator built by `indicator`.
+    fn legend_entries(
+        &self,
+        all: &MultiSeries,
+        scheme: &Colorscheme,
+        indicator: impl Fn(LegendEntry) -> LegendEntry,
+    ) -> Vec<LegendEntry> {
+        all.series
+            .iter()
+            .enumerate()
+            .map(|(i, s)| {
+                indicator(LegendEntry::new(
+                    s.name.clone(),
+                    self.series_color(i, s, Some(scheme)),
+                ))
+            })
+            .collect()
+    }
+}
+
+impl Themable for MultiSeriesConfig {
+    fn apply_theme(&mut self, scheme: &Colorscheme) {
+        if self.colors.is_none() && !scheme.cycle.is_empty() {
+            self.colors = Some(scheme.cycle.to_vec());
+        }
+    }
+}
+
+/// A scatter plot of every series, one color per series.
+#[derive(Debug, Clone, Copy)]
+pub struct MultiScatter<'a> {
+    /// The series being visualized.
+    pub data: &'a MultiSeries,
+}
+
+impl<'a> MultiScatter<'a> {
+    /// Create a multi-series scatter plot.
+    #[must_use]
+    pub fn new(data: &'a MultiSeries) -> Self {
+        Self { data }
+    }
+
+    /// One legend entry per series, matching the markers.
+    #[must_use]
+    pub fn legend_entries(
+        &self,
+        configs: &MultiSeriesConfig,
+        scheme: &Colorscheme,
+    ) -> Vec<LegendEntry> {
+        configs.legend_entries(self.data, scheme, |entry| entry.with_shape(configs.shape))
+    }
+}
+
+impl ChartElement for MultiScatter<'_> {
+    type Config = MultiSeriesConfig;
+
+    fn draw_in_view(
+        &self,
+        rl: &mut RaylibDrawHandle,
+        configs: &Self::Config,
+        view: &ViewTransformer,
+    ) {
+        for (i, series) in self.data.series.iter().enumerate() {
+            let scatter_configs = ScatterPlotBuilder::default()
+                .fixed_color(configs.series_color(i, series, None))
+                .fixed_size(configs.marker_size)
+                .fixed_shape(configs.shape)
+                .build()
+                .expect("Failed to build scatter config");
+            ScatterPlot::new(&series.data).draw_in_view(rl, &scatter_configs, view);
+        }
+    }
+
+    fn data_bounds(&self) -> DataBBox {
+        self.data.data_bounds()
+    }
+}
+
+/// A polyline per series, connecting its points in storage order, one
+/// color per series. Like [`LinePlot`](crate::plottable::lineplot::LinePlot),
+/// each line breaks at points with a non-finite coordinate.
+#[derive(Debug, Clone, Copy)]
+pub struct MultiLine<'a> {
+    /// The series being visualized.
+    pub data: &'a MultiSeries,
+}
+
+impl<'a> MultiLine<'a> {
+    /// Create a multi-series line chart.
+    #[must_use]
+    pub fn new(data: &'a MultiSeries) -> Self {
+        Self { data }
+    }
+
+    /// One legend entry per series, drawn as a line swatch.
+    #[must_use]
+    pub fn legend_entries(
+        &self,
+        configs: &MultiSeriesConfig,
+        scheme: &Colorscheme,
+    ) -> Vec<LegendEntry> {
+        configs.legend_entries(self.data, scheme, |entry| {
+            entry.with_line(configs.thickness)
+        })
+    }
+}
+
+impl ChartElement for MultiLine<'_> {
+    type Config = MultiSeriesConfig;
+
+    fn draw_in_view(
+        &self,
+        rl: &mut RaylibDrawHandle,
+        configs: &Self::Config,
+        view: &ViewTransformer,
+    ) {
+        for (i, series) in self.data.series.iter().enumerate() {
+            let color = configs.series_color(i, series, None);
+            for (a, b) in segments(&series.data.data) {
+                let (a, b) = (view.to_screen(&a), view.to_screen(&b));
+                rl.draw_line_ex(*a, *b, configs.thickness, color);
+            }
+        }
+    }
+
+    fn data_bounds(&self) -> DataBBox {
+        self.data.data_bounds()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dataset::Dataset;
+
+    #[test]
+    fn own_color_overrides_cycle() {
+        let configs = MultiSeriesConfigBuilder::default()
+            .colors(vec![Color::RED, Color::GREEN])
+            .build()
+            .unwrap();
+        let plain = Series::new("a", Dataset::new(vec![(0.0, 0.0)]));
+        let blue = plain.clone().with_color(Color::BLUE);
+        assert_eq!(configs.series_color(3, &plain, None).g, Color::GREEN.g);
+        assert_eq!(configs.series_color(0, &blue, None).b, Color::BLUE.b);
+    }
+}
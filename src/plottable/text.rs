@@ -5,7 +5,7 @@
 //! * [`TextLabel`] : a concrete string at a screen-space position,
 //!   implementing [`PlotElement`].
 //! * [`TextStyle`] : all visual and layout properties (font, size, color,
-//!   anchor, rotation, offset), built via [`TextStyleBuilder`].
+//!   anchor, rotation, offset, line spacing, background box), built via [`TextStyleBuilder`].
 //! * [`FontHandle`] : an `Rc`-wrapped font reference that can be shared
 //!   across multiple styles without lifetime friction.
 //! * [`Anchor`] / [`HAlign`] / [`VAlign`] : positioning helpers that
@@ -42,13 +42,13 @@
 //! When no font is loaded, raylib's built-in bitmap font is used
 //! automatically.
 
-use std::rc::Rc;
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
 
 use derive_builder::Builder;
 use raylib::{
     RaylibHandle, RaylibThread,
     color::Color,
-    math::Vector2,
+    math::{Rectangle, Vector2},
     prelude::{RaylibDraw, RaylibDrawHandle},
     text::{RaylibFont, WeakFont},
 };
@@ -174,6 +174,40 @@ impl FontHandle {
         })
     }
 
+    /// Load a font from bytes in memory, e.g. a TTF embedded with
+    /// `include_bytes!`, so the binary doesn't depend on files on disk.
+    ///
+    /// `file_type` is the file extension raylib uses to pick a decoder,
+    /// including the leading dot: `".ttf"`, `".otf"`, or `".fnt"`. `size` is
+    /// the rasterised size in pixels, as in [`FontHandle::load`].
+    ///
+    /// # Errors
+    ///
+    /// Returns raylib's message when `data` cannot be decoded as a font of
+    /// `file_type`.
+    ///
+    /// ```rust,no_run
+    /// # use locus::prelude::*;
+    /// # let (mut rl, thread) = raylib::init().build();
+    /// # let bytes: &[u8] = &[];
+    /// // let bytes = include_bytes!("../assets/font.ttf");
+    /// let font = FontHandle::from_memory(&mut rl, &thread, ".ttf", bytes, 48).unwrap();
+    /// ```
+    pub fn from_memory(
+        rl: &mut RaylibHandle,
+        thread: &RaylibThread,
+        file_type: &str,
+        data: &[u8],
+        size: i32,
+    ) -> Result<Self, String> {
+        let font = rl
+            .load_font_from_memory(thread, file_type, data, size, None)
+            .map_err(|e| e.to_string())?;
+        Ok(Self {
+            font: Rc::new(font.make_weak()),
+        })
+    }
+
     /// Obtain a handle to raylib's built-in default font.
     #[must_use]
     pub fn default_font(rl: &RaylibHandle) -> Self {
@@ -231,6 +265,30 @@ pub struct TextStyle {
     /// Extra pixel offset applied *after* anchor resolution.
     #[builder(default = "Vector2::new(0.0, 0.0)")]
     pub offset: Vector2,
+    /// Distance between the tops of consecutive lines, as a multiple of
+    /// `font_size`. Only relevant for text containing `\n`.
+    #[builder(default = "1.2")]
+    pub line_spacing: f32,
+    /// Fill colour of an optional box drawn behind the text. `None` draws no
+    /// box.
+    #[builder(default = "None")]
+    pub background: Option<Color>,
+    /// Padding in pixels between the text block and the edge of its
+    /// background box.
+    #[builder(default = "4.0")]
+    pub background_padding: f32,
+    /// Corner radius of the background box in pixels. Rounded corners are
+    /// only drawn for unrotated text; rotated boxes keep square corners.
+    #[builder(default = "0.0")]
+    pub corner_radius: f32,
+    /// Shrink, then wrap, the text when it is wider than the space given to
+    /// it (see [`TextStyle::fitted`]). A [`Graph`](crate::graph::Graph)
+    /// fits its title, subtitle, caption, and axis labels to the viewport.
+    #[builder(default = "false")]
+    pub fit: bool,
+    /// Smallest font size `fit` shrinks the text to before wrapping it.
+    #[builder(default = "10.0")]
+    pub min_font_size: f32,
 }
 
 impl Default for TextStyle {
@@ -244,6 +302,12 @@ impl Default for TextStyle {
             anchor: Anchor::CENTER,
             rotation: 0.0,
             offset: Vector2::new(0.0, 0.0),
+            line_spacing: 1.2,
+            background: None,
+            background_padding: 4.0,
+            corner_radius: 0.0,
+            fit: false,
+            min_font_size: 10.0,
         }
     }
 }
@@ -251,15 +315,91 @@ impl Default for TextStyle {
 impl TextStyle {
     /// Measure `text` using this style's font, size, and spacing.
     ///
+    /// Multi-line text is measured as a block: the width of the widest line
+    /// by the stacked height of all lines (see [`TextStyle::line_height`]).
+    ///
     /// When no custom font is set the caller must provide a fallback via
     /// `default_font`; passing the draw-handle's default font works.
+    ///
+    /// Line sizes are memoized per thread, so static labels are measured
+    /// only once; see [`clear_text_measure_cache`].
     #[must_use]
     pub fn measure_text(&self, text: &str, default_font: &WeakFont) -> Vector2 {
-        match &self.font {
-            Some(fh) => fh.measure(text, self.font_size, self.spacing),
-            None => default_font.measure_text(text, self.font_size, self.spacing),
+        let mut block = Vector2::zero();
+        let mut top = 0.0;
+        for line in text.split('\n') {
+            let size = self.measure_line(line, default_font);
+            block.x = block.x.max(size.x);
+            block.y = top + size.y;
+            top += self.line_height();
+        }
+        block
+    }
+
+    /// Vertical distance between the tops of consecutive lines, in pixels.
+    #[must_use]
+    pub fn line_height(&self) -> f32 {
+        self.font_size * self.line_spacing
+    }
+
+    /// Measure a single line of text (no `\n` handling), memoized in the
+    /// thread's measurement cache.
+    fn measure_line(&self, line: &str, default_font: &WeakFont) -> Vector2 {
+        let font: &raylib::ffi::Font = match &self.font {
+            Some(fh) => fh.as_ffi(),
+            None => default_font.as_ref(),
+        };
+        let style = MeasureStyle {
+            font: font.texture.id,
+            size: self.font_size.to_bits(),
+            spacing: self.spacing.to_bits(),
+        };
+        if let Some(size) = MEASURE_CACHE.with_borrow(|cache| cache.get(style, line)) {
+            return size;
+        }
+        let size = match &self.font {
+            Some(fh) => fh.measure(line, self.font_size, self.spacing),
+            None => default_font.measure_text(line, self.font_size, self.spacing),
+        };
+        MEASURE_CACHE.with_borrow_mut(|cache| cache.insert(style, line, size));
+        size
+    }
+    /// `text` and the style to draw it with so that it spans at most
+    /// `max_width` pixels, when `fit` is set.
+    ///
+    /// The font size first shrinks toward `min_font_size`. Text still too
+    /// wide at that size is wrapped onto more lines at spaces; a single word
+    /// wider than `max_width` keeps a line of its own. Without `fit`, or when
+    /// the text already fits, both are returned unchanged.
+    #[must_use]
+    pub fn fitted(
+        &self,
+        text: &str,
+        max_width: f32,
+        default_font: &WeakFont,
+    ) -> (String, TextStyle) {
+        let mut style = self.clone();
+        if !self.fit || max_width <= 0.0 {
+            return (text.to_owned(), style);
+        }
+        // Text width grows about linearly with the font size; the extra
+        // rounds absorb letter spacing and glyph rounding.
+        for _ in 0..FIT_ROUNDS {
+            let width = style.measure_text(text, default_font).x;
+            if width <= max_width || style.font_size <= style.min_font_size {
+                break;
+            }
+            style.font_size = (style.font_size * max_width / width).max(style.min_font_size);
         }
+        if style.measure_text(text, default_font).x <= max_width {
+            return (text.to_owned(), style);
+        }
+        let wrapped = wrap_words(text, max_width, |line| {
+            style.measure_line(line, default_font).x
+        });
+        (wrapped, style)
     }
+
     /// Resolve the effective drawing colour (user-set or theme fallback).
     #[must_use]
     pub fn effective_color(&self) -> Color {
@@ -267,6 +407,88 @@ impl TextStyle {
     }
 }
 
+/// Rounds of shrinking [`TextStyle::fitted`] tries before wrapping.
+const FIT_ROUNDS: usize = 4;
+
+/// `text` with its lines broken at spaces so that each spans at most
+/// `max_width` as reported by `measure`. Existing line breaks are kept.
+fn wrap_words(text: &str, max_width: f32, measure: impl Fn(&str) -> f32) -> String {
+    let mut lines = Vec::new();
+    for paragraph in text.split('\n') {
+        let mut line = String::new();
+        for word in paragraph.split_whitespace() {
+            if line.is_empty() {
+                line.push_str(word);
+                continue;
+            }
+            let candidate = format!("{line} {word}");
+            if measure(&candidate) <= max_width {
+                line = candidate;
+            } else {
+                lines.push(std::mem::replace(&mut line, word.to_owned()));
+            }
+        }
+        lines.push(line);
+    }
+    lines.join("\n")
+}
+
+/// Everything besides the text that affects the size of a measured line.
+/// Fonts are told apart by their atlas texture id.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct MeasureStyle {
+    font: u32,
+    size: u32,
+    spacing: u32,
+}
+
+/// Number of measurements kept before the cache starts over.
+const MEASURE_CACHE_CAPACITY: usize = 4096;
+
+/// Memoized line sizes, grouped by style so that a lookup borrows the line
+/// instead of allocating a key for it.
+#[derive(Debug, Default)]
+struct MeasureCache {
+    sizes: HashMap<MeasureStyle, HashMap<String, Vector2>>,
+    len: usize,
+}
+
+impl MeasureCache {
+    fn get(&self, style: MeasureStyle, line: &str) -> Option<Vector2> {
+        self.sizes.get(&style)?.get(line).copied()
+    }
+
+    fn insert(&mut self, style: MeasureStyle, line: &str, size: Vector2) {
+        // Labels that change every frame would otherwise grow the cache
+        // without bound.
+        if self.len >= MEASURE_CACHE_CAPACITY {
+            self.clear();
+        }
+        let lines = self.sizes.entry(style).or_default();
+        if lines.insert(line.to_owned(), size).is_none() {
+            self.len += 1;
+        }
+    }
+
+    fn clear(&mut self) {
+        self.sizes.clear();
+        self.len = 0;
+    }
+}
+
+thread_local! {
+    static MEASURE_CACHE: RefCell<MeasureCache> = RefCell::new(MeasureCache::default());
+}
+
+/// Forget every memoized text measurement.
+///
+/// [`TextStyle::measure_text`] caches results per string, font, size, and
+/// spacing. Call this after unloading a font or reloading one that may reuse
+/// the same texture, so stale sizes are not returned.
+pub fn clear_text_measure_cache() {
+    MEASURE_CACHE.with_borrow_mut(MeasureCache::clear);
+}
+
 impl Themable for TextStyle {
     fn apply_theme(&mut self, scheme: &crate::colorscheme::Colorscheme) {
         if self.color.is_none() {
@@ -292,8 +514,47 @@ impl TextLabel {
             position: position.into(),
         }
     }
+
+    /// Draw the padded background box for a text block of size `block`
+    /// whose (unrotated) top-left sits at `position + tl`.
+    fn draw_background(
+        &self,
+        rl: &mut RaylibDrawHandle,
+        configs: &TextStyle,
+        block: Vector2,
+        tl: Vector2,
+        color: Color,
+    ) {
+        let pad = configs.background_padding;
+        let size = block + Vector2::new(2.0 * pad, 2.0 * pad);
+        if configs.rotation.abs() < f32::EPSILON {
+            let corner = *self.position + tl - Vector2::new(pad, pad);
+            let rect = Rectangle::new(corner.x, corner.y, size.x, size.y);
+            let half_side = size.x.min(size.y) * 0.5;
+            if configs.corner_radius > 0.0 && half_side > 0.0 {
+                let roundness = (configs.corner_radius / half_side).min(1.0);
+                rl.draw_rectangle_rounded(rect, roundness, BACKGROUND_CORNER_SEGMENTS, color);
+            } else {
+                rl.draw_rectangle_rec(rect, color);
+            }
+        } else {
+            // Same pivot as the glyphs: the anchor point, expressed relative
+            // to the box's top-left corner.
+            let pivot = *self.position + configs.offset;
+            let origin = configs.offset - tl + Vector2::new(pad, pad);
+            rl.draw_rectangle_pro(
+                Rectangle::new(pivot.x, pivot.y, size.x, size.y),
+                origin,
+                configs.rotation,
+                color,
+            );
+        }
+    }
 }
 
+/// Segments per corner used for rounded text backgrounds.
+const BACKGROUND_CORNER_SEGMENTS: i32 = 8;
+
 impl PlotElement for TextLabel {
     type Config = TextStyle;
 
@@ -303,31 +564,145 @@ impl PlotElement for TextLabel {
             Some(fh) => &fh.font,
             None => &default_font,
         };
-        let size = configs.measure_text(&self.text, font);
-        let tl = anchor_text_top_left(size, configs.anchor, configs.offset);
+        // The anchor applies to the whole block; each line is then aligned
+        // inside it according to the horizontal anchor.
+        let block = configs.measure_text(&self.text, font);
+        let tl = anchor_text_top_left(block, configs.anchor, configs.offset);
         let color = configs.effective_color();
-        if configs.rotation.abs() < f32::EPSILON {
-            // Fast path — no rotation
-            rl.draw_text_ex(
-                font,
-                &self.text,
-                *self.position + tl,
-                configs.font_size,
-                configs.spacing,
-                color,
-            );
-        } else {
-            // draw_text_pro rotates around `origin` (relative to `position`)
-            rl.draw_text_pro(
-                font,
-                &self.text,
-                *self.position + tl,
-                Vector2::new(0.0, 0.0),
-                configs.rotation,
-                configs.font_size,
-                configs.spacing,
-                color,
-            );
+        if let Some(background) = configs.background {
+            self.draw_background(rl, configs, block, tl, background);
         }
+        let mut line_top = 0.0;
+        for line in self.text.split('\n') {
+            let width = configs.measure_line(line, font).x;
+            let dx = match configs.anchor.h {
+                HAlign::Left => 0.0,
+                HAlign::Center => (block.x - width) * 0.5,
+                HAlign::Right => block.x - width,
+            };
+            let line_tl = Vector2::new(dx, line_top);
+            line_top += configs.line_height();
+            if configs.rotation.abs() < f32::EPSILON {
+                // Fast path — no rotation
+                rl.draw_text_ex(
+                    font,
+                    line,
+                    *self.position + tl + line_tl,
+                    configs.font_size,
+                    configs.spacing,
+                    color,
+                );
+            } else {
+                // draw_text_pro rotates around `origin` (relative to the line's
+                // top-left), so pivot on the anchor point to keep it fixed.
+                rl.draw_text_pro(
+                    font,
+                    line,
+                    *self.position + configs.offset,
+                    configs.offset - tl - line_tl,
+                    configs.rotation,
+                    configs.font_size,
+                    configs.spacing,
+                    color,
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn top_left(anchor: Anchor) -> (f32, f32) {
+        let tl = anchor_text_top_left(Vector2::new(40.0, 10.0), anchor, Vector2::zero());
+        (tl.x, tl.y)
+    }
+
+    #[test]
+    fn top_left_for_each_anchor() {
+        assert_eq!(top_left(Anchor::TOP_LEFT), (0.0, 0.0));
+        assert_eq!(top_left(Anchor::TOP_CENTER), (-20.0, 0.0));
+        assert_eq!(top_left(Anchor::CENTER), (-20.0, -5.0));
+        assert_eq!(top_left(Anchor::LEFT_MIDDLE), (0.0, -5.0));
+        assert_eq!(top_left(Anchor::RIGHT_MIDDLE), (-40.0, -5.0));
+        assert_eq!(top_left(Anchor::CENTER_BOTTOM), (-20.0, -10.0));
+        assert_eq!(
+            top_left(Anchor {
+                h: HAlign::Right,
+                v: VAlign::Bottom,
+            }),
+            (-40.0, -10.0)
+        );
+    }
+
+    #[test]
+    fn offset_is_added_after_anchoring() {
+        let tl = anchor_text_top_left(
+            Vector2::new(40.0, 10.0),
+            Anchor::RIGHT_MIDDLE,
+            Vector2::new(3.0, -2.0),
+        );
+        assert_eq!((tl.x, tl.y), (-37.0, -7.0));
+    }
+
+    #[test]
+    fn wrap_words_breaks_at_spaces_within_the_width() {
+        #[allow(clippy::cast_precision_loss)]
+        let measure = |line: &str| line.chars().count() as f32 * 10.0;
+        assert_eq!(
+            wrap_words("a long title here", 70.0, measure),
+            "a long\ntitle\nhere"
+        );
+        assert_eq!(
+            wrap_words("unbreakable x\ny", 50.0, measure),
+            "unbreakable\nx\ny"
+        );
+        assert_eq!(wrap_words("fits", 40.0, measure), "fits");
+    }
+
+    const STYLE: MeasureStyle = MeasureStyle {
+        font: 1,
+        size: 20,
+        spacing: 1,
+    };
+
+    #[test]
+    fn measure_cache_hits_only_the_same_line_and_style() {
+        let mut cache = MeasureCache::default();
+        assert!(cache.get(STYLE, "label").is_none());
+        cache.insert(STYLE, "label", Vector2::new(30.0, 10.0));
+        assert_eq!(cache.get(STYLE, "label"), Some(Vector2::new(30.0, 10.0)));
+        assert!(cache.get(STYLE, "other").is_none());
+        let bigger = MeasureStyle { size: 40, ..STYLE };
+        assert!(cache.get(bigger, "label").is_none());
+        // Re-inserting a line replaces it without counting it twice.
+        cache.insert(STYLE, "label", Vector2::new(31.0, 10.0));
+        assert_eq!(cache.len, 1);
+        assert_eq!(cache.get(STYLE, "label"), Some(Vector2::new(31.0, 10.0)));
+    }
+
+    #[test]
+    fn measure_cache_starts_over_at_capacity() {
+        let mut cache = MeasureCache::default();
+        for i in 0..MEASURE_CACHE_CAPACITY {
+            cache.insert(STYLE, &i.to_string(), Vector2::zero());
+        }
+        assert_eq!(cache.len, MEASURE_CACHE_CAPACITY);
+        assert!(cache.get(STYLE, "0").is_some());
+        cache.insert(STYLE, "overflow", Vector2::one());
+        assert_eq!(cache.len, 1);
+        assert!(cache.get(STYLE, "0").is_none());
+        assert_eq!(cache.get(STYLE, "overflow"), Some(Vector2::one()));
+    }
+
+    #[test]
+    fn clearing_forgets_every_measurement() {
+        MEASURE_CACHE.with_borrow_mut(|cache| cache.insert(STYLE, "label", Vector2::one()));
+        clear_text_measure_cache();
+        MEASURE_CACHE.with_borrow(|cache| {
+            assert!(cache.get(STYLE, "label").is_none());
+            assert_eq!(cache.len, 0);
+        });
     }
 }
@@ -12,12 +12,15 @@
 //! * **`SymLog`** : combines a linear region around zero with log wings in
 //!   both the positive and negative directions, useful for data that
 //!   spans several orders of magnitude while including zero.
+//!
+//! Axes of named categories instead use a [`CategoryAxis`], which puts one
+//! labelled tick in the middle of each integer slot.
 
-use std::cmp::Ordering;
+use std::{cmp::Ordering, ops::Range, rc::Rc};
 
 use crate::plottable::{
     common::{linear_spacing, log_spacing},
-    line::Separation,
+    line::{Axis, Separation},
 };
 
 /// A single tick mark along an axis.
@@ -31,6 +34,135 @@ pub struct Tick {
     pub major: bool,
 }
 
+/// Shared, cloneable callback that turns a tick value into its label.
+///
+/// Used by [`TickLabelsConfig`](super::line::TickLabelsConfig) to override
+/// the built-in numeric formatting, e.g. for percentages, currency, or
+/// mapping indices to category names.
+///
+/// ```rust
+/// use locus::prelude::*;
+/// let percent = LabelFormatter::new(|v| format!("{:.0}%", v * 100.0));
+/// assert_eq!(percent.format(0.25), "25%");
+/// ```
+#[derive(Clone)]
+pub struct LabelFormatter(Rc<dyn Fn(f32) -> String>);
+
+impl std::fmt::Debug for LabelFormatter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LabelFormatter").finish_non_exhaustive()
+    }
+}
+
+impl LabelFormatter {
+    /// Wrap a closure that receives the tick value and returns its label.
+    #[must_use]
+    pub fn new(f: impl Fn(f32) -> String + 'static) -> Self {
+        Self(Rc::new(f))
+    }
+
+    /// Produce the label for `value`.
+    #[must_use]
+    pub fn format(&self, value: f32) -> String {
+        (self.0)(value)
+    }
+}
+
+/// Insert `separator` every three digits in the integer part of an already
+/// formatted number, keeping its sign and everything after the integer part
+/// (decimals, exponent, suffix) untouched.
+///
+/// This is the shared grouping step for numeric labels, e.g. tick labels
+/// with [`TickLabelsConfig::thousands_separator`](crate::plottable::line::TickLabelsConfig::thousands_separator).
+///
+/// ```rust
+/// use locus::prelude::*;
+/// assert_eq!(group_thousands("-2000000.25", ','), "-2,000,000.25");
+/// assert_eq!(group_thousands("1500k", ' '), "1 500k");
+/// ```
+#[must_use]
+pub fn group_thousands(formatted: &str, separator: char) -> String {
+    let (sign, rest) = match formatted.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", formatted),
+    };
+    let digits_end = rest
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(rest.len());
+    let (digits, tail) = rest.split_at(digits_end);
+    let mut grouped = String::with_capacity(formatted.len() + digits.len() / 3);
+    grouped.push_str(sign);
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            grouped.push(separator);
+        }
+        grouped.push(digit);
+    }
+    grouped.push_str(tail);
+    grouped
+}
+
+/// Number notation used when rendering tick labels.
+#[derive(Debug, Clone, Copy, Default)]
+pub enum Notation {
+    /// Plain decimal numbers, e.g. `2000000` (the default).
+    #[default]
+    Plain,
+    /// Scientific notation with a power-of-ten exponent, e.g. `2e6`.
+    Scientific,
+    /// Engineering notation with SI suffixes in powers of 1000, e.g. `2M`.
+    ///
+    /// Supported suffixes range from `µ` (10⁻⁶) to `G` (10⁹); values outside
+    /// that range keep the nearest suffix.
+    Engineering,
+    /// Fractions shown as percentages with a fixed number of decimals, e.g.
+    /// `0.25` as `25%` with `decimals: 0`.
+    Percent {
+        /// Decimal places of the percentage.
+        decimals: usize,
+    },
+    /// Exactly this many decimal places, without trimming trailing zeros,
+    /// e.g. `0.5` as `0.50` with `Fixed(2)`.
+    Fixed(usize),
+}
+
+impl Notation {
+    /// Format `value` according to this notation.
+    ///
+    /// ```rust
+    /// use locus::prelude::*;
+    /// assert_eq!(Notation::Engineering.format(1_500.0), "1.5k");
+    /// assert_eq!(Notation::Scientific.format(1_500.0), "1.5e3");
+    /// assert_eq!(Notation::Percent { decimals: 1 }.format(0.125), "12.5%");
+    /// assert_eq!(Notation::Fixed(2).format(0.5), "0.50");
+    /// ```
+    #[must_use]
+    pub fn format(self, value: f32) -> String {
+        match self {
+            Notation::Percent { decimals } => format!("{}%", format_fixed(value * 100.0, decimals)),
+            Notation::Fixed(decimals) => format_fixed(value, decimals),
+            _ if value == 0.0 || !value.is_finite() => format_tick(value, 0),
+            Notation::Plain => format_tick(value, NOTATION_DECIMALS),
+            Notation::Scientific => {
+                let (mantissa, exponent) = split_exponent(value, 1, i32::MIN, i32::MAX);
+                format!("{}e{exponent}", format_mantissa(mantissa))
+            }
+            Notation::Engineering => {
+                let (mantissa, exponent) = split_exponent(value, 3, -6, 9);
+                let suffix = match exponent {
+                    -6 => "µ",
+                    -3 => "m",
+                    3 => "k",
+                    6 => "M",
+                    9 => "G",
+                    _ => "",
+                };
+                format!("{}{suffix}", format_mantissa(mantissa))
+            }
+        }
+    }
+}
+
 /// The type of scale used to generate tick positions.
 #[derive(Debug, Clone, Copy, Default)]
 pub enum Scale {
@@ -58,6 +190,161 @@ pub enum Scale {
     },
 }
 
+/// How many ticks an axis aims for.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TickDensity {
+    /// At most this many ticks, whatever the length of the axis.
+    Fixed(usize),
+    /// About one tick per this many pixels of axis length, so short axes
+    /// get fewer ticks and long axes more. The count is derived when the
+    /// axis is drawn.
+    PerPixels(f32),
+}
+
+impl Default for TickDensity {
+    fn default() -> Self {
+        Self::Fixed(10)
+    }
+}
+
+impl TickDensity {
+    /// The maximum tick count for an axis `length_px` pixels long, as
+    /// passed to [`TickSpec::max_ticks`].
+    ///
+    /// A per-pixel density never asks for fewer than two ticks, and falls
+    /// back to two for a spacing or length that is not positive and finite.
+    ///
+    /// ```rust
+    /// use locus::prelude::*;
+    /// assert_eq!(TickDensity::PerPixels(80.0).max_ticks(400.0), 5);
+    /// assert_eq!(TickDensity::PerPixels(80.0).max_ticks(100.0), 2);
+    /// assert_eq!(TickDensity::Fixed(7).max_ticks(100.0), 7);
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn max_ticks(self, length_px: f32) -> usize {
+        match self {
+            Self::Fixed(count) => count,
+            Self::PerPixels(spacing) => {
+                let count = (length_px.abs() / spacing).floor();
+                if spacing > 0.0 && count.is_finite() {
+                    (count as usize).max(2)
+                } else {
+                    2
+                }
+            }
+        }
+    }
+}
+
+/// Named categories laid out on integer slots of an axis.
+///
+/// Category `i` occupies the slot `i - 0.5..i + 0.5`, the layout used by the
+/// [bar charts](crate::plottable::bar), and gets one tick labelled with its
+/// name in the middle of the slot. Set it as
+/// [`TickLabelsConfig::x_categories`](super::line::TickLabelsConfig::x_categories)
+/// to draw the names instead of numbers; they are written as given, without
+/// numeric formatting. Long names can be rotated with the tick labels'
+/// `label_rotation` or cut short with [`CategoryAxis::with_max_chars`].
+///
+/// ```rust
+/// use locus::prelude::*;
+/// let days = CategoryAxis::new(["Monday", "Tuesday", "Wednesday"]).with_max_chars(3);
+/// let ticks = TickLabelsBuilder::default()
+///     .x_categories(days.clone())
+///     .label_rotation(-30.0)
+///     .build()
+///     .unwrap();
+/// let axis = days.axis(0.0..10.0);
+/// assert_eq!(days.label(2).as_deref(), Some("Wed..."));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CategoryAxis {
+    /// Category names, in slot order.
+    pub labels: Vec<String>,
+    /// Longest name drawn in full. Longer ones keep this many characters
+    /// followed by `...`. `None` never truncates.
+    pub max_chars: Option<usize>,
+}
+
+impl CategoryAxis {
+    /// Create an axis with one slot per name in `labels`.
+    #[must_use]
+    pub fn new<L: Into<String>>(labels: impl IntoIterator<Item = L>) -> Self {
+        Self {
+            labels: labels.into_iter().map(Into::into).collect(),
+            max_chars: None,
+        }
+    }
+
+    /// Truncate names longer than `max_chars` characters with an ellipsis.
+    #[must_use]
+    pub fn with_max_chars(mut self, max_chars: usize) -> Self {
+        self.max_chars = Some(max_chars);
+        self
+    }
+
+    /// Number of categories.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.labels.len()
+    }
+
+    /// Whether there are no categories.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty()
+    }
+
+    /// The range covering every slot, `-0.5..n - 0.5`.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn range(&self) -> Range<f32> {
+        -0.5..self.len() as f32 - 0.5
+    }
+
+    /// An axis spanning every slot along x and `y` along y.
+    #[must_use]
+    pub fn axis(&self, y: Range<f32>) -> Axis {
+        Axis::with_limits(self.range(), y)
+    }
+
+    /// The name drawn for category `index`, truncated to `max_chars`.
+    /// `None` past the last category.
+    #[must_use]
+    pub fn label(&self, index: usize) -> Option<String> {
+        let label = self.labels.get(index)?;
+        Some(match self.max_chars {
+            Some(max) if label.chars().count() > max => {
+                let mut short: String = label.chars().take(max).collect();
+                // The default raylib font has no glyph for `…`.
+                short.push_str("...");
+                short
+            }
+            _ => label.clone(),
+        })
+    }
+
+    /// One major tick per category, at its slot centre and labelled with
+    /// its name.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn tick_set(&self) -> TickSet {
+        TickSet {
+            step: Some(1.0),
+            ticks: (0..self.len())
+                .filter_map(|i| {
+                    Some(Tick {
+                        value: i as f32,
+                        label: self.label(i)?,
+                        major: true,
+                    })
+                })
+                .collect(),
+        }
+    }
+}
+
 /// Parameters that fully describe how to generate ticks for one axis.
 #[derive(Debug, Clone, Copy)]
 pub struct TickSpec {
@@ -98,6 +385,44 @@ impl TickSet {
             } => Self::symlog_ticks(min, max, base, lin_threshold, include_minor, spec.max_ticks),
         }
     }
+
+    /// Ticks at exactly `positions`, bypassing the nice-number generators.
+    ///
+    /// Every tick is major and labelled with the shortest decimal form of
+    /// its value, so no minor ticks are produced. Non-finite positions are
+    /// dropped, and the rest sorted with duplicates removed.
+    ///
+    /// ```rust
+    /// use locus::prelude::*;
+    /// let set = TickSet::explicit(&[10.0, 1.0, 2.5, 50.0, f32::NAN]);
+    /// let labels: Vec<_> = set.ticks.iter().map(|t| t.label.as_str()).collect();
+    /// assert_eq!(labels, ["1", "2.5", "10", "50"]);
+    /// ```
+    #[must_use]
+    pub fn explicit(positions: &[f32]) -> Self {
+        let mut values: Vec<f32> = positions
+            .iter()
+            .copied()
+            .filter(|v| v.is_finite())
+            .collect();
+        values.sort_by(f32::total_cmp);
+        values.dedup();
+        Self {
+            step: None,
+            ticks: values
+                .into_iter()
+                .map(|v| {
+                    // `-0.0` prints as "-0"; `0.0` equals it and prints as "0".
+                    let v = if v == 0.0 { 0.0 } else { v };
+                    Tick {
+                        value: v,
+                        label: v.to_string(),
+                        major: true,
+                    }
+                })
+                .collect(),
+        }
+    }
     #[allow(
         clippy::cast_precision_loss,
         clippy::cast_possible_truncation,
@@ -257,6 +582,45 @@ fn format_tick(v: f32, decimals: usize) -> String {
     if s == "-0" { "0".to_string() } else { s }
 }
 
+/// `v` with exactly `decimals` decimal places; negative zero prints as zero.
+fn format_fixed(v: f32, decimals: usize) -> String {
+    let s = format!("{v:.decimals$}");
+    if s.starts_with('-') && s[1..].chars().all(|c| c == '0' || c == '.') {
+        s[1..].to_string()
+    } else {
+        s
+    }
+}
+
+/// Decimal places kept when formatting values outside of a [`TickSet`].
+const NOTATION_DECIMALS: usize = 3;
+
+/// Splits `value` into `mantissa * 10^exponent`, where `exponent` is a
+/// multiple of `step` chosen so that `1 <= |mantissa| < 10^step`, limited to
+/// `[min_exp, max_exp]`.
+fn split_exponent(value: f32, step: i32, min_exp: i32, max_exp: i32) -> (f64, i32) {
+    let factor = 10.0_f64.powi(step);
+    // Half a unit in the last printed decimal, so that e.g. 999.9999 is
+    // promoted to `1k` instead of printing as `1000`.
+    let rounding = 0.5 * 10.0_f64.powi(-i32::try_from(NOTATION_DECIMALS).unwrap_or(0));
+    let mut mantissa = f64::from(value);
+    let mut exponent = 0;
+    while mantissa.abs() >= factor - rounding && exponent <= max_exp - step {
+        mantissa /= factor;
+        exponent += step;
+    }
+    while mantissa.abs() < 1.0 - rounding && exponent >= min_exp + step {
+        mantissa *= factor;
+        exponent -= step;
+    }
+    (mantissa, exponent)
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn format_mantissa(mantissa: f64) -> String {
+    format_tick(mantissa as f32, NOTATION_DECIMALS)
+}
+
 fn format_log_label(v: f32) -> String {
     // Keep labels compact
     if (0.01..1000.0).contains(&v) {
@@ -265,3 +629,85 @@ fn format_log_label(v: f32) -> String {
         format!("{v:.0e}")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn log_ticks_keep_a_power_of_the_base_at_the_maximum() {
+        let ticks = TickSet::generate_ticks(
+            1.0,
+            100.0,
+            TickSpec {
+                scale: Scale::Log {
+                    base: 10.0,
+                    include_minor: false,
+                },
+                max_ticks: 5,
+                separation: Separation::Auto,
+            },
+        );
+        let values: Vec<f32> = ticks.ticks.iter().map(|t| t.value).collect();
+        assert_eq!(values, vec![1.0, 10.0, 100.0]);
+    }
+
+    #[test]
+    fn category_ticks_sit_in_slot_centres() {
+        let categories = CategoryAxis::new(["a", "bb", "a very long name"]).with_max_chars(6);
+        assert_eq!(categories.range(), -0.5..2.5);
+        let ticks = categories.tick_set();
+        let placed: Vec<_> = ticks
+            .ticks
+            .iter()
+            .map(|t| (t.value, t.label.as_str()))
+            .collect();
+        assert_eq!(placed, [(0.0, "a"), (1.0, "bb"), (2.0, "a very...")]);
+        assert_eq!(categories.label(3), None);
+    }
+
+    #[test]
+    fn thousands_grouping() {
+        assert_eq!(group_thousands("2000000", ','), "2,000,000");
+        assert_eq!(group_thousands("-1234567", ','), "-1,234,567");
+        assert_eq!(group_thousands("12345.6789", ' '), "12 345.6789");
+        assert_eq!(group_thousands("-0.5", ','), "-0.5");
+        assert_eq!(group_thousands("999", ','), "999");
+        assert_eq!(group_thousands("100000", ','), "100,000");
+        assert_eq!(group_thousands("1000%", ','), "1,000%");
+    }
+
+    #[test]
+    fn percent_and_fixed_keep_their_decimals() {
+        assert_eq!(Notation::Percent { decimals: 0 }.format(0.25), "25%");
+        assert_eq!(Notation::Percent { decimals: 0 }.format(0.0), "0%");
+        assert_eq!(Notation::Percent { decimals: 2 }.format(1.0), "100.00%");
+        assert_eq!(Notation::Fixed(2).format(1.5), "1.50");
+        assert_eq!(Notation::Fixed(1).format(0.0), "0.0");
+        assert_eq!(Notation::Fixed(1).format(-0.01), "0.0");
+        assert_eq!(Notation::Fixed(0).format(-2.4), "-2");
+    }
+
+    #[test]
+    fn engineering_uses_si_suffixes() {
+        assert_eq!(Notation::Engineering.format(1_500.0), "1.5k");
+        assert_eq!(Notation::Engineering.format(0.002), "2m");
+        assert_eq!(Notation::Engineering.format(2_000_000.0), "2M");
+        assert_eq!(Notation::Engineering.format(-3.0e-6), "-3µ");
+    }
+
+    #[test]
+    fn engineering_boundaries() {
+        assert_eq!(Notation::Engineering.format(1_000.0), "1k");
+        assert_eq!(Notation::Engineering.format(1.0), "1");
+        assert_eq!(Notation::Engineering.format(999.0), "999");
+        assert_eq!(Notation::Engineering.format(0.0), "0");
+    }
+
+    #[test]
+    fn scientific_notation() {
+        assert_eq!(Notation::Scientific.format(2_000_000.0), "2e6");
+        assert_eq!(Notation::Scientific.format(0.025), "2.5e-2");
+        assert_eq!(Notation::Scientific.format(1.0), "1e0");
+    }
+}
@@ -0,0 +1,331 @@
+//! Gantt-style timelines of tasks over time.
+//!
+//! A [`Timeline`] draws one horizontal bar per task, spanning its start to
+//! its end along the x axis, in the row the task is assigned to. Row `r`
+//! occupies the slot `r - 0.5..r + 0.5` on the y axis, so several tasks may
+//! share a row and tick `r` sits beside its bars. Each bar can carry its
+//! label inside or beside it.
+//!
+//! Bars take the color of their row from the theme cycle unless set
+//! explicitly, or a [`TaskColor`] closure colors every task individually.
+//! Pair the timeline with a
+//! [`LabelFormatter`](crate::plottable::ticks::LabelFormatter) on the x tick
+//! labels to show times as dates. Rows grow upward with `y`; invert the y
+//! axis of the graph to list the first row at the top.
+//!
+//! # Example
+//!
+//! ```rust
+//! use locus::prelude::*;
+//! use raylib::color::Color;
+//! let timeline = Timeline::new([
+//!     (0, 0.0, 3.0, "design"),
+//!     (1, 2.0, 7.0, "build"),
+//!     (2, 6.5, 9.0, "ship"),
+//! ]);
+//! let config = TimelineConfigBuilder::default()
+//!     .row_spacing(0.3)
+//!     .label_position(TimelineLabel::Beside)
+//!     .task_color(TaskColor::new(|task, _| {
+//!         if task.end - task.start > 4.0 { Color::ORANGE } else { Color::SKYBLUE }
+//!     }))
+//!     .build()
+//!     .unwrap();
+//! let graph = Graph::new(timeline);
+//! ```
+
+use std::rc::Rc;
+
+use derive_builder::Builder;
+use raylib::prelude::*;
+
+use crate::{
+    colorscheme::{Colorscheme, Themable, indexed_color},
+    plottable::{
+        point::{Datapoint, Screenpoint},
+        text::{Anchor, TextLabel, TextStyle, TextStyleBuilder},
+        view::{DataBBox, ViewTransformer},
+    },
+    plotter::ChartElement,
+};
+
+/// One task of a [`Timeline`]: a labelled span of time in a row.
+#[derive(Debug, Clone)]
+pub struct TimelineTask {
+    /// Row the bar is drawn in.
+    pub row: usize,
+    /// Time the task starts at.
+    pub start: f32,
+    /// Time the task ends at.
+    pub end: f32,
+    /// Text drawn with the bar, if labels are shown.
+    pub label: String,
+}
+
+/// Horizontal bars of tasks laid out in rows across a time axis.
+///
+/// See the [module documentation](self) for an example.
+#[derive(Debug, Clone)]
+pub struct Timeline {
+    /// The tasks, drawn in order.
+    pub tasks: Vec<TimelineTask>,
+}
+
+impl Timeline {
+    /// Create a timeline from `(row, start, end, label)` tasks.
+    #[must_use]
+    pub fn new<L: Into<String>>(tasks: impl IntoIterator<Item = (usize, f32, f32, L)>) -> Self {
+        Self {
+            tasks: tasks
+                .into_iter()
+                .map(|(row, start, end, label)| TimelineTask {
+                    row,
+                    start,
+                    end,
+                    label: label.into(),
+                })
+                .collect(),
+        }
+    }
+
+    /// Number of rows spanned by the tasks.
+    fn row_count(&self) -> usize {
+        self.tasks.iter().map(|t| t.row + 1).max().unwrap_or(0)
+    }
+}
+
+/// Shared, cloneable callback that picks the color of a task from the task
+/// and its index.
+///
+/// ```rust
+/// use locus::prelude::*;
+/// use raylib::color::Color;
+/// let late = TaskColor::new(|task, _| if task.end > 10.0 { Color::RED } else { Color::GRAY });
+/// ```
+#[derive(Clone)]
+pub struct TaskColor(Rc<dyn Fn(&TimelineTask, usize) -> Color>);
+
+impl std::fmt::Debug for TaskColor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TaskColor").finish_non_exhaustive()
+    }
+}
+
+impl TaskColor {
+    /// Wrap a closure that receives a task and its index and returns its
+    /// color.
+    #[must_use]
+    pub fn new(f: impl Fn(&TimelineTask, usize) -> Color + 'static) -> Self {
+        Self(Rc::new(f))
+    }
+
+    /// The color of `task`, the `index`-th of its timeline.
+    #[must_use]
+    pub fn color(&self, task: &TimelineTask, index: usize) -> Color {
+        (self.0)(task, index)
+    }
+}
+
+/// Where a [`Timeline`] writes the label of each bar.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TimelineLabel {
+    /// No labels.
+    #[default]
+    Hidden,
+    /// Centred on the bar.
+    Inside,
+    /// Just past the end of the bar.
+    Beside,
+}
+
+/// Configuration for [`Timeline`] rendering.
+///
+/// A bar takes the color returned by `task_color` when set. Otherwise, when
+/// `colors` is `None` it is resolved from the
+/// [`Colorscheme`](crate::colorscheme::Colorscheme) cycle during theme
+/// application, and row `r` uses `colors[r % colors.len()]`.
+#[derive(Debug, Clone, Builder)]
+#[builder(pattern = "owned")]
+#[builder(default)]
+pub struct TimelineConfig {
+    /// Fraction of each row slot left empty between the bars of
+    /// neighbouring rows, in `0.0..1.0`.
+    pub row_spacing: f32,
+    /// Fixed bar height in pixels. `None` fills the row slot less the
+    /// spacing, so bars grow and shrink with the view.
+    #[builder(setter(into, strip_option))]
+    pub bar_height: Option<f32>,
+    /// Explicit per-row colors. `None` means "use the theme cycle".
+    #[builder(setter(into, strip_option))]
+    pub colors: Option<Vec<Color>>,
+    /// Per-task color, taking precedence over `colors`.
+    #[builder(setter(into, strip_option))]
+    pub task_color: Option<TaskColor>,
+    /// Alpha applied to the bar fill.
+    pub alpha: f32,
+    /// Optional border as `(color, thickness)`. `None` means no border.
+    #[builder(setter(into, strip_option))]
+    pub border: Option<(Color, f32)>,
+    /// Where to write the task labels.
+    pub label_position: TimelineLabel,
+    /// Pixels between the end of a bar and a label drawn beside it.
+    pub label_offset: f32,
+    /// Style of the task labels. The anchor is chosen from
+    /// `label_position`.
+    pub label_style: TextStyle,
+}
+
+impl Default for TimelineConfig {
+    fn default() -> Self {
+        Self {
+            row_spacing: 0.4,
+            bar_height: None,
+            colors: None,
+            task_color: None,
+            alpha: 1.0,
+            border: None,
+            label_position: TimelineLabel::Hidden,
+            label_offset: 6.0,
+            label_style: TextStyleBuilder::default()
+                .font_size(14.0)
+                .build()
+                .expect("Will never fail"),
+        }
+    }
+}
+
+impl TimelineConfig {
+    /// Fill color of the `index`-th task.
+    fn bar_color(&self, task: &TimelineTask, index: usize) -> Color {
+        if let Some(task_color) = &self.task_color {
+            return task_color.color(task, index);
+        }
+        indexed_color(self.colors.as_deref(), None, task.row)
+    }
+
+    /// Screen rectangle of the bar of `task`: its time span across, and
+    /// either the fixed pixel height or the row slot less the spacing.
+    #[allow(clippy::cast_precision_loss)]
+    fn bar_rect(&self, task: &TimelineTask, view: &ViewTransformer) -> Rectangle {
+        let row = task.row as f32;
+        let half = (1.0 - self.row_spacing.clamp(0.0, 0.95)) * 0.5;
+        let a = view.to_screen(&Datapoint::new(task.start.min(task.end), row - half));
+        let b = view.to_screen(&Datapoint::new(task.start.max(task.end), row + half));
+        let mut rect = Rectangle::new(
+            a.x.min(b.x),
+            a.y.min(b.y),
+            (a.x - b.x).abs(),
+            (a.y - b.y).abs(),
+        );
+        if let Some(height) = self.bar_height {
+            rect.y += (rect.height - height) * 0.5;
+            rect.height = height;
+        }
+        rect
+    }
+}
+
+impl ChartElement for Timeline {
+    type Config = TimelineConfig;
+
+    fn draw_in_view(
+        &self,
+        rl: &mut RaylibDrawHandle,
+        configs: &Self::Config,
+        view: &ViewTransformer,
+    ) {
+        for (i, task) in self.tasks.iter().enumerate() {
+            if !(task.start.is_finite() && task.end.is_finite()) {
+                continue;
+            }
+            let rect = configs.bar_rect(task, view);
+            rl.draw_rectangle_rec(rect, configs.bar_color(task, i).alpha(configs.alpha));
+            if let Some((color, thickness)) = configs.border {
+                rl.draw_rectangle_lines_ex(rect, thickness, color);
+            }
+
+            let middle = rect.y + rect.height * 0.5;
+            let (anchor, origin) = match configs.label_position {
+                TimelineLabel::Hidden => continue,
+                TimelineLabel::Inside => (
+                    Anchor::CENTER,
+                    Screenpoint::new(rect.x + rect.width * 0.5, middle),
+                ),
+                TimelineLabel::Beside => (
+                    Anchor::LEFT_MIDDLE,
+                    Screenpoint::new(rect.x + rect.width + configs.label_offset, middle),
+                ),
+            };
+            if task.label.is_empty() {
+                continue;
+            }
+            let mut style = configs.label_style.clone();
+            style.anchor = anchor;
+            TextLabel::new(task.label.clone(), origin).plot(rl, &style);
+        }
+    }
+
+    /// Spans the earliest start to the latest end, and the slots of every
+    /// row up to the last one used.
+    #[allow(clippy::cast_precision_loss)]
+    fn data_bounds(&self) -> DataBBox {
+        let (start, end) = self
+            .tasks
+            .iter()
+            .flat_map(|t| [t.start, t.end])
+            .filter(|v| v.is_finite())
+            .fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), v| {
+                (lo.min(v), hi.max(v))
+            });
+        if start > end {
+            return DataBBox::from_min_max((0.0, 0.0), (0.0, 0.0));
+        }
+        let rows = self.row_count().max(1) as f32;
+        DataBBox::from_min_max((start, -0.5), (end, rows - 0.5))
+    }
+}
+
+impl Themable for TimelineConfig {
+    fn apply_theme(&mut self, scheme: &Colorscheme) {
+        if self.colors.is_none() && !scheme.cycle.is_empty() {
+            self.colors = Some(scheme.cycle.to_vec());
+        }
+        self.label_style.apply_theme(scheme);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bounds_cover_every_task_and_row() {
+        let timeline = Timeline::new([
+            (0, 2.0, 5.0, "a"),
+            (3, 1.0, f32::NAN, "b"),
+            (1, 4.0, 9.0, "c"),
+        ]);
+        let bounds = timeline.data_bounds();
+        assert_eq!((bounds.minimum.x, bounds.maximum.x), (1.0, 9.0));
+        assert_eq!((bounds.minimum.y, bounds.maximum.y), (-0.5, 3.5));
+    }
+
+    #[test]
+    fn task_color_overrides_row_colors() {
+        let timeline = Timeline::new([(0, 0.0, 1.0, "a"), (1, 0.0, 1.0, "b")]);
+        let rows = TimelineConfigBuilder::default()
+            .colors(vec![Color::RED, Color::BLUE])
+            .build()
+            .unwrap();
+        assert_eq!(rows.bar_color(&timeline.tasks[1], 1), Color::BLUE);
+        let per_task = TimelineConfigBuilder::default()
+            .colors(vec![Color::RED])
+            .task_color(TaskColor::new(|_, i| {
+                if i == 0 { Color::GREEN } else { Color::GOLD }
+            }))
+            .build()
+            .unwrap();
+        assert_eq!(per_task.bar_color(&timeline.tasks[0], 0), Color::GREEN);
+        assert_eq!(per_task.bar_color(&timeline.tasks[1], 1), Color::GOLD);
+    }
+}
@@ -9,15 +9,19 @@
 //! * [`Viewport`] : defines a rectangular region on the screen together
 //!   with inner [`Margins`], producing an outer bounding box (for the
 //!   background / chrome) and an inner bounding box (for the data area).
-//! * [`ViewTransformer`] : the core mapping that linearly projects
-//!   [`Datapoint`]s to [`Screenpoint`]s, including y-axis inversion
-//!   (data-space y grows up, screen-space y grows down).
+//! * [`ViewTransformer`] : the core mapping that projects [`Datapoint`]s
+//!   to [`Screenpoint`]s, including y-axis inversion (data-space y grows
+//!   up, screen-space y grows down). Each axis is spaced according to its
+//!   [`ScaleSpec`], so data lines up with log or symlog ticks.
 
 use std::ops::Deref;
 
 use raylib::math::Vector2;
 
-use crate::plottable::point::{Datapoint, Screenpoint};
+use crate::plottable::{
+    point::{Datapoint, Screenpoint},
+    ticks::Scale,
+};
 
 /// A generic axis-aligned bounding box over point type `P`.
 ///
@@ -26,7 +30,9 @@ use crate::plottable::point::{Datapoint, Screenpoint};
 ///
 /// The invariant `minimum.x <= maximum.x` and `minimum.y <= maximum.y` is
 /// enforced by [`BBox::new`] (which re-orders the components) and
-/// debug-asserted by [`BBox::from_min_max`].
+/// debug-asserted by [`BBox::from_min_max`]. The one exception is an
+/// *empty* axis, running from `+∞` down to `-∞`, which places no constraint
+/// on that axis: [`union`](BBox::union) takes it from the other box.
 #[derive(Debug, Clone, Copy)]
 pub struct BBox<P> {
     /// Component-wise minimum corner.
@@ -69,15 +75,35 @@ where
         let minimum: P = minimum.into();
         let maximum: P = maximum.into();
         debug_assert!(
-            minimum.x <= maximum.x,
+            minimum.x <= maximum.x || empty_axis(minimum.x, maximum.x),
             "BBox invariant violated: min.x > max.x"
         );
         debug_assert!(
-            minimum.y <= maximum.y,
+            minimum.y <= maximum.y || empty_axis(minimum.y, maximum.y),
             "BBox invariant violated: min.y > max.y"
         );
         Self { minimum, maximum }
     }
+
+    /// A box over `y0..=y1` (in either order) with an empty x axis, for
+    /// elements that span whatever x range is shown.
+    #[must_use]
+    pub fn spanning_y(y0: f32, y1: f32) -> Self {
+        Self::from_min_max(
+            Vector2::new(f32::INFINITY, y0.min(y1)),
+            Vector2::new(f32::NEG_INFINITY, y0.max(y1)),
+        )
+    }
+
+    /// A box over `x0..=x1` (in either order) with an empty y axis, for
+    /// elements that span whatever y range is shown.
+    #[must_use]
+    pub fn spanning_x(x0: f32, x1: f32) -> Self {
+        Self::from_min_max(
+            Vector2::new(x0.min(x1), f32::INFINITY),
+            Vector2::new(x0.max(x1), f32::NEG_INFINITY),
+        )
+    }
     /// Width of the bounding box (along the x-axis).
     pub fn width(&self) -> f32 {
         self.maximum.x - self.minimum.x
@@ -87,6 +113,82 @@ where
     pub fn height(&self) -> f32 {
         self.maximum.y - self.minimum.y
     }
+
+    /// The smallest box containing both `self` and `other`.
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Self {
+        Self::from_min_max(
+            Vector2::new(
+                self.minimum.x.min(other.minimum.x),
+                self.minimum.y.min(other.minimum.y),
+            ),
+            Vector2::new(
+                self.maximum.x.max(other.maximum.x),
+                self.maximum.y.max(other.maximum.y),
+            ),
+        )
+    }
+
+    /// Whether `p` lies inside the box. Points on the edges count as inside.
+    pub fn contains(&self, p: &P) -> bool {
+        (self.minimum.x..=self.maximum.x).contains(&p.x)
+            && (self.minimum.y..=self.maximum.y).contains(&p.y)
+    }
+
+    /// Whether the two boxes overlap. Boxes that only touch along an edge
+    /// or at a corner count as intersecting.
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.minimum.x <= other.maximum.x
+            && other.minimum.x <= self.maximum.x
+            && self.minimum.y <= other.maximum.y
+            && other.minimum.y <= self.maximum.y
+    }
+}
+
+/// Whether `min..max` is the empty axis `+∞..-∞` (see [`BBox`]).
+fn empty_axis(min: f32, max: f32) -> bool {
+    min.is_infinite() && max.is_infinite() && min > max
+}
+
+/// Padding applied on each side of a zero-extent axis by
+/// [`DataBBox::padded`] and [`DataBBox::padded_abs`], so the projection
+/// never divides by zero.
+const ZERO_EXTENT_PADDING: f32 = 1.0;
+
+impl DataBBox {
+    /// Expand each side by `fraction` of the box's extent along that axis,
+    /// e.g. `0.05` for 5% breathing room. Negative fractions are treated as
+    /// zero.
+    ///
+    /// An axis with zero extent is padded by a small fixed amount instead,
+    /// so a [`ViewTransformer`] built from the result never collapses.
+    #[must_use]
+    pub fn padded(&self, fraction: f32) -> Self {
+        let fraction = fraction.max(0.0);
+        self.padded_abs(self.width() * fraction, self.height() * fraction)
+    }
+
+    /// Expand the left and right sides by `dx` and the bottom and top by
+    /// `dy`, in data units. Negative amounts are treated as zero.
+    ///
+    /// An axis that would still have zero extent is padded by a small fixed
+    /// amount instead.
+    #[must_use]
+    pub fn padded_abs(&self, dx: f32, dy: f32) -> Self {
+        let pad = |extent: f32, amount: f32| {
+            let amount = amount.max(0.0);
+            if extent + 2.0 * amount <= f32::EPSILON {
+                ZERO_EXTENT_PADDING
+            } else {
+                amount
+            }
+        };
+        let (dx, dy) = (pad(self.width(), dx), pad(self.height(), dy));
+        Self::from_min_max(
+            (self.minimum.x - dx, self.minimum.y - dy),
+            (self.maximum.x + dx, self.maximum.y + dy),
+        )
+    }
 }
 
 /// Pixel insets applied to a [`Viewport`] to separate the outer frame from
@@ -139,7 +241,7 @@ pub struct Viewport {
     pub(crate) y: f32,
     pub(crate) width: f32,
     pub(crate) height: f32,
-    margins: Margins,
+    pub(crate) margins: Margins,
 }
 
 impl Default for Viewport {
@@ -181,6 +283,112 @@ impl Viewport {
         self
     }
 
+    /// Move the viewport's top-left corner to `(x, y)`, keeping its size.
+    #[inline]
+    #[must_use]
+    pub const fn with_position(mut self, x: f32, y: f32) -> Self {
+        self.x = x;
+        self.y = y;
+        self
+    }
+
+    /// Set the viewport's size, keeping its top-left corner.
+    #[inline]
+    #[must_use]
+    pub const fn with_size(mut self, width: f32, height: f32) -> Self {
+        self.width = width;
+        self.height = height;
+        self
+    }
+
+    /// Resize to `width` by `height`, scaling the position by the same
+    /// factors so a layout of viewports keeps its proportions, e.g. when the
+    /// window is resized:
+    ///
+    /// ```rust
+    /// use locus::prelude::*;
+    /// // The right half of an 800x600 window...
+    /// let right = Viewport::new(400.0, 0.0, 400.0, 600.0);
+    /// // ...stays the right half after the window grows to 1200x600.
+    /// let right = right.resized(600.0, 600.0);
+    /// assert_eq!((right.x(), right.width()), (600.0, 600.0));
+    /// ```
+    ///
+    /// Margins stay in pixels. A zero-sized axis keeps its position.
+    #[must_use]
+    pub fn resized(&self, width: f32, height: f32) -> Self {
+        let scale = |old: f32, new: f32| if old > 0.0 { new / old } else { 1.0 };
+        Self {
+            x: self.x * scale(self.width, width),
+            y: self.y * scale(self.height, height),
+            width,
+            height,
+            margins: self.margins,
+        }
+    }
+
+    /// Left edge in screen pixels.
+    #[inline]
+    #[must_use]
+    pub const fn x(&self) -> f32 {
+        self.x
+    }
+
+    /// Top edge in screen pixels.
+    #[inline]
+    #[must_use]
+    pub const fn y(&self) -> f32 {
+        self.y
+    }
+
+    /// Full width in pixels, margins included.
+    #[inline]
+    #[must_use]
+    pub const fn width(&self) -> f32 {
+        self.width
+    }
+
+    /// Full height in pixels, margins included.
+    #[inline]
+    #[must_use]
+    pub const fn height(&self) -> f32 {
+        self.height
+    }
+
+    /// Insets between the outer frame and the plotting area.
+    #[inline]
+    #[must_use]
+    pub const fn margins(&self) -> Margins {
+        self.margins
+    }
+
+    /// Largest sub-viewport whose inner area has the given `aspect` ratio
+    /// (width / height), centred inside this viewport. Margins are preserved
+    /// around the shrunk inner area.
+    ///
+    /// Space along the axis with excess room is left unused (letterboxing).
+    /// Non-positive or non-finite ratios, or an empty inner area, return the
+    /// viewport unchanged.
+    #[must_use]
+    pub fn letterboxed(&self, aspect: f32) -> Self {
+        let inner = self.inner_bbox();
+        let (width, height) = (inner.width(), inner.height());
+        if !(aspect.is_finite() && aspect > 0.0) || width <= 0.0 || height <= 0.0 {
+            return *self;
+        }
+        let mut boxed = *self;
+        if width / height > aspect {
+            let excess = width - height * aspect;
+            boxed.width -= excess;
+            boxed.x += excess * 0.5;
+        } else {
+            let excess = height - width / aspect;
+            boxed.height -= excess;
+            boxed.y += excess * 0.5;
+        }
+        boxed
+    }
+
     /// Outer rectangle in screen coordinates.
     /// NOTE: this returns a *numeric* bounding box where `minimum.y <= maximum.y`.
     /// In Raylib screen space that means:
@@ -222,8 +430,108 @@ fn map_val(val: f32, in_min: f32, in_max: f32, out_min: f32, out_max: f32) -> f3
     }
     (val - in_min) / (in_max - in_min) * (out_max - out_min) + out_min
 }
-/// Transforms [`Datapoint`]s to [`Screenpoint`]s by linearly mapping the
-/// data bounding box onto the screen bounding box.
+/// How data values along one axis are spaced on screen.
+///
+/// The transform is applied to a coordinate before the linear pixel mapping
+/// of [`ViewTransformer`], so equal screen distances cover equal *scaled*
+/// distances. Use the same scale as the axis ticks so data lines up with
+/// them; a tick [`Scale`] converts with `ScaleSpec::from`.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum ScaleSpec {
+    /// Values are spaced uniformly (the default).
+    #[default]
+    Linear,
+    /// Values are spaced by their logarithm. Non-positive values have none:
+    /// [`Graph`](crate::graph::Graph) starts such an axis at the smallest
+    /// positive data value, and non-positive points fall far below it.
+    Log {
+        /// Logarithm base (must be > 1).
+        base: f32,
+    },
+    /// Linear within `lin_threshold` of zero and logarithmic outside, so the
+    /// axis can span zero and both signs.
+    SymLog {
+        /// Logarithm base (must be > 1).
+        base: f32,
+        /// Half-width of the linear region centred on zero (must be > 0).
+        lin_threshold: f32,
+    },
+}
+
+impl ScaleSpec {
+    /// Map a data value to its position along the scaled axis.
+    ///
+    /// ```rust
+    /// use locus::prelude::*;
+    /// let log = ScaleSpec::Log { base: 10.0 };
+    /// assert!((log.forward(1000.0) - 3.0).abs() < 1e-5);
+    /// assert!((log.inverse(log.forward(42.0)) - 42.0).abs() < 1e-3);
+    /// ```
+    #[must_use]
+    pub fn forward(self, value: f32) -> f32 {
+        match self {
+            Self::Linear => value,
+            Self::Log { base } => value.max(f32::MIN_POSITIVE).log(base),
+            Self::SymLog {
+                base,
+                lin_threshold,
+            } => {
+                if value.abs() <= lin_threshold {
+                    value
+                } else {
+                    value.signum() * lin_threshold * (1.0 + (value.abs() / lin_threshold).log(base))
+                }
+            }
+        }
+    }
+
+    /// Map a position along the scaled axis back to a data value; the
+    /// inverse of [`forward`](ScaleSpec::forward).
+    #[must_use]
+    pub fn inverse(self, position: f32) -> f32 {
+        match self {
+            Self::Linear => position,
+            Self::Log { base } => base.powf(position),
+            Self::SymLog {
+                base,
+                lin_threshold,
+            } => {
+                if position.abs() <= lin_threshold {
+                    position
+                } else {
+                    position.signum()
+                        * lin_threshold
+                        * base.powf(position.abs() / lin_threshold - 1.0)
+                }
+            }
+        }
+    }
+}
+
+impl From<Scale> for ScaleSpec {
+    fn from(scale: Scale) -> Self {
+        match scale {
+            Scale::Linear => Self::Linear,
+            Scale::Log { base, .. } => Self::Log { base },
+            Scale::SymLog {
+                base,
+                lin_threshold,
+                ..
+            } => Self::SymLog {
+                base,
+                lin_threshold,
+            },
+        }
+    }
+}
+
+/// Transforms [`Datapoint`]s to [`Screenpoint`]s by mapping the data
+/// bounding box onto the screen bounding box.
+///
+/// Each axis is first spaced by its [`ScaleSpec`] (linear by default, see
+/// [`with_x_scale`](ViewTransformer::with_x_scale) /
+/// [`with_y_scale`](ViewTransformer::with_y_scale)) and then mapped
+/// linearly onto the pixels.
 ///
 /// The y-axis is **inverted** during the transform: data-space y increases
 /// upward (mathematical convention) while screen-space y increases downward
@@ -233,12 +541,31 @@ fn map_val(val: f32, in_min: f32, in_max: f32, out_min: f32, out_max: f32) -> f3
 /// [`Graph::plot`](crate::graph::Graph) and passed to every
 /// [`ChartElement::draw_in_view`](crate::plotter::ChartElement::draw_in_view)
 /// call.
+///
+/// Either axis can additionally be reversed with
+/// [`with_inverted_x`](ViewTransformer::with_inverted_x) /
+/// [`with_inverted_y`](ViewTransformer::with_inverted_y), e.g. for depth or
+/// rank axes that grow downward or leftward.
 #[derive(Debug, Clone, Copy)]
 pub struct ViewTransformer {
     /// The axis-aligned bounding box of the data in data coordinates.
     pub data_bounds: DataBBox,
     /// The viewport (with margins) that defines the screen target area.
     pub screen_bounds: Viewport,
+    /// When `true`, data-x grows leftward (data min maps to the right edge).
+    pub invert_x: bool,
+    /// When `true`, data-y grows downward (data min maps to the top edge).
+    pub invert_y: bool,
+    /// Data bounds of the secondary (right-hand) y-axis, if any. See
+    /// [`secondary`](ViewTransformer::secondary).
+    pub secondary_bounds: Option<DataBBox>,
+    /// Spacing of the x axis.
+    pub x_scale: ScaleSpec,
+    /// Spacing of the y axis.
+    pub y_scale: ScaleSpec,
+    /// Spacing of the secondary y-axis, used by
+    /// [`secondary`](ViewTransformer::secondary).
+    pub secondary_y_scale: ScaleSpec,
 }
 
 impl ViewTransformer {
@@ -248,38 +575,151 @@ impl ViewTransformer {
         Self {
             data_bounds,
             screen_bounds,
+            invert_x: false,
+            invert_y: false,
+            secondary_bounds: None,
+            x_scale: ScaleSpec::Linear,
+            y_scale: ScaleSpec::Linear,
+            secondary_y_scale: ScaleSpec::Linear,
+        }
+    }
+
+    /// Set the spacing of the x axis, returning self for chaining.
+    #[must_use]
+    pub fn with_x_scale(mut self, scale: impl Into<ScaleSpec>) -> Self {
+        self.x_scale = scale.into();
+        self
+    }
+
+    /// Set the spacing of the y axis, returning self for chaining.
+    #[must_use]
+    pub fn with_y_scale(mut self, scale: impl Into<ScaleSpec>) -> Self {
+        self.y_scale = scale.into();
+        self
+    }
+
+    /// Attach the data bounds of a secondary y-axis, returning self for
+    /// chaining.
+    #[must_use]
+    pub fn with_secondary_bounds(mut self, bounds: DataBBox) -> Self {
+        self.secondary_bounds = Some(bounds);
+        self
+    }
+
+    /// Set the spacing of the secondary y-axis, returning self for chaining.
+    #[must_use]
+    pub fn with_secondary_y_scale(mut self, scale: impl Into<ScaleSpec>) -> Self {
+        self.secondary_y_scale = scale.into();
+        self
+    }
+
+    /// A transformer that projects against the secondary data bounds and
+    /// y scale while sharing this transformer's screen area, x scale and
+    /// inversion flags.
+    ///
+    /// Returns `None` when no secondary axis is attached.
+    #[must_use]
+    pub fn secondary(&self) -> Option<Self> {
+        self.secondary_bounds.map(|bounds| Self {
+            data_bounds: bounds,
+            secondary_bounds: None,
+            y_scale: self.secondary_y_scale,
+            ..*self
+        })
+    }
+
+    /// Set whether the x direction is reversed, returning self for chaining.
+    #[must_use]
+    pub fn with_inverted_x(mut self, invert: bool) -> Self {
+        self.invert_x = invert;
+        self
+    }
+
+    /// Set whether the y direction is reversed, returning self for chaining.
+    #[must_use]
+    pub fn with_inverted_y(mut self, invert: bool) -> Self {
+        self.invert_y = invert;
+        self
+    }
+
+    /// Screen-space x range `(start, end)` that data min/max map onto.
+    fn screen_x_range(&self, screen_bounds: &ScreenBBox) -> (f32, f32) {
+        if self.invert_x {
+            (screen_bounds.maximum.x, screen_bounds.minimum.x)
+        } else {
+            (screen_bounds.minimum.x, screen_bounds.maximum.x)
+        }
+    }
+
+    /// Screen-space y range `(start, end)` that data min/max map onto.
+    ///
+    /// Without inversion data min (bottom) maps to screen max (bottom) and
+    /// data max (top) to screen min (top).
+    fn screen_y_range(&self, screen_bounds: &ScreenBBox) -> (f32, f32) {
+        if self.invert_y {
+            (screen_bounds.minimum.y, screen_bounds.maximum.y)
+        } else {
+            (screen_bounds.maximum.y, screen_bounds.minimum.y)
         }
     }
 
     /// Project a data-space point to screen-space coordinates.
     ///
-    /// The x component is linearly mapped from the data range to the inner
-    /// screen width. The y component is mapped with an inversion so that
-    /// increasing data-y moves upward on the screen.
+    /// Each component is spaced by its axis scale, then linearly mapped
+    /// from the scaled data range to the inner screen extent. The y
+    /// component is mapped with an inversion so that increasing data-y
+    /// moves upward on the screen. The `invert_x` / `invert_y` flags reverse
+    /// the respective direction.
     #[must_use]
     pub fn to_screen(&self, point: &Datapoint) -> Screenpoint {
         let screen_bounds = self.screen_bounds.inner_bbox();
+        let (x_start, x_end) = self.screen_x_range(&screen_bounds);
+        let (y_start, y_end) = self.screen_y_range(&screen_bounds);
+        let (sx, sy) = (self.x_scale, self.y_scale);
         let x = map_val(
-            point.x,
-            self.data_bounds.minimum.x,
-            self.data_bounds.maximum.x,
-            screen_bounds.minimum.x,
-            screen_bounds.maximum.x,
+            sx.forward(point.x),
+            sx.forward(self.data_bounds.minimum.x),
+            sx.forward(self.data_bounds.maximum.x),
+            x_start,
+            x_end,
         );
-
-        // Explicit Y inversion:
-        // data min (bottom) -> screen max (bottom)
-        // data max (top)    -> screen min (top)
         let y = map_val(
-            point.y,
-            self.data_bounds.minimum.y,
-            self.data_bounds.maximum.y,
-            screen_bounds.maximum.y,
-            screen_bounds.minimum.y,
+            sy.forward(point.y),
+            sy.forward(self.data_bounds.minimum.y),
+            sy.forward(self.data_bounds.maximum.y),
+            y_start,
+            y_end,
         );
 
         Screenpoint((x, y).into())
     }
+
+    /// Project a screen-space point back to data-space coordinates.
+    ///
+    /// This is the inverse of [`to_screen`](ViewTransformer::to_screen).
+    #[must_use]
+    pub fn to_data(&self, point: &Screenpoint) -> Datapoint {
+        let screen_bounds = self.screen_bounds.inner_bbox();
+        let (x_start, x_end) = self.screen_x_range(&screen_bounds);
+        let (y_start, y_end) = self.screen_y_range(&screen_bounds);
+        let (sx, sy) = (self.x_scale, self.y_scale);
+        let x = sx.inverse(map_val(
+            point.x,
+            x_start,
+            x_end,
+            sx.forward(self.data_bounds.minimum.x),
+            sx.forward(self.data_bounds.maximum.x),
+        ));
+        let y = sy.inverse(map_val(
+            point.y,
+            y_start,
+            y_end,
+            sy.forward(self.data_bounds.minimum.y),
+            sy.forward(self.data_bounds.maximum.y),
+        ));
+
+        Datapoint((x, y).into())
+    }
 }
 #[cfg(test)]
 mod tests {
@@ -289,6 +729,106 @@ mod tests {
         assert!((a - b).abs() < 1e-5, "expected {b}, got {a}");
     }
 
+    #[test]
+    fn bbox_union_contains_and_intersects() {
+        let a: DataBBox = BBox::new((0.0, 0.0), (4.0, 4.0));
+        let overlapping: DataBBox = BBox::new((2.0, 2.0), (6.0, 5.0));
+        let nested: DataBBox = BBox::new((1.0, 1.0), (2.0, 3.0));
+        let disjoint: DataBBox = BBox::new((10.0, -3.0), (12.0, -1.0));
+
+        assert!(a.intersects(&overlapping) && overlapping.intersects(&a));
+        assert!(a.intersects(&nested) && nested.intersects(&a));
+        assert!(!a.intersects(&disjoint) && !disjoint.intersects(&a));
+
+        let u = a.union(&overlapping);
+        assert_eq!((u.minimum.x, u.minimum.y), (0.0, 0.0));
+        assert_eq!((u.maximum.x, u.maximum.y), (6.0, 5.0));
+        let u = a.union(&nested);
+        assert_eq!((u.minimum.x, u.maximum.x), (0.0, 4.0));
+        let u = a.union(&disjoint);
+        assert_eq!((u.minimum.x, u.minimum.y), (0.0, -3.0));
+        assert_eq!((u.maximum.x, u.maximum.y), (12.0, 4.0));
+
+        assert!(a.contains(&Datapoint::new(4.0, 0.0)));
+        assert!(a.contains(&Datapoint::new(1.0, 2.0)));
+        assert!(!a.contains(&Datapoint::new(4.5, 2.0)));
+    }
+
+    #[test]
+    fn padding_expands_each_side() {
+        let b: DataBBox = BBox::new((0.0, 10.0), (10.0, 20.0));
+        let p = b.padded(0.1);
+        assert_approx(p.minimum.x, -1.0);
+        assert_approx(p.maximum.x, 11.0);
+        assert_approx(p.minimum.y, 9.0);
+        assert_approx(p.maximum.y, 21.0);
+
+        let p = b.padded_abs(2.0, 0.5);
+        assert_approx(p.minimum.x, -2.0);
+        assert_approx(p.maximum.y, 20.5);
+    }
+
+    #[test]
+    fn padding_zero_extent_never_collapses() {
+        let point: DataBBox = BBox::new((3.0, 7.0), (3.0, 7.0));
+        let p = point.padded(0.1);
+        assert!(p.width() > 0.0 && p.height() > 0.0);
+        let p = point.padded_abs(0.25, 0.0);
+        assert_approx(p.width(), 0.5);
+        assert!(p.height() > 0.0);
+    }
+
+    #[test]
+    fn resized_scales_position_with_size() {
+        let vp = Viewport::new(100.0, 50.0, 200.0, 100.0).with_margins(Margins::all(10.0));
+        let r = vp.resized(400.0, 50.0);
+        assert_approx(r.x(), 200.0);
+        assert_approx(r.y(), 25.0);
+        assert_approx(r.width(), 400.0);
+        assert_approx(r.height(), 50.0);
+        assert_approx(r.margins().left, 10.0);
+
+        let moved = vp.with_position(0.0, 0.0).with_size(10.0, 20.0);
+        assert_approx(moved.x(), 0.0);
+        assert_approx(moved.height(), 20.0);
+    }
+
+    #[test]
+    fn log_scale_spaces_decades_evenly() {
+        let data = BBox::new((1.0, -100.0), (1000.0, 100.0));
+        let viewport = Viewport::new(0.0, 0.0, 300.0, 200.0);
+        let view = ViewTransformer::new(data, viewport)
+            .with_x_scale(ScaleSpec::Log { base: 10.0 })
+            .with_y_scale(ScaleSpec::SymLog {
+                base: 10.0,
+                lin_threshold: 1.0,
+            });
+
+        for (x, expected) in [(1.0, 0.0), (10.0, 100.0), (100.0, 200.0), (1000.0, 300.0)] {
+            assert_approx(view.to_screen(&Datapoint::new(x, 0.0)).x, expected);
+        }
+        // Symlog is symmetric about zero, which sits mid-height.
+        assert_approx(view.to_screen(&Datapoint::new(1.0, 0.0)).y, 100.0);
+        let up = view.to_screen(&Datapoint::new(1.0, 10.0)).y;
+        let down = view.to_screen(&Datapoint::new(1.0, -10.0)).y;
+        assert_approx(100.0 - up, down - 100.0);
+
+        let back = view.to_data(&view.to_screen(&Datapoint::new(31.6, -42.0)));
+        assert!((back.x - 31.6).abs() < 1e-3, "x = {}", back.x);
+        assert!((back.y + 42.0).abs() < 1e-3, "y = {}", back.y);
+    }
+
+    #[test]
+    fn scale_spec_matches_tick_scale() {
+        let spec: ScaleSpec = Scale::Log {
+            base: 2.0,
+            include_minor: true,
+        }
+        .into();
+        assert_eq!(spec, ScaleSpec::Log { base: 2.0 });
+        assert_eq!(ScaleSpec::from(Scale::Linear), ScaleSpec::Linear);
+    }
+
     #[test]
     fn to_screen_flips_y_cartesian_to_raylib() {
         let data = BBox::new((0.0, 0.0), (10.0, 10.0));
@@ -310,4 +850,88 @@ mod tests {
         assert_approx(p.x, 100.0);
         assert_approx(p.y, 100.0);
     }
+
+    #[test]
+    fn inverted_y_composes_with_flip() {
+        let data = BBox::new((0.0, 0.0), (10.0, 10.0));
+        let viewport = Viewport::new(0.0, 0.0, 100.0, 100.0);
+        let view = ViewTransformer::new(data, viewport).with_inverted_y(true);
+
+        // data bottom-left -> screen top-left
+        let p = view.to_screen(&Datapoint::new(0.0, 0.0));
+        assert_approx(p.x, 0.0);
+        assert_approx(p.y, 0.0);
+
+        // data top-left -> screen bottom-left
+        let p = view.to_screen(&Datapoint::new(0.0, 10.0));
+        assert_approx(p.y, 100.0);
+    }
+
+    #[test]
+    fn inverted_x_maps_min_to_right_edge() {
+        let data = BBox::new((0.0, 0.0), (10.0, 10.0));
+        let viewport = Viewport::new(0.0, 0.0, 100.0, 100.0);
+        let view = ViewTransformer::new(data, viewport).with_inverted_x(true);
+
+        let p = view.to_screen(&Datapoint::new(0.0, 0.0));
+        assert_approx(p.x, 100.0);
+        assert_approx(p.y, 100.0);
+    }
+
+    #[test]
+    fn to_data_round_trips_with_inversion() {
+        let data = BBox::new((-5.0, 2.0), (15.0, 42.0));
+        let viewport = Viewport::new(10.0, 20.0, 300.0, 200.0);
+        for (invert_x, invert_y) in [(false, false), (true, false), (false, true), (true, true)] {
+            let view = ViewTransformer::new(data, viewport)
+                .with_inverted_x(invert_x)
+                .with_inverted_y(invert_y);
+            let original = Datapoint::new(3.0, 17.5);
+            let back = view.to_data(&view.to_screen(&original));
+            assert!((back.x - original.x).abs() < 1e-4);
+            assert!((back.y - original.y).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn letterboxed_centres_region() {
+        let vp = Viewport::new(0.0, 0.0, 200.0, 100.0).letterboxed(1.0);
+        assert_approx(vp.x, 50.0);
+        assert_approx(vp.width, 100.0);
+        assert_approx(vp.height, 100.0);
+
+        let vp = Viewport::new(0.0, 0.0, 100.0, 200.0).letterboxed(2.0);
+        assert_approx(vp.y, 75.0);
+        assert_approx(vp.width, 100.0);
+        assert_approx(vp.height, 50.0);
+    }
+
+    #[test]
+    fn letterboxed_fits_the_inner_area_and_keeps_margins() {
+        let margins = Margins {
+            left: 40.0,
+            right: 10.0,
+            top: 10.0,
+            bottom: 30.0,
+        };
+        // Inner area 250 x 100, boxed to 100 x 100.
+        let vp = Viewport::new(0.0, 0.0, 300.0, 140.0)
+            .with_margins(margins)
+            .letterboxed(1.0);
+        let inner = vp.inner_bbox();
+        assert_approx(inner.width(), 100.0);
+        assert_approx(inner.height(), 100.0);
+        assert_approx(inner.minimum.x, 115.0);
+        assert_approx(vp.width, 150.0);
+        assert_approx(vp.margins().left, 40.0);
+
+        // Inner area 100 x 200, boxed to 100 x 50.
+        let vp = Viewport::new(0.0, 0.0, 150.0, 240.0)
+            .with_margins(margins)
+            .letterboxed(2.0);
+        let inner = vp.inner_bbox();
+        assert_approx(inner.width(), 100.0);
+        assert_approx(inner.height(), 50.0);
+        assert_approx(inner.minimum.y, 85.0);
+    }
 }
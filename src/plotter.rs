@@ -9,13 +9,20 @@
 //!   [`ViewTransformer`] to project
 //!   their coordinates onto the screen (e.g. scatter plots, axes, grid lines).
 //!
+//! [`DynChartElement`] is an object-safe variant of [`ChartElement`] whose
+//! configuration is stored with the element, so that different element types
+//! can be mixed behind a `Box<dyn DynChartElement>`.
+//!
 //! Every concrete visual primitive in the [`plottable`](crate::plottable)
 //! module implements one (or both) of these traits. The associated `Config`
 //! type carries all style and layout parameters needed to render the element.
 
 use raylib::prelude::RaylibDrawHandle;
 
-use crate::plottable::view::{DataBBox, ViewTransformer};
+use crate::{
+    colorscheme::Colorscheme,
+    plottable::view::{DataBBox, ViewTransformer},
+};
 
 /// A drawable element that operates entirely in screen (pixel) coordinates.
 ///
@@ -64,4 +71,38 @@ pub trait ChartElement {
     /// Return the axis-aligned bounding box of this element in data
     /// coordinates.
     fn data_bounds(&self) -> DataBBox;
+
+    /// The smallest strictly positive x and y coordinates of the element,
+    /// where a log axis starts when the bounds reach zero or below.
+    ///
+    /// Defaults to the bounds' minimum on each axis where it is finite and
+    /// positive.
+    fn min_positive(&self) -> (Option<f32>, Option<f32>) {
+        let min = self.data_bounds().minimum;
+        let positive = |v: f32| (v.is_finite() && v > 0.0).then_some(v);
+        (positive(min.x), positive(min.y))
+    }
+}
+
+/// Object-safe counterpart of [`ChartElement`] with the configuration erased.
+///
+/// Implementors keep their configuration alongside the element, which makes
+/// it possible to store heterogeneous elements as
+/// `Box<dyn DynChartElement>`, e.g. inside
+/// [`Layers`](crate::plottable::layers::Layers).
+pub trait DynChartElement {
+    /// Resolve theme-dependent defaults of the stored configuration.
+    fn apply_theme(&mut self, scheme: &Colorscheme);
+
+    /// Render the element with its stored configuration, using `view` to
+    /// project data coordinates to screen coordinates.
+    fn draw_in_view(&self, rl: &mut RaylibDrawHandle, view: &ViewTransformer);
+
+    /// Return the axis-aligned bounding box of this element in data
+    /// coordinates.
+    fn data_bounds(&self) -> DataBBox;
+
+    /// The smallest strictly positive x and y coordinates of the element; see
+    /// [`ChartElement::min_positive`].
+    fn min_positive(&self) -> (Option<f32>, Option<f32>);
 }
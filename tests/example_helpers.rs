@@ -0,0 +1,8 @@
+//! Runs the unit tests of the helpers the examples share (k-means and the
+//! dataset generators in `examples/common.rs`), which `cargo test` does not
+//! build as tests on its own.
+
+// `common.rs` is also built as its own example, hence its `#![no_main]`.
+#[allow(unused_attributes)]
+#[path = "../examples/common.rs"]
+mod common;